@@ -0,0 +1,374 @@
+//! Utilities for baking textures with Cycles.
+
+use crate::{
+    bpy,
+    enums::{BakeType, ImageFileFormat, RenderEngine},
+    error::BlError,
+    result::Result,
+    types::{BpyID, Image, ImageOptionsBuilder, Material, Node, Object, SelectionGuard},
+};
+use builder_derive_more::IntoBuilder;
+use derive_builder::Builder;
+use pyo3::{intern, PyAny, PyObject, Python};
+use std::{collections::HashSet, path::PathBuf};
+
+/// Settings of <https://docs.blender.org/api/latest/bpy.ops.object.html#bpy.ops.object.bake>
+/// for baking into a new image.
+#[derive(Debug, Clone, Builder, IntoBuilder)]
+#[builder(default, build_fn(private, name = "fallible_build", error = "BlError"))]
+pub struct Bake {
+    pub bake_type: BakeType,
+    /// Include direct lighting (only applies to diffuse, glossy and transmission bakes).
+    pub use_pass_direct: bool,
+    /// Include indirect lighting (only applies to diffuse, glossy and transmission bakes).
+    pub use_pass_indirect: bool,
+    /// Include the color of the surface (only applies to diffuse, glossy and transmission bakes).
+    pub use_pass_color: bool,
+    /// Name of the target image.
+    #[builder(setter(into))]
+    pub image_name: String,
+    pub width: u32,
+    pub height: u32,
+    /// Store the target image with 32-bit floating point precision (e.g., for normal maps).
+    pub float_buffer: bool,
+    /// Number of pixels by which the baked islands are extended.
+    pub margin: u32,
+    /// UV map of the target object (`None` uses the active UV map).
+    #[builder(setter(into, strip_option))]
+    pub uv_map: Option<String>,
+    /// Distance by which rays are cast inwards from the target object when baking from
+    /// selected objects.
+    pub cage_extrusion: f32,
+    /// Maximum distance of rays that are cast from the target object when baking from selected
+    /// objects (`0.0` is unlimited).
+    pub max_ray_distance: f32,
+    /// Name of the object that is used as cage instead of extruding the target object.
+    #[builder(setter(into, strip_option))]
+    pub cage_object: Option<String>,
+    /// Number of Cycles samples (`None` keeps the samples of the scene).
+    #[builder(setter(strip_option))]
+    pub samples: Option<u32>,
+    /// Saves the baked image at this path if it is set.
+    #[builder(setter(into, strip_option))]
+    pub filepath: Option<PathBuf>,
+    pub file_format: ImageFileFormat,
+}
+
+impl BakeBuilder {
+    #[must_use]
+    pub fn build(&self) -> Bake {
+        self.fallible_build().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl Default for Bake {
+    fn default() -> Self {
+        Self {
+            bake_type: BakeType::Diffuse,
+            use_pass_direct: false,
+            use_pass_indirect: false,
+            use_pass_color: true,
+            image_name: "Bake".to_string(),
+            width: 1024,
+            height: 1024,
+            float_buffer: false,
+            margin: 16,
+            uv_map: None,
+            cage_extrusion: 0.0,
+            max_ray_distance: 0.0,
+            cage_object: None,
+            samples: None,
+            filepath: None,
+            file_format: ImageFileFormat::Png,
+        }
+    }
+}
+
+impl Bake {
+    /// Bakes the materials of `target` into a new image on the CPU, see
+    /// [`Bake::bake_selected_to_active`] for baking from other objects.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `target` has no materials.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn bake(&self, py: Python, target: &Object) -> Result<Image> {
+        self.run(py, target, &[])
+    }
+
+    /// Bakes the surfaces of `sources` (e.g., high-poly meshes) onto the UV map of `target` by
+    /// casting rays from `target` (see [`Bake::cage_extrusion`] and [`Bake::max_ray_distance`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `sources` is empty or `target` has no materials.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn bake_selected_to_active(
+        &self,
+        py: Python,
+        target: &Object,
+        sources: &[Object],
+    ) -> Result<Image> {
+        if sources.is_empty() {
+            return Err(BlError::ValueError(
+                "Baking from selected objects requires at least one source object".to_string(),
+            ));
+        }
+        self.run(py, target, sources)
+    }
+
+    fn run(&self, py: Python, target: &Object, sources: &[Object]) -> Result<Image> {
+        let materials = unique_materials(py, target)?;
+        if materials.is_empty() {
+            return Err(BlError::ValueError(format!(
+                "Object '{}' has no material to bake into",
+                target.name(py)?
+            )));
+        }
+
+        let mut image = Image::new(
+            py,
+            &self.image_name,
+            self.width,
+            self.height,
+            &ImageOptionsBuilder::default()
+                .alpha(true)
+                .float_buffer(self.float_buffer)
+                .build(),
+        )?;
+        if let Err(err) = self.bake_into_image(py, &mut image, materials, target, sources) {
+            bpy::data::images(py)?.remove(py, &image, true, true, true)?;
+            return Err(err);
+        }
+        Ok(image)
+    }
+
+    /// Bakes into `image` via temporary Image Texture nodes in `materials` and saves it.
+    fn bake_into_image(
+        &self,
+        py: Python,
+        image: &mut Image,
+        materials: Vec<Material>,
+        target: &Object,
+        sources: &[Object],
+    ) -> Result<()> {
+        image.set_colorspace(
+            py,
+            if self.bake_type.is_color() {
+                "sRGB"
+            } else {
+                "Non-Color"
+            },
+        )?;
+
+        let mut image_nodes = ImageNodeGuard::default();
+        for material in materials {
+            image_nodes.add(py, material, image)?;
+        }
+        self.bake_into_image_nodes(py, target, sources)?;
+        drop(image_nodes);
+
+        if let Some(filepath) = &self.filepath {
+            image.save_as(py, filepath, self.file_format)?;
+        }
+        Ok(())
+    }
+
+    /// Bakes into the active Image Texture node of each material with Cycles on the CPU.
+    fn bake_into_image_nodes(&self, py: Python, target: &Object, sources: &[Object]) -> Result<()> {
+        let scene = bpy::context::scene(py)?;
+        let render = scene.render(py)?;
+        let cycles = scene.getattr(py, intern!(py, "cycles"))?;
+        let cycles = cycles.as_ref(py);
+        let _render_settings = RenderSettingsGuard::new(render, cycles)?;
+
+        render.setattr(intern!(py, "engine"), RenderEngine::Cycles)?;
+        cycles.setattr(intern!(py, "device"), "CPU")?;
+        if let Some(samples) = self.samples {
+            cycles.setattr(intern!(py, "samples"), samples)?;
+        }
+
+        let selection = SelectionGuard::new(py)?;
+        let objects: Vec<Object> = std::iter::once(target.clone())
+            .chain(sources.iter().cloned())
+            .collect();
+        selection.select_only(py, &objects)?;
+        self.call_operator(py, !sources.is_empty())
+    }
+
+    fn call_operator(&self, py: Python, use_selected_to_active: bool) -> Result<()> {
+        Ok(bpy::ops::object::bake(
+            py,
+            self.bake_type,
+            self.pass_filter(),
+            "",
+            self.width,
+            self.height,
+            self.margin,
+            "EXTEND",
+            use_selected_to_active,
+            self.max_ray_distance,
+            self.cage_extrusion,
+            self.cage_object.as_deref().unwrap_or_default(),
+            "TANGENT",
+            "POS_X",
+            "POS_Y",
+            "POS_Z",
+            "IMAGE_TEXTURES",
+            "INTERNAL",
+            true,
+            self.cage_object.is_some(),
+            false,
+            false,
+            self.uv_map.as_deref().unwrap_or_default(),
+        )?)
+    }
+
+    fn pass_filter(&self) -> HashSet<String> {
+        let passes: &[(&str, bool)] = match self.bake_type {
+            BakeType::Diffuse | BakeType::Glossy | BakeType::Transmission => &[
+                ("DIRECT", self.use_pass_direct),
+                ("INDIRECT", self.use_pass_indirect),
+                ("COLOR", self.use_pass_color),
+            ],
+            BakeType::Combined => &[
+                ("DIRECT", true),
+                ("INDIRECT", true),
+                ("DIFFUSE", true),
+                ("GLOSSY", true),
+                ("TRANSMISSION", true),
+                ("EMIT", true),
+            ],
+            _ => &[],
+        };
+        passes
+            .iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(pass, _)| (*pass).to_string())
+            .collect()
+    }
+}
+
+/// Returns the materials of all material slots of `object` without duplicates.
+fn unique_materials(py: Python, object: &Object) -> Result<Vec<Material>> {
+    let mut materials: Vec<Material> = Vec::new();
+    for slot in object.material_slots(py)?.iter()? {
+        let material: Option<Material> = slot?.getattr(intern!(py, "material"))?.extract()?;
+        let Some(material) = material else {
+            continue;
+        };
+        let mut is_duplicate = false;
+        for other in &materials {
+            is_duplicate |= other.as_ref(py).eq(material.as_ref(py))?;
+        }
+        if !is_duplicate {
+            materials.push(material);
+        }
+    }
+    Ok(materials)
+}
+
+/// Restores the render engine and the Cycles device and samples when dropped.
+struct RenderSettingsGuard {
+    render: PyObject,
+    cycles: PyObject,
+    engine: PyObject,
+    device: PyObject,
+    samples: PyObject,
+}
+
+impl RenderSettingsGuard {
+    fn new(render: &PyAny, cycles: &PyAny) -> Result<Self> {
+        let py = render.py();
+        Ok(Self {
+            render: render.into(),
+            cycles: cycles.into(),
+            engine: render.getattr(intern!(py, "engine"))?.into(),
+            device: cycles.getattr(intern!(py, "device"))?.into(),
+            samples: cycles.getattr(intern!(py, "samples"))?.into(),
+        })
+    }
+}
+
+impl Drop for RenderSettingsGuard {
+    fn drop(&mut self) {
+        Python::with_gil(|py| {
+            for (settings, attr, value) in [
+                (&self.render, intern!(py, "engine"), &self.engine),
+                (&self.cycles, intern!(py, "device"), &self.device),
+                (&self.cycles, intern!(py, "samples"), &self.samples),
+            ] {
+                if let Err(err) = settings.as_ref(py).setattr(attr, value) {
+                    err.print(py);
+                }
+            }
+        });
+    }
+}
+
+/// Adds Image Texture nodes as the targets of a bake and reverts all changes to the materials
+/// when dropped, i.e., removes the nodes and restores `use_nodes` and the active nodes.
+#[derive(Default)]
+struct ImageNodeGuard {
+    materials: Vec<ChangedMaterial>,
+}
+
+struct ChangedMaterial {
+    material: Material,
+    use_nodes: bool,
+    active: Option<Node>,
+    node: Option<Node>,
+}
+
+impl ImageNodeGuard {
+    /// Adds an Image Texture node with `image` as the active node of `material`.
+    fn add(&mut self, py: Python, material: Material, image: &Image) -> Result<()> {
+        let mut changed = ChangedMaterial {
+            use_nodes: material.use_nodes(py)?,
+            material,
+            active: None,
+            node: None,
+        };
+        let result = Self::add_image_node(py, &mut changed, image);
+        self.materials.push(changed);
+        result
+    }
+
+    fn add_image_node(py: Python, changed: &mut ChangedMaterial, image: &Image) -> Result<()> {
+        changed.material.set_use_nodes(py, &true)?;
+        let mut nodes = changed.material.node_tree(py)?.nodes(py)?;
+        changed.active = nodes.getattr(py, intern!(py, "active"))?.extract(py)?;
+        let node = changed.node.insert(nodes.new(py, "ShaderNodeTexImage")?);
+        let node_ref: &PyAny = node.as_ref(py);
+        node_ref.setattr(intern!(py, "image"), image.as_ref(py))?;
+        node_ref.setattr(intern!(py, "select"), true)?;
+        nodes.set_active(py, node)?;
+        Ok(())
+    }
+
+    fn restore(py: Python, changed: &mut ChangedMaterial) -> Result<()> {
+        if changed.node.is_some() || changed.active.is_some() {
+            let mut nodes = changed.material.node_tree(py)?.nodes(py)?;
+            if let Some(node) = &changed.node {
+                nodes.remove(py, node)?;
+            }
+            if let Some(active) = &changed.active {
+                nodes.set_active(py, active)?;
+            }
+        }
+        changed.material.set_use_nodes(py, &changed.use_nodes)?;
+        Ok(())
+    }
+}
+
+impl Drop for ImageNodeGuard {
+    fn drop(&mut self) {
+        Python::with_gil(|py| {
+            for changed in &mut self.materials {
+                if let Err(err) = Self::restore(py, changed) {
+                    pyo3::PyErr::from(err).print(py);
+                }
+            }
+        });
+    }
+}
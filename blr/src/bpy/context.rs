@@ -1,5 +1,8 @@
 //! Bindings for [`bpy.context`](https://docs.blender.org/api/latest/bpy.context.html).
-use super::{bind_python, Collection, ContextMode, Object, PyAny, ViewLayer};
+use super::{
+    bind_python, Action, Bone, ContextMode, Depsgraph, EditBone, FCurve, Keyframe, Object,
+    PoseBone, PyAny, Scene, SceneCollection, ViewLayer,
+};
 
 bind_python! { bpy.context.temp_override => pub fn fn_temp_override(py: Python) -> Result<&PyAny> }
 
@@ -7,7 +10,7 @@ bind_python! { bpy.context.temp_override => pub fn fn_temp_override(py: Python)
 bind_python! { bpy.context.area => pub fn area(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.asset_file_handle => pub fn asset_file_handle(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.blend_data => pub fn blend_data(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.collection => pub fn collection(py: Python) -> Result<Collection> }
+bind_python! { bpy.context.collection => pub fn collection(py: Python) -> Result<SceneCollection> }
 bind_python! { bpy.context.engine => pub fn engine(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.gizmo_group => pub fn gizmo_group(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.layer_collection => pub fn layer_collection(py: Python) -> Result<&PyAny> }
@@ -15,7 +18,7 @@ bind_python! { bpy.context.mode => pub fn mode(py: Python) -> Result<ContextMode
 bind_python! { bpy.context.preferences => pub fn preferences(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.region => pub fn region(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.region_data => pub fn region_data(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.scene => pub fn scene(py: Python) -> Result<&PyAny> }
+bind_python! { bpy.context.scene => pub fn scene(py: Python) -> Result<Scene> }
 bind_python! { bpy.context.screen => pub fn screen(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.space_data => pub fn space_data(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.tool_settings => pub fn tool_settings(py: Python) -> Result<&PyAny> }
@@ -38,9 +41,9 @@ bind_python! { bpy.context.material_slot => pub fn material_slot(py: Python) ->
 bind_python! { bpy.context.texture => pub fn texture(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.texture_user => pub fn texture_user(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.texture_user_property => pub fn texture_user_property(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.bone => pub fn bone(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.edit_bone => pub fn edit_bone(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.pose_bone => pub fn pose_bone(py: Python) -> Result<&PyAny> }
+bind_python! { bpy.context.bone => pub fn bone(py: Python) -> Result<Option<Bone>> }
+bind_python! { bpy.context.edit_bone => pub fn edit_bone(py: Python) -> Result<Option<EditBone>> }
+bind_python! { bpy.context.pose_bone => pub fn pose_bone(py: Python) -> Result<Option<PoseBone>> }
 bind_python! { bpy.context.particle_system => pub fn particle_system(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.particle_system_editable => pub fn particle_system_editable(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.particle_settings => pub fn particle_settings(py: Python) -> Result<&PyAny> }
@@ -85,15 +88,15 @@ bind_python! { bpy.context.editable_objects => pub fn editable_objects(py: Pytho
 bind_python! { bpy.context.selected_editable_objects => pub fn selected_editable_objects(py: Python) -> Result<Vec<Object>> }
 bind_python! { bpy.context.objects_in_mode => pub fn objects_in_mode(py: Python) -> Result<Vec<Object>> }
 bind_python! { bpy.context.objects_in_mode_unique_data => pub fn objects_in_mode_unique_data(py: Python) -> Result<Vec<Object>> }
-bind_python! { bpy.context.visible_bones => pub fn visible_bones(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.editable_bones => pub fn editable_bones(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.selected_bones => pub fn selected_bones(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.selected_editable_bones => pub fn selected_editable_bones(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.visible_pose_bones => pub fn visible_pose_bones(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.selected_pose_bones => pub fn selected_pose_bones(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.selected_pose_bones_from_active_object => pub fn selected_pose_bones_from_active_object(py: Python) -> Result<Vec<&PyAny>> }
+bind_python! { bpy.context.visible_bones => pub fn visible_bones(py: Python) -> Result<Vec<EditBone>> }
+bind_python! { bpy.context.editable_bones => pub fn editable_bones(py: Python) -> Result<Vec<EditBone>> }
+bind_python! { bpy.context.selected_bones => pub fn selected_bones(py: Python) -> Result<Vec<EditBone>> }
+bind_python! { bpy.context.selected_editable_bones => pub fn selected_editable_bones(py: Python) -> Result<Vec<EditBone>> }
+bind_python! { bpy.context.visible_pose_bones => pub fn visible_pose_bones(py: Python) -> Result<Vec<PoseBone>> }
+bind_python! { bpy.context.selected_pose_bones => pub fn selected_pose_bones(py: Python) -> Result<Vec<PoseBone>> }
+bind_python! { bpy.context.selected_pose_bones_from_active_object => pub fn selected_pose_bones_from_active_object(py: Python) -> Result<Vec<PoseBone>> }
 bind_python! { bpy.context.active_bone => pub fn active_bone(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.active_pose_bone => pub fn active_pose_bone(py: Python) -> Result<&PyAny> }
+bind_python! { bpy.context.active_pose_bone => pub fn active_pose_bone(py: Python) -> Result<Option<PoseBone>> }
 bind_python! { bpy.context.active_object => pub fn active_object(py: Python) -> Result<Object> }
 bind_python! { bpy.context.object => pub fn object(py: Python) -> Result<Object> }
 bind_python! { bpy.context.edit_object => pub fn edit_object(py: Python) -> Result<Object> }
@@ -122,18 +125,18 @@ bind_python! { bpy.context.active_gpencil_layer => pub fn active_gpencil_layer(p
 bind_python! { bpy.context.active_gpencil_frame => pub fn active_gpencil_frame(py: Python) -> Result<Vec<&PyAny>> }
 bind_python! { bpy.context.active_annotation_layer => pub fn active_annotation_layer(py: Python) -> Result<&PyAny> }
 bind_python! { bpy.context.active_operator => pub fn active_operator(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.active_action => pub fn active_action(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.selected_visible_actions => pub fn selected_visible_actions(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.selected_editable_actions => pub fn selected_editable_actions(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.visible_fcurves => pub fn visible_fcurves(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.editable_fcurves => pub fn editable_fcurves(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.selected_visible_fcurves => pub fn selected_visible_fcurves(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.selected_editable_fcurves => pub fn selected_editable_fcurves(py: Python) -> Result<Vec<&PyAny>> }
-bind_python! { bpy.context.active_editable_fcurve => pub fn active_editable_fcurve(py: Python) -> Result<&PyAny> }
-bind_python! { bpy.context.selected_editable_keyframes => pub fn selected_editable_keyframes(py: Python) -> Result<Vec<&PyAny>> }
+bind_python! { bpy.context.active_action => pub fn active_action(py: Python) -> Result<Option<Action>> }
+bind_python! { bpy.context.selected_visible_actions => pub fn selected_visible_actions(py: Python) -> Result<Vec<Action>> }
+bind_python! { bpy.context.selected_editable_actions => pub fn selected_editable_actions(py: Python) -> Result<Vec<Action>> }
+bind_python! { bpy.context.visible_fcurves => pub fn visible_fcurves(py: Python) -> Result<Vec<FCurve>> }
+bind_python! { bpy.context.editable_fcurves => pub fn editable_fcurves(py: Python) -> Result<Vec<FCurve>> }
+bind_python! { bpy.context.selected_visible_fcurves => pub fn selected_visible_fcurves(py: Python) -> Result<Vec<FCurve>> }
+bind_python! { bpy.context.selected_editable_fcurves => pub fn selected_editable_fcurves(py: Python) -> Result<Vec<FCurve>> }
+bind_python! { bpy.context.active_editable_fcurve => pub fn active_editable_fcurve(py: Python) -> Result<Option<FCurve>> }
+bind_python! { bpy.context.selected_editable_keyframes => pub fn selected_editable_keyframes(py: Python) -> Result<Vec<Keyframe>> }
 bind_python! { bpy.context.ui_list => pub fn ui_list(py: Python) -> Result<&PyAny> }
 
 // Text context <https://docs.blender.org/api/latest/bpy.context.html>
 bind_python! { bpy.context.edit_text => pub fn edit_text(py: Python) -> Result<&PyAny> }
 
-bind_python! { bpy.context.evaluated_depsgraph_get => pub fn evaluated_depsgraph_get(py: Python) -> Result<&PyAny> }
+bind_python! { bpy.context.evaluated_depsgraph_get() => pub fn evaluated_depsgraph_get(py: Python) -> Result<Depsgraph> }
@@ -1,6 +1,7 @@
 //! Bindings for [`bpy.data`](https://docs.blender.org/api/latest/bpy.data.html).
 use super::{
-    bind_python, Collection, Materials, NodeTrees, ObjectCollection, PathBuf, PyAny, PyDict,
+    bind_python, Collection, Images, Materials, NodeTrees, ObjectCollection, PathBuf, PyAny,
+    PyDict, SceneCollections,
 };
 
 bind_python! { bpy.data.actions => pub fn actions(py: Python) -> Result<Collection> }
@@ -8,13 +9,13 @@ bind_python! { bpy.data.armatures => pub fn armatures(py: Python) -> Result<Coll
 bind_python! { bpy.data.brushes => pub fn brushes(py: Python) -> Result<Collection> }
 bind_python! { bpy.data.cache_files => pub fn cache_files(py: Python) -> Result<Collection> }
 bind_python! { bpy.data.cameras => pub fn cameras(py: Python) -> Result<Collection> }
-bind_python! { bpy.data.collections => pub fn collections(py: Python) -> Result<Collection> }
+bind_python! { bpy.data.collections => pub fn collections(py: Python) -> Result<SceneCollections> }
 bind_python! { bpy.data.curves => pub fn curves(py: Python) -> Result<Collection> }
 bind_python! { bpy.data.filepath => pub fn filepath(py: Python) -> Result<PathBuf> }
 bind_python! { bpy.data.fonts => pub fn fonts(py: Python) -> Result<Collection> }
 bind_python! { bpy.data.grease_pencils => pub fn grease_pencils(py: Python) -> Result<Collection> }
 bind_python! { bpy.data.hair_curves => pub fn hair_curves(py: Python) -> Result<Collection> }
-bind_python! { bpy.data.images => pub fn images(py: Python) -> Result<Collection> }
+bind_python! { bpy.data.images => pub fn images(py: Python) -> Result<Images> }
 bind_python! { bpy.data.is_dirty => pub fn is_dirty(py: Python) -> Result<bool> }
 bind_python! { bpy.data.is_saved => pub fn is_saved(py: Python) -> Result<bool> }
 bind_python! { bpy.data.lattices => pub fn lattices(py: Python) -> Result<Collection> }
@@ -3,10 +3,15 @@
 
 use crate::{
     enums::{
-        Alignment, AxisXYZ, ContextMode, LightType, MeshSelectMode, ModifierType, ObjectMode,
-        ObjectType, OriginCenter, OriginType, SnapElement, TransformOrientation,
+        Alignment, AxisXYZ, BakeType, ContextMode, LightType, MeshSelectMode, ModifierType,
+        ObjectMode, ObjectType, OriginCenter, OriginType, RigidBodyCollisionShape,
+        RigidBodyConstraintType, RigidBodyType, SnapElement, TransformOrientation,
+    },
+    types::{
+        Action, Bone, Collection, Depsgraph, EditBone, FCurve, Images, Keyframe, Materials,
+        NodeTrees, Object, ObjectCollection, PoseBone, Scene, SceneCollection, SceneCollections,
+        ViewLayer,
     },
-    types::{Collection, Materials, NodeTrees, Object, ObjectCollection, ViewLayer},
 };
 use pyo3::{types::PyDict, PyAny};
 use pyo3_macros_more::bind_python;
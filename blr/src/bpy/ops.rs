@@ -1,7 +1,8 @@
 //! Bindings for [`bpy.ops`](https://docs.blender.org/api/latest/bpy.ops.html).
 use super::{
-    bind_python, Alignment, AxisXYZ, HashSet, LightType, MeshSelectMode, ModifierType, ObjectMode,
-    ObjectType, OriginCenter, OriginType, Path, PyAny, SnapElement, TransformOrientation,
+    bind_python, Alignment, AxisXYZ, BakeType, HashSet, LightType, MeshSelectMode, ModifierType,
+    ObjectMode, ObjectType, OriginCenter, OriginType, Path, PyAny, RigidBodyCollisionShape,
+    RigidBodyConstraintType, RigidBodyType, SnapElement, TransformOrientation,
 };
 
 // /// <https://docs.blender.org/api/latest/bpy.ops.action.html>
@@ -101,11 +102,24 @@ pub mod collection {
 //     bind_python! {}
 // }
 
-// /// <https://docs.blender.org/api/latest/bpy.ops.constraint.html>
-// pub mod constraint {
-//     use super::*;
-//     bind_python! {}
-// }
+/// <https://docs.blender.org/api/latest/bpy.ops.constraint.html>
+pub mod constraint {
+    use super::bind_python;
+
+    bind_python! { bpy.ops.constraint.apply() => pub fn apply(py: Python, constraint: &str, owner: &str, report: bool) }
+    bind_python! { bpy.ops.constraint.childof_clear_inverse() => pub fn childof_clear_inverse(py: Python, constraint: &str, owner: &str) }
+    bind_python! { bpy.ops.constraint.childof_set_inverse() => pub fn childof_set_inverse(py: Python, constraint: &str, owner: &str) }
+    bind_python! { bpy.ops.constraint.copy() => pub fn copy(py: Python, constraint: &str, owner: &str, report: bool) }
+    bind_python! { bpy.ops.constraint.copy_to_selected() => pub fn copy_to_selected(py: Python, constraint: &str, owner: &str) }
+    bind_python! { bpy.ops.constraint.delete() => pub fn delete(py: Python, constraint: &str, owner: &str, report: bool) }
+    bind_python! { bpy.ops.constraint.disable_keep_transform() => pub fn disable_keep_transform(py: Python, constraint: &str, owner: &str) }
+    bind_python! { bpy.ops.constraint.followpath_path_animate() => pub fn followpath_path_animate(py: Python, constraint: &str, owner: &str, frame_start: i32, length: i32) }
+    bind_python! { bpy.ops.constraint.limitdistance_reset() => pub fn limitdistance_reset(py: Python, constraint: &str, owner: &str) }
+    bind_python! { bpy.ops.constraint.move_down() => pub fn move_down(py: Python, constraint: &str, owner: &str) }
+    bind_python! { bpy.ops.constraint.move_to_index() => pub fn move_to_index(py: Python, constraint: &str, owner: &str, index: usize) }
+    bind_python! { bpy.ops.constraint.move_up() => pub fn move_up(py: Python, constraint: &str, owner: &str) }
+    bind_python! { bpy.ops.constraint.stretchto_reset() => pub fn stretchto_reset(py: Python, constraint: &str, owner: &str) }
+}
 
 /// <https://docs.blender.org/api/latest/bpy.ops.curve.html>
 pub mod curve {
@@ -628,8 +642,8 @@ pub mod node {
 /// <https://docs.blender.org/api/latest/bpy.ops.object.html>
 pub mod object {
     use super::{
-        bind_python, Alignment, LightType, ModifierType, ObjectMode, ObjectType, OriginCenter,
-        OriginType, Path,
+        bind_python, Alignment, BakeType, HashSet, LightType, ModifierType, ObjectMode, ObjectType,
+        OriginCenter, OriginType, Path,
     };
 
     bind_python! { bpy.ops.object.add() => pub fn add(py: Python, radius: f32, r#type: ObjectType, enter_editmode: bool, align: Alignment, location: [f32; 3], rotation: [f32; 3], scale: [f32; 3]) }
@@ -637,6 +651,7 @@ pub mod object {
     bind_python! { bpy.ops.object.anim_transforms_to_deltas() => pub fn anim_transforms_to_deltas(py: Python) }
     bind_python! { bpy.ops.object.armature_add() => pub fn armature_add(py: Python, radius: f32, enter_editmode: bool, align: Alignment, location: [f32; 3], rotation: [f32; 3], scale: [f32; 3]) }
     bind_python! { bpy.ops.object.assign_property_defaults() => pub fn assign_property_defaults(py: Python, process_data: bool, process_bones: bool) }
+    bind_python! { bpy.ops.object.bake() => pub fn bake(py: Python, r#type: BakeType, pass_filter: HashSet<String>, filepath: &str, width: u32, height: u32, margin: u32, margin_type: &str, use_selected_to_active: bool, max_ray_distance: f32, cage_extrusion: f32, cage_object: &str, normal_space: &str, normal_r: &str, normal_g: &str, normal_b: &str, target: &str, save_mode: &str, use_clear: bool, use_cage: bool, use_split_materials: bool, use_automatic_name: bool, uv_layer: &str) }
     bind_python! { bpy.ops.object.bake_image() => pub fn bake_image(py: Python) }
     bind_python! { bpy.ops.object.camera_add() => pub fn camera_add(py: Python, enter_editmode: bool, align: Alignment, location: [f32; 3], rotation: [f32; 3], scale: [f32; 3]) }
     bind_python! { bpy.ops.object.clear_override_library() => pub fn clear_override_library(py: Python) }
@@ -935,11 +950,24 @@ pub mod render {
     bind_python! { bpy.ops.render.render() => pub fn render(py: Python, animation: bool, write_still: bool, use_viewport: bool, layer: &str, scene: &str) }
 }
 
-// /// <https://docs.blender.org/api/latest/bpy.ops.rigidbody.html>
-// pub mod rigidbody {
-//     use super::*;
-//     bind_python! {}
-// }
+/// <https://docs.blender.org/api/latest/bpy.ops.rigidbody.html>
+pub mod rigidbody {
+    use super::{bind_python, RigidBodyCollisionShape, RigidBodyConstraintType, RigidBodyType};
+
+    bind_python! { bpy.ops.rigidbody.bake_to_keyframes() => pub fn bake_to_keyframes(py: Python, frame_start: i32, frame_end: i32, step: i32) }
+    bind_python! { bpy.ops.rigidbody.connect() => pub fn connect(py: Python, con_type: &str, pivot_type: &str, connection_pattern: &str) }
+    bind_python! { bpy.ops.rigidbody.constraint_add() => pub fn constraint_add(py: Python, r#type: RigidBodyConstraintType) }
+    bind_python! { bpy.ops.rigidbody.constraint_remove() => pub fn constraint_remove(py: Python) }
+    bind_python! { bpy.ops.rigidbody.mass_calculate() => pub fn mass_calculate(py: Python, material: &str, density: f32) }
+    bind_python! { bpy.ops.rigidbody.object_add() => pub fn object_add(py: Python, r#type: RigidBodyType) }
+    bind_python! { bpy.ops.rigidbody.object_remove() => pub fn object_remove(py: Python) }
+    bind_python! { bpy.ops.rigidbody.object_settings_copy() => pub fn object_settings_copy(py: Python) }
+    bind_python! { bpy.ops.rigidbody.objects_add() => pub fn objects_add(py: Python, r#type: RigidBodyType) }
+    bind_python! { bpy.ops.rigidbody.objects_remove() => pub fn objects_remove(py: Python) }
+    bind_python! { bpy.ops.rigidbody.shape_change() => pub fn shape_change(py: Python, r#type: RigidBodyCollisionShape) }
+    bind_python! { bpy.ops.rigidbody.world_add() => pub fn world_add(py: Python) }
+    bind_python! { bpy.ops.rigidbody.world_remove() => pub fn world_remove(py: Python) }
+}
 
 /// <https://docs.blender.org/api/latest/bpy.ops.scene.html>
 /// Note: This module is incomplete.
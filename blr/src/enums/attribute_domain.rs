@@ -0,0 +1,57 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/attribute_domain_items.html>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeDomain {
+    Point,
+    Edge,
+    Face,
+    Corner,
+    Curve,
+    Instance,
+}
+
+impl TryFrom<&str> for AttributeDomain {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "POINT" => Self::Point,
+            "EDGE" => Self::Edge,
+            "FACE" => Self::Face,
+            "CORNER" => Self::Corner,
+            "CURVE" => Self::Curve,
+            "INSTANCE" => Self::Instance,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid attribute domain: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for AttributeDomain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Point => write!(f, "POINT"),
+            Self::Edge => write!(f, "EDGE"),
+            Self::Face => write!(f, "FACE"),
+            Self::Corner => write!(f, "CORNER"),
+            Self::Curve => write!(f, "CURVE"),
+            Self::Instance => write!(f, "INSTANCE"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for AttributeDomain {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for AttributeDomain {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
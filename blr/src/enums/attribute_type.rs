@@ -0,0 +1,94 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/attribute_type_items.html>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttributeType {
+    Float,
+    Int,
+    FloatVector,
+    FloatColor,
+    ByteColor,
+    String,
+    Boolean,
+    Float2,
+    Int8,
+    Int32_2D,
+    Quaternion,
+}
+
+impl AttributeType {
+    /// Name of the property that holds the value of each attribute element, e.g. `value` for
+    /// [`AttributeType::Float`] or `vector` for [`AttributeType::FloatVector`].
+    #[must_use]
+    pub const fn value_field(&self) -> &'static str {
+        match self {
+            Self::FloatVector | Self::Float2 => "vector",
+            Self::FloatColor | Self::ByteColor => "color",
+            _ => "value",
+        }
+    }
+
+    /// Number of scalar components of each attribute element.
+    #[must_use]
+    pub const fn components(&self) -> usize {
+        match self {
+            Self::Float2 | Self::Int32_2D => 2,
+            Self::FloatVector => 3,
+            Self::FloatColor | Self::ByteColor | Self::Quaternion => 4,
+            _ => 1,
+        }
+    }
+}
+
+impl TryFrom<&str> for AttributeType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "FLOAT" => Self::Float,
+            "INT" => Self::Int,
+            "FLOAT_VECTOR" => Self::FloatVector,
+            "FLOAT_COLOR" => Self::FloatColor,
+            "BYTE_COLOR" => Self::ByteColor,
+            "STRING" => Self::String,
+            "BOOLEAN" => Self::Boolean,
+            "FLOAT2" => Self::Float2,
+            "INT8" => Self::Int8,
+            "INT32_2D" => Self::Int32_2D,
+            "QUATERNION" => Self::Quaternion,
+            _ => Err(BlError::ValueError(format!("Invalid attribute type: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for AttributeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Float => write!(f, "FLOAT"),
+            Self::Int => write!(f, "INT"),
+            Self::FloatVector => write!(f, "FLOAT_VECTOR"),
+            Self::FloatColor => write!(f, "FLOAT_COLOR"),
+            Self::ByteColor => write!(f, "BYTE_COLOR"),
+            Self::String => write!(f, "STRING"),
+            Self::Boolean => write!(f, "BOOLEAN"),
+            Self::Float2 => write!(f, "FLOAT2"),
+            Self::Int8 => write!(f, "INT8"),
+            Self::Int32_2D => write!(f, "INT32_2D"),
+            Self::Quaternion => write!(f, "QUATERNION"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for AttributeType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for AttributeType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,89 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/bake_pass_type_items.html>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BakeType {
+    Combined,
+    Ao,
+    Shadow,
+    Position,
+    Normal,
+    Uv,
+    Roughness,
+    Emit,
+    Environment,
+    Diffuse,
+    Glossy,
+    Transmission,
+}
+
+impl BakeType {
+    /// Returns `true` if the baked values are colors (stored as sRGB) instead of non-color data.
+    #[must_use]
+    pub const fn is_color(&self) -> bool {
+        matches!(
+            self,
+            Self::Combined
+                | Self::Emit
+                | Self::Environment
+                | Self::Diffuse
+                | Self::Glossy
+                | Self::Transmission
+        )
+    }
+}
+
+impl TryFrom<&str> for BakeType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "COMBINED" => Self::Combined,
+            "AO" => Self::Ao,
+            "SHADOW" => Self::Shadow,
+            "POSITION" => Self::Position,
+            "NORMAL" => Self::Normal,
+            "UV" => Self::Uv,
+            "ROUGHNESS" => Self::Roughness,
+            "EMIT" => Self::Emit,
+            "ENVIRONMENT" => Self::Environment,
+            "DIFFUSE" => Self::Diffuse,
+            "GLOSSY" => Self::Glossy,
+            "TRANSMISSION" => Self::Transmission,
+            _ => Err(BlError::ValueError(format!("Invalid bake type: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for BakeType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Combined => write!(f, "COMBINED"),
+            Self::Ao => write!(f, "AO"),
+            Self::Shadow => write!(f, "SHADOW"),
+            Self::Position => write!(f, "POSITION"),
+            Self::Normal => write!(f, "NORMAL"),
+            Self::Uv => write!(f, "UV"),
+            Self::Roughness => write!(f, "ROUGHNESS"),
+            Self::Emit => write!(f, "EMIT"),
+            Self::Environment => write!(f, "ENVIRONMENT"),
+            Self::Diffuse => write!(f, "DIFFUSE"),
+            Self::Glossy => write!(f, "GLOSSY"),
+            Self::Transmission => write!(f, "TRANSMISSION"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for BakeType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for BakeType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
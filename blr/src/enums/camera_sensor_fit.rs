@@ -0,0 +1,48 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.Camera.html#bpy.types.Camera.sensor_fit>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CameraSensorFit {
+    Auto,
+    Horizontal,
+    Vertical,
+}
+
+impl TryFrom<&str> for CameraSensorFit {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "AUTO" => Self::Auto,
+            "HORIZONTAL" => Self::Horizontal,
+            "VERTICAL" => Self::Vertical,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid camera sensor fit: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for CameraSensorFit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "AUTO"),
+            Self::Horizontal => write!(f, "HORIZONTAL"),
+            Self::Vertical => write!(f, "VERTICAL"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for CameraSensorFit {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for CameraSensorFit {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
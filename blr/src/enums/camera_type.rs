@@ -0,0 +1,46 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.Camera.html#bpy.types.Camera.type>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CameraType {
+    Perspective,
+    Orthographic,
+    Panoramic,
+}
+
+impl TryFrom<&str> for CameraType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "PERSP" => Self::Perspective,
+            "ORTHO" => Self::Orthographic,
+            "PANO" => Self::Panoramic,
+            _ => Err(BlError::ValueError(format!("Invalid camera type: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for CameraType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Perspective => write!(f, "PERSP"),
+            Self::Orthographic => write!(f, "ORTHO"),
+            Self::Panoramic => write!(f, "PANO"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for CameraType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for CameraType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
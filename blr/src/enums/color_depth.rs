@@ -0,0 +1,52 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.ImageFormatSettings.html#bpy.types.ImageFormatSettings.color_depth>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorDepth {
+    Bits8,
+    Bits10,
+    Bits12,
+    Bits16,
+    Bits32,
+}
+
+impl TryFrom<&str> for ColorDepth {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "8" => Self::Bits8,
+            "10" => Self::Bits10,
+            "12" => Self::Bits12,
+            "16" => Self::Bits16,
+            "32" => Self::Bits32,
+            _ => Err(BlError::ValueError(format!("Invalid color depth: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for ColorDepth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bits8 => write!(f, "8"),
+            Self::Bits10 => write!(f, "10"),
+            Self::Bits12 => write!(f, "12"),
+            Self::Bits16 => write!(f, "16"),
+            Self::Bits32 => write!(f, "32"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ColorDepth {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for ColorDepth {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,57 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.Constraint.html#bpy.types.Constraint.owner_space>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstraintSpace {
+    World,
+    Custom,
+    Pose,
+    LocalWithParent,
+    Local,
+    LocalOwnerOrient,
+}
+
+impl TryFrom<&str> for ConstraintSpace {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "WORLD" => Self::World,
+            "CUSTOM" => Self::Custom,
+            "POSE" => Self::Pose,
+            "LOCAL_WITH_PARENT" => Self::LocalWithParent,
+            "LOCAL" => Self::Local,
+            "LOCAL_OWNER_ORIENT" => Self::LocalOwnerOrient,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid constraint space: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for ConstraintSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::World => write!(f, "WORLD"),
+            Self::Custom => write!(f, "CUSTOM"),
+            Self::Pose => write!(f, "POSE"),
+            Self::LocalWithParent => write!(f, "LOCAL_WITH_PARENT"),
+            Self::Local => write!(f, "LOCAL"),
+            Self::LocalOwnerOrient => write!(f, "LOCAL_OWNER_ORIENT"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ConstraintSpace {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for ConstraintSpace {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
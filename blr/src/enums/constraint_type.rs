@@ -0,0 +1,121 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/constraint_type_items.html>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ConstraintType {
+    CameraSolver,
+    FollowTrack,
+    ObjectSolver,
+    CopyLocation,
+    CopyRotation,
+    CopyScale,
+    CopyTransforms,
+    LimitDistance,
+    LimitLocation,
+    LimitRotation,
+    LimitScale,
+    MaintainVolume,
+    Transform,
+    TransformCache,
+    ClampTo,
+    DampedTrack,
+    Ik,
+    LockedTrack,
+    SplineIk,
+    StretchTo,
+    TrackTo,
+    Action,
+    Armature,
+    ChildOf,
+    Floor,
+    FollowPath,
+    Pivot,
+    Shrinkwrap,
+}
+
+impl TryFrom<&str> for ConstraintType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "CAMERA_SOLVER" => Self::CameraSolver,
+            "FOLLOW_TRACK" => Self::FollowTrack,
+            "OBJECT_SOLVER" => Self::ObjectSolver,
+            "COPY_LOCATION" => Self::CopyLocation,
+            "COPY_ROTATION" => Self::CopyRotation,
+            "COPY_SCALE" => Self::CopyScale,
+            "COPY_TRANSFORMS" => Self::CopyTransforms,
+            "LIMIT_DISTANCE" => Self::LimitDistance,
+            "LIMIT_LOCATION" => Self::LimitLocation,
+            "LIMIT_ROTATION" => Self::LimitRotation,
+            "LIMIT_SCALE" => Self::LimitScale,
+            "MAINTAIN_VOLUME" => Self::MaintainVolume,
+            "TRANSFORM" => Self::Transform,
+            "TRANSFORM_CACHE" => Self::TransformCache,
+            "CLAMP_TO" => Self::ClampTo,
+            "DAMPED_TRACK" => Self::DampedTrack,
+            "IK" => Self::Ik,
+            "LOCKED_TRACK" => Self::LockedTrack,
+            "SPLINE_IK" => Self::SplineIk,
+            "STRETCH_TO" => Self::StretchTo,
+            "TRACK_TO" => Self::TrackTo,
+            "ACTION" => Self::Action,
+            "ARMATURE" => Self::Armature,
+            "CHILD_OF" => Self::ChildOf,
+            "FLOOR" => Self::Floor,
+            "FOLLOW_PATH" => Self::FollowPath,
+            "PIVOT" => Self::Pivot,
+            "SHRINKWRAP" => Self::Shrinkwrap,
+            _ => Err(BlError::ValueError(format!("Invalid constraint type: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for ConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CameraSolver => write!(f, "CAMERA_SOLVER"),
+            Self::FollowTrack => write!(f, "FOLLOW_TRACK"),
+            Self::ObjectSolver => write!(f, "OBJECT_SOLVER"),
+            Self::CopyLocation => write!(f, "COPY_LOCATION"),
+            Self::CopyRotation => write!(f, "COPY_ROTATION"),
+            Self::CopyScale => write!(f, "COPY_SCALE"),
+            Self::CopyTransforms => write!(f, "COPY_TRANSFORMS"),
+            Self::LimitDistance => write!(f, "LIMIT_DISTANCE"),
+            Self::LimitLocation => write!(f, "LIMIT_LOCATION"),
+            Self::LimitRotation => write!(f, "LIMIT_ROTATION"),
+            Self::LimitScale => write!(f, "LIMIT_SCALE"),
+            Self::MaintainVolume => write!(f, "MAINTAIN_VOLUME"),
+            Self::Transform => write!(f, "TRANSFORM"),
+            Self::TransformCache => write!(f, "TRANSFORM_CACHE"),
+            Self::ClampTo => write!(f, "CLAMP_TO"),
+            Self::DampedTrack => write!(f, "DAMPED_TRACK"),
+            Self::Ik => write!(f, "IK"),
+            Self::LockedTrack => write!(f, "LOCKED_TRACK"),
+            Self::SplineIk => write!(f, "SPLINE_IK"),
+            Self::StretchTo => write!(f, "STRETCH_TO"),
+            Self::TrackTo => write!(f, "TRACK_TO"),
+            Self::Action => write!(f, "ACTION"),
+            Self::Armature => write!(f, "ARMATURE"),
+            Self::ChildOf => write!(f, "CHILD_OF"),
+            Self::Floor => write!(f, "FLOOR"),
+            Self::FollowPath => write!(f, "FOLLOW_PATH"),
+            Self::Pivot => write!(f, "PIVOT"),
+            Self::Shrinkwrap => write!(f, "SHRINKWRAP"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ConstraintType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for ConstraintType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,136 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/id_type_items.html>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DataBlockKind {
+    Action,
+    Armature,
+    Brush,
+    Camera,
+    Collection,
+    Curve,
+    Font,
+    Image,
+    Light,
+    LightProbe,
+    Material,
+    Mesh,
+    MetaBall,
+    NodeTree,
+    Object,
+    ParticleSettings,
+    PointCloud,
+    Scene,
+    Text,
+    Texture,
+    Volume,
+    World,
+}
+
+impl TryFrom<&str> for DataBlockKind {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "ACTION" => Self::Action,
+            "ARMATURE" => Self::Armature,
+            "BRUSH" => Self::Brush,
+            "CAMERA" => Self::Camera,
+            "COLLECTION" => Self::Collection,
+            "CURVE" => Self::Curve,
+            "FONT" => Self::Font,
+            "IMAGE" => Self::Image,
+            "LIGHT" => Self::Light,
+            "LIGHT_PROBE" => Self::LightProbe,
+            "MATERIAL" => Self::Material,
+            "MESH" => Self::Mesh,
+            "META" => Self::MetaBall,
+            "NODETREE" => Self::NodeTree,
+            "OBJECT" => Self::Object,
+            "PARTICLE" => Self::ParticleSettings,
+            "POINTCLOUD" => Self::PointCloud,
+            "SCENE" => Self::Scene,
+            "TEXT" => Self::Text,
+            "TEXTURE" => Self::Texture,
+            "VOLUME" => Self::Volume,
+            "WORLD" => Self::World,
+            _ => Err(BlError::ValueError(format!("Invalid data-block kind: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for DataBlockKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Action => write!(f, "ACTION"),
+            Self::Armature => write!(f, "ARMATURE"),
+            Self::Brush => write!(f, "BRUSH"),
+            Self::Camera => write!(f, "CAMERA"),
+            Self::Collection => write!(f, "COLLECTION"),
+            Self::Curve => write!(f, "CURVE"),
+            Self::Font => write!(f, "FONT"),
+            Self::Image => write!(f, "IMAGE"),
+            Self::Light => write!(f, "LIGHT"),
+            Self::LightProbe => write!(f, "LIGHT_PROBE"),
+            Self::Material => write!(f, "MATERIAL"),
+            Self::Mesh => write!(f, "MESH"),
+            Self::MetaBall => write!(f, "META"),
+            Self::NodeTree => write!(f, "NODETREE"),
+            Self::Object => write!(f, "OBJECT"),
+            Self::ParticleSettings => write!(f, "PARTICLE"),
+            Self::PointCloud => write!(f, "POINTCLOUD"),
+            Self::Scene => write!(f, "SCENE"),
+            Self::Text => write!(f, "TEXT"),
+            Self::Texture => write!(f, "TEXTURE"),
+            Self::Volume => write!(f, "VOLUME"),
+            Self::World => write!(f, "WORLD"),
+        }
+    }
+}
+
+impl DataBlockKind {
+    /// Returns the name of the collection in
+    /// [`bpy.types.BlendData`](https://docs.blender.org/api/latest/bpy.types.BlendData.html)
+    /// that holds data-blocks of this kind.
+    #[must_use]
+    pub const fn data_collection(&self) -> &'static str {
+        match self {
+            Self::Action => "actions",
+            Self::Armature => "armatures",
+            Self::Brush => "brushes",
+            Self::Camera => "cameras",
+            Self::Collection => "collections",
+            Self::Curve => "curves",
+            Self::Font => "fonts",
+            Self::Image => "images",
+            Self::Light => "lights",
+            Self::LightProbe => "lightprobes",
+            Self::Material => "materials",
+            Self::Mesh => "meshes",
+            Self::MetaBall => "metaballs",
+            Self::NodeTree => "node_groups",
+            Self::Object => "objects",
+            Self::ParticleSettings => "particles",
+            Self::PointCloud => "pointclouds",
+            Self::Scene => "scenes",
+            Self::Text => "texts",
+            Self::Texture => "textures",
+            Self::Volume => "volumes",
+            Self::World => "worlds",
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DataBlockKind {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for DataBlockKind {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
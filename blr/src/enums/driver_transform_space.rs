@@ -0,0 +1,48 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.DriverTarget.html#bpy.types.DriverTarget.transform_space>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DriverTransformSpace {
+    WorldSpace,
+    TransformSpace,
+    LocalSpace,
+}
+
+impl TryFrom<&str> for DriverTransformSpace {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "WORLD_SPACE" => Self::WorldSpace,
+            "TRANSFORM_SPACE" => Self::TransformSpace,
+            "LOCAL_SPACE" => Self::LocalSpace,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid driver transform space: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for DriverTransformSpace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::WorldSpace => write!(f, "WORLD_SPACE"),
+            Self::TransformSpace => write!(f, "TRANSFORM_SPACE"),
+            Self::LocalSpace => write!(f, "LOCAL_SPACE"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DriverTransformSpace {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for DriverTransformSpace {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,72 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.DriverTarget.html#bpy.types.DriverTarget.transform_type>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DriverTransformType {
+    LocX,
+    LocY,
+    LocZ,
+    RotX,
+    RotY,
+    RotZ,
+    RotW,
+    ScaleX,
+    ScaleY,
+    ScaleZ,
+    ScaleAvg,
+}
+
+impl TryFrom<&str> for DriverTransformType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "LOC_X" => Self::LocX,
+            "LOC_Y" => Self::LocY,
+            "LOC_Z" => Self::LocZ,
+            "ROT_X" => Self::RotX,
+            "ROT_Y" => Self::RotY,
+            "ROT_Z" => Self::RotZ,
+            "ROT_W" => Self::RotW,
+            "SCALE_X" => Self::ScaleX,
+            "SCALE_Y" => Self::ScaleY,
+            "SCALE_Z" => Self::ScaleZ,
+            "SCALE_AVG" => Self::ScaleAvg,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid driver transform type: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for DriverTransformType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::LocX => write!(f, "LOC_X"),
+            Self::LocY => write!(f, "LOC_Y"),
+            Self::LocZ => write!(f, "LOC_Z"),
+            Self::RotX => write!(f, "ROT_X"),
+            Self::RotY => write!(f, "ROT_Y"),
+            Self::RotZ => write!(f, "ROT_Z"),
+            Self::RotW => write!(f, "ROT_W"),
+            Self::ScaleX => write!(f, "SCALE_X"),
+            Self::ScaleY => write!(f, "SCALE_Y"),
+            Self::ScaleZ => write!(f, "SCALE_Z"),
+            Self::ScaleAvg => write!(f, "SCALE_AVG"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DriverTransformType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for DriverTransformType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.Driver.html#bpy.types.Driver.type>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DriverType {
+    Average,
+    Sum,
+    Scripted,
+    Min,
+    Max,
+}
+
+impl TryFrom<&str> for DriverType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "AVERAGE" => Self::Average,
+            "SUM" => Self::Sum,
+            "SCRIPTED" => Self::Scripted,
+            "MIN" => Self::Min,
+            "MAX" => Self::Max,
+            _ => Err(BlError::ValueError(format!("Invalid driver type: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for DriverType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Average => write!(f, "AVERAGE"),
+            Self::Sum => write!(f, "SUM"),
+            Self::Scripted => write!(f, "SCRIPTED"),
+            Self::Min => write!(f, "MIN"),
+            Self::Max => write!(f, "MAX"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DriverType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for DriverType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.DriverVariable.html#bpy.types.DriverVariable.type>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DriverVariableType {
+    SingleProp,
+    Transforms,
+    RotationDiff,
+    LocDiff,
+}
+
+impl TryFrom<&str> for DriverVariableType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "SINGLE_PROP" => Self::SingleProp,
+            "TRANSFORMS" => Self::Transforms,
+            "ROTATION_DIFF" => Self::RotationDiff,
+            "LOC_DIFF" => Self::LocDiff,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid driver variable type: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for DriverVariableType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::SingleProp => write!(f, "SINGLE_PROP"),
+            Self::Transforms => write!(f, "TRANSFORMS"),
+            Self::RotationDiff => write!(f, "ROTATION_DIFF"),
+            Self::LocDiff => write!(f, "LOC_DIFF"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DriverVariableType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for DriverVariableType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
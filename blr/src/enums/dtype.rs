@@ -26,6 +26,7 @@ impl TryFrom<&str> for Dtype {
 
     fn try_from(s: &str) -> Result<Self> {
         Ok(match s {
+            "BOOLEAN" => Self::Boolean,
             "COLLECTION" => Self::Collection,
             "CUSTOM" => Self::Custom,
             "GEOMETRY" => Self::Geometry,
@@ -0,0 +1,60 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/fmodifier_type_items.html>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FModifierType {
+    Generator,
+    FnGenerator,
+    Envelope,
+    Cycles,
+    Noise,
+    Limits,
+    Stepped,
+}
+
+impl TryFrom<&str> for FModifierType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "GENERATOR" => Self::Generator,
+            "FNGENERATOR" => Self::FnGenerator,
+            "ENVELOPE" => Self::Envelope,
+            "CYCLES" => Self::Cycles,
+            "NOISE" => Self::Noise,
+            "LIMITS" => Self::Limits,
+            "STEPPED" => Self::Stepped,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid F-curve modifier type: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for FModifierType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Generator => write!(f, "GENERATOR"),
+            Self::FnGenerator => write!(f, "FNGENERATOR"),
+            Self::Envelope => write!(f, "ENVELOPE"),
+            Self::Cycles => write!(f, "CYCLES"),
+            Self::Noise => write!(f, "NOISE"),
+            Self::Limits => write!(f, "LIMITS"),
+            Self::Stepped => write!(f, "STEPPED"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for FModifierType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for FModifierType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,108 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/image_type_items.html>
+/// (only formats that can be written as still images)
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ImageFileFormat {
+    Bmp,
+    Iris,
+    Png,
+    Jpeg,
+    Jpeg2000,
+    Targa,
+    TargaRaw,
+    Cineon,
+    Dpx,
+    OpenExrMultilayer,
+    OpenExr,
+    Hdr,
+    Tiff,
+    Webp,
+}
+
+impl ImageFileFormat {
+    /// File extensions of the format (the first one is used for new files).
+    #[must_use]
+    pub const fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            Self::Bmp => &["bmp"],
+            Self::Iris => &["rgb", "sgi"],
+            Self::Png => &["png"],
+            Self::Jpeg => &["jpg", "jpeg"],
+            Self::Jpeg2000 => &["jp2", "j2c"],
+            Self::Targa | Self::TargaRaw => &["tga"],
+            Self::Cineon => &["cin"],
+            Self::Dpx => &["dpx"],
+            Self::OpenExrMultilayer | Self::OpenExr => &["exr"],
+            Self::Hdr => &["hdr"],
+            Self::Tiff => &["tif", "tiff"],
+            Self::Webp => &["webp"],
+        }
+    }
+
+    #[must_use]
+    pub const fn extension(&self) -> &'static str {
+        self.extensions()[0]
+    }
+}
+
+impl TryFrom<&str> for ImageFileFormat {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "BMP" => Self::Bmp,
+            "IRIS" => Self::Iris,
+            "PNG" => Self::Png,
+            "JPEG" => Self::Jpeg,
+            "JPEG2000" => Self::Jpeg2000,
+            "TARGA" => Self::Targa,
+            "TARGA_RAW" => Self::TargaRaw,
+            "CINEON" => Self::Cineon,
+            "DPX" => Self::Dpx,
+            "OPEN_EXR_MULTILAYER" => Self::OpenExrMultilayer,
+            "OPEN_EXR" => Self::OpenExr,
+            "HDR" => Self::Hdr,
+            "TIFF" => Self::Tiff,
+            "WEBP" => Self::Webp,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid image file format: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for ImageFileFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bmp => write!(f, "BMP"),
+            Self::Iris => write!(f, "IRIS"),
+            Self::Png => write!(f, "PNG"),
+            Self::Jpeg => write!(f, "JPEG"),
+            Self::Jpeg2000 => write!(f, "JPEG2000"),
+            Self::Targa => write!(f, "TARGA"),
+            Self::TargaRaw => write!(f, "TARGA_RAW"),
+            Self::Cineon => write!(f, "CINEON"),
+            Self::Dpx => write!(f, "DPX"),
+            Self::OpenExrMultilayer => write!(f, "OPEN_EXR_MULTILAYER"),
+            Self::OpenExr => write!(f, "OPEN_EXR"),
+            Self::Hdr => write!(f, "HDR"),
+            Self::Tiff => write!(f, "TIFF"),
+            Self::Webp => write!(f, "WEBP"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ImageFileFormat {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for ImageFileFormat {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/beztriple_interpolation_easing_items.html>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyframeEasing {
+    Auto,
+    EaseIn,
+    EaseOut,
+    EaseInOut,
+}
+
+impl TryFrom<&str> for KeyframeEasing {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "AUTO" => Self::Auto,
+            "EASE_IN" => Self::EaseIn,
+            "EASE_OUT" => Self::EaseOut,
+            "EASE_IN_OUT" => Self::EaseInOut,
+            _ => Err(BlError::ValueError(format!("Invalid keyframe easing: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for KeyframeEasing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Auto => write!(f, "AUTO"),
+            Self::EaseIn => write!(f, "EASE_IN"),
+            Self::EaseOut => write!(f, "EASE_OUT"),
+            Self::EaseInOut => write!(f, "EASE_IN_OUT"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for KeyframeEasing {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for KeyframeEasing {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,81 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/beztriple_interpolation_mode_items.html>
+///
+/// The discriminants are the integer values of the items in Blender (`eBezTriple_Interpolation`),
+/// which are used by `foreach_get` and `foreach_set` and differ from the order in the UI.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeyframeInterpolation {
+    Constant = 0,
+    Linear = 1,
+    Bezier = 2,
+    Sine = 12,
+    Quad = 9,
+    Cubic = 6,
+    Quart = 10,
+    Quint = 11,
+    Expo = 8,
+    Circ = 5,
+    Back = 3,
+    Bounce = 4,
+    Elastic = 7,
+}
+
+impl TryFrom<&str> for KeyframeInterpolation {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "CONSTANT" => Self::Constant,
+            "LINEAR" => Self::Linear,
+            "BEZIER" => Self::Bezier,
+            "SINE" => Self::Sine,
+            "QUAD" => Self::Quad,
+            "CUBIC" => Self::Cubic,
+            "QUART" => Self::Quart,
+            "QUINT" => Self::Quint,
+            "EXPO" => Self::Expo,
+            "CIRC" => Self::Circ,
+            "BACK" => Self::Back,
+            "BOUNCE" => Self::Bounce,
+            "ELASTIC" => Self::Elastic,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid keyframe interpolation: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for KeyframeInterpolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constant => write!(f, "CONSTANT"),
+            Self::Linear => write!(f, "LINEAR"),
+            Self::Bezier => write!(f, "BEZIER"),
+            Self::Sine => write!(f, "SINE"),
+            Self::Quad => write!(f, "QUAD"),
+            Self::Cubic => write!(f, "CUBIC"),
+            Self::Quart => write!(f, "QUART"),
+            Self::Quint => write!(f, "QUINT"),
+            Self::Expo => write!(f, "EXPO"),
+            Self::Circ => write!(f, "CIRC"),
+            Self::Back => write!(f, "BACK"),
+            Self::Bounce => write!(f, "BOUNCE"),
+            Self::Elastic => write!(f, "ELASTIC"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for KeyframeInterpolation {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for KeyframeInterpolation {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,51 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.AreaLight.html#bpy.types.AreaLight.shape>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LightAreaShape {
+    Square,
+    Rectangle,
+    Disk,
+    Ellipse,
+}
+
+impl TryFrom<&str> for LightAreaShape {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "SQUARE" => Self::Square,
+            "RECTANGLE" => Self::Rectangle,
+            "DISK" => Self::Disk,
+            "ELLIPSE" => Self::Ellipse,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid area light shape: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for LightAreaShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Square => write!(f, "SQUARE"),
+            Self::Rectangle => write!(f, "RECTANGLE"),
+            Self::Disk => write!(f, "DISK"),
+            Self::Ellipse => write!(f, "ELLIPSE"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for LightAreaShape {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for LightAreaShape {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
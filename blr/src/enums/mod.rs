@@ -1,10 +1,28 @@
 //! Idiomatic wrappers around Blender [enums](https://docs.blender.org/api/latest/bpy_types_enum_items/index.html).
 
 pub mod alignment;
+pub mod attribute_domain;
+pub mod attribute_type;
 pub mod axis_xyz;
+pub mod bake_type;
+pub mod camera_sensor_fit;
+pub mod camera_type;
+pub mod color_depth;
+pub mod constraint_space;
+pub mod constraint_type;
 pub mod context_mode;
+pub mod data_block_kind;
 pub mod direction_xyz;
+pub mod driver_transform_space;
+pub mod driver_transform_type;
+pub mod driver_type;
+pub mod driver_variable_type;
 pub mod dtype;
+pub mod fmodifier_type;
+pub mod image_file_format;
+pub mod keyframe_easing;
+pub mod keyframe_interpolation;
+pub mod light_area_shape;
 pub mod light_type;
 pub mod mesh_select_mode;
 pub mod modifier_type;
@@ -13,18 +31,44 @@ pub mod object_mode;
 pub mod object_type;
 pub mod origin_center;
 pub mod origin_type;
+pub mod render_engine;
 pub mod render_variant;
+pub mod rigid_body_collision_shape;
+pub mod rigid_body_constraint_type;
+pub mod rigid_body_type;
+pub mod rotation_mode;
+pub mod shape_key_interpolation;
 pub mod snap_element;
 pub mod text_alignment_horizontal;
 pub mod text_alignment_vertical;
 pub mod text_overflow;
+pub mod track_axis;
 pub mod transform_orientation;
+pub mod vertex_group_assign_mode;
 
 pub use alignment::Alignment;
+pub use attribute_domain::AttributeDomain;
+pub use attribute_type::AttributeType;
 pub use axis_xyz::AxisXYZ;
+pub use bake_type::BakeType;
+pub use camera_sensor_fit::CameraSensorFit;
+pub use camera_type::CameraType;
+pub use color_depth::ColorDepth;
+pub use constraint_space::ConstraintSpace;
+pub use constraint_type::ConstraintType;
 pub use context_mode::ContextMode;
+pub use data_block_kind::DataBlockKind;
 pub use direction_xyz::DirectionXYZ;
+pub use driver_transform_space::DriverTransformSpace;
+pub use driver_transform_type::DriverTransformType;
+pub use driver_type::DriverType;
+pub use driver_variable_type::DriverVariableType;
 pub use dtype::Dtype;
+pub use fmodifier_type::FModifierType;
+pub use image_file_format::ImageFileFormat;
+pub use keyframe_easing::KeyframeEasing;
+pub use keyframe_interpolation::KeyframeInterpolation;
+pub use light_area_shape::LightAreaShape;
 pub use light_type::LightType;
 pub use mesh_select_mode::MeshSelectMode;
 pub use modifier_type::ModifierType;
@@ -33,9 +77,17 @@ pub use object_mode::ObjectMode;
 pub use object_type::ObjectType;
 pub use origin_center::OriginCenter;
 pub use origin_type::OriginType;
+pub use render_engine::RenderEngine;
 pub use render_variant::RenderVariant;
+pub use rigid_body_collision_shape::RigidBodyCollisionShape;
+pub use rigid_body_constraint_type::RigidBodyConstraintType;
+pub use rigid_body_type::RigidBodyType;
+pub use rotation_mode::RotationMode;
+pub use shape_key_interpolation::ShapeKeyInterpolation;
 pub use snap_element::SnapElement;
 pub use text_alignment_horizontal::TextAlignmentHorizontal;
 pub use text_alignment_vertical::TextAlignmentVertical;
 pub use text_overflow::TextOverflow;
+pub use track_axis::TrackAxis;
 pub use transform_orientation::TransformOrientation;
+pub use vertex_group_assign_mode::VertexGroupAssignMode;
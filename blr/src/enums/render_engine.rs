@@ -0,0 +1,80 @@
+use crate::{bpy, result::Result, BlError};
+use pyo3::Python;
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.RenderSettings.html#bpy.types.RenderSettings.engine>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RenderEngine {
+    BlenderEevee,
+    /// Identifier of EEVEE from Blender 4.2 until 5.0, see [`RenderEngine::eevee`].
+    BlenderEeveeNext,
+    BlenderWorkbench,
+    Cycles,
+}
+
+impl RenderEngine {
+    /// Returns the identifier of EEVEE in the running version of Blender, which is
+    /// `BLENDER_EEVEE_NEXT` from Blender 4.2 until 5.0 and `BLENDER_EEVEE` otherwise.
+    pub fn eevee(py: Python) -> Result<Self> {
+        let [major, minor, _] = bpy::app::version(py)?;
+        Ok(if (4, 2) <= (major, minor) && major < 5 {
+            Self::BlenderEeveeNext
+        } else {
+            Self::BlenderEevee
+        })
+    }
+
+    /// Returns `true` for both identifiers of EEVEE.
+    #[must_use]
+    pub const fn is_eevee(&self) -> bool {
+        matches!(self, Self::BlenderEevee | Self::BlenderEeveeNext)
+    }
+
+    /// Returns the engine with the identifier of the running version of Blender, i.e., EEVEE is
+    /// mapped to the identifier returned by [`RenderEngine::eevee`].
+    pub fn resolve(self, py: Python) -> Result<Self> {
+        if self.is_eevee() {
+            Self::eevee(py)
+        } else {
+            Ok(self)
+        }
+    }
+}
+
+impl TryFrom<&str> for RenderEngine {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "BLENDER_EEVEE" => Self::BlenderEevee,
+            "BLENDER_EEVEE_NEXT" => Self::BlenderEeveeNext,
+            "BLENDER_WORKBENCH" => Self::BlenderWorkbench,
+            "CYCLES" => Self::Cycles,
+            _ => Err(BlError::ValueError(format!("Invalid render engine: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for RenderEngine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BlenderEevee => write!(f, "BLENDER_EEVEE"),
+            Self::BlenderEeveeNext => write!(f, "BLENDER_EEVEE_NEXT"),
+            Self::BlenderWorkbench => write!(f, "BLENDER_WORKBENCH"),
+            Self::Cycles => write!(f, "CYCLES"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for RenderEngine {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for RenderEngine {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
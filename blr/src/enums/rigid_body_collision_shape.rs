@@ -0,0 +1,63 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.RigidBodyObject.html#bpy.types.RigidBodyObject.collision_shape>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RigidBodyCollisionShape {
+    Box,
+    Sphere,
+    Capsule,
+    Cylinder,
+    Cone,
+    ConvexHull,
+    Mesh,
+    Compound,
+}
+
+impl TryFrom<&str> for RigidBodyCollisionShape {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "BOX" => Self::Box,
+            "SPHERE" => Self::Sphere,
+            "CAPSULE" => Self::Capsule,
+            "CYLINDER" => Self::Cylinder,
+            "CONE" => Self::Cone,
+            "CONVEX_HULL" => Self::ConvexHull,
+            "MESH" => Self::Mesh,
+            "COMPOUND" => Self::Compound,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid rigid body collision shape: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for RigidBodyCollisionShape {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Box => write!(f, "BOX"),
+            Self::Sphere => write!(f, "SPHERE"),
+            Self::Capsule => write!(f, "CAPSULE"),
+            Self::Cylinder => write!(f, "CYLINDER"),
+            Self::Cone => write!(f, "CONE"),
+            Self::ConvexHull => write!(f, "CONVEX_HULL"),
+            Self::Mesh => write!(f, "MESH"),
+            Self::Compound => write!(f, "COMPOUND"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for RigidBodyCollisionShape {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for RigidBodyCollisionShape {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
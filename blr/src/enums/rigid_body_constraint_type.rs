@@ -0,0 +1,63 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.RigidBodyConstraint.html#bpy.types.RigidBodyConstraint.type>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RigidBodyConstraintType {
+    Fixed,
+    Point,
+    Hinge,
+    Slider,
+    Piston,
+    Generic,
+    GenericSpring,
+    Motor,
+}
+
+impl TryFrom<&str> for RigidBodyConstraintType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "FIXED" => Self::Fixed,
+            "POINT" => Self::Point,
+            "HINGE" => Self::Hinge,
+            "SLIDER" => Self::Slider,
+            "PISTON" => Self::Piston,
+            "GENERIC" => Self::Generic,
+            "GENERIC_SPRING" => Self::GenericSpring,
+            "MOTOR" => Self::Motor,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid rigid body constraint type: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for RigidBodyConstraintType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed => write!(f, "FIXED"),
+            Self::Point => write!(f, "POINT"),
+            Self::Hinge => write!(f, "HINGE"),
+            Self::Slider => write!(f, "SLIDER"),
+            Self::Piston => write!(f, "PISTON"),
+            Self::Generic => write!(f, "GENERIC"),
+            Self::GenericSpring => write!(f, "GENERIC_SPRING"),
+            Self::Motor => write!(f, "MOTOR"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for RigidBodyConstraintType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for RigidBodyConstraintType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
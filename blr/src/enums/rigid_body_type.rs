@@ -0,0 +1,43 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.RigidBodyObject.html#bpy.types.RigidBodyObject.type>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RigidBodyType {
+    Active,
+    Passive,
+}
+
+impl TryFrom<&str> for RigidBodyType {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "ACTIVE" => Self::Active,
+            "PASSIVE" => Self::Passive,
+            _ => Err(BlError::ValueError(format!("Invalid rigid body type: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for RigidBodyType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Active => write!(f, "ACTIVE"),
+            Self::Passive => write!(f, "PASSIVE"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for RigidBodyType {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for RigidBodyType {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,61 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy_types_enum_items/object_rotation_mode_items.html>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RotationMode {
+    Quaternion,
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+    AxisAngle,
+}
+
+impl TryFrom<&str> for RotationMode {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "QUATERNION" => Self::Quaternion,
+            "XYZ" => Self::Xyz,
+            "XZY" => Self::Xzy,
+            "YXZ" => Self::Yxz,
+            "YZX" => Self::Yzx,
+            "ZXY" => Self::Zxy,
+            "ZYX" => Self::Zyx,
+            "AXIS_ANGLE" => Self::AxisAngle,
+            _ => Err(BlError::ValueError(format!("Invalid rotation mode: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for RotationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Quaternion => write!(f, "QUATERNION"),
+            Self::Xyz => write!(f, "XYZ"),
+            Self::Xzy => write!(f, "XZY"),
+            Self::Yxz => write!(f, "YXZ"),
+            Self::Yzx => write!(f, "YZX"),
+            Self::Zxy => write!(f, "ZXY"),
+            Self::Zyx => write!(f, "ZYX"),
+            Self::AxisAngle => write!(f, "AXIS_ANGLE"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for RotationMode {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for RotationMode {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
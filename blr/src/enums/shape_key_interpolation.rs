@@ -0,0 +1,51 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.ShapeKey.html#bpy.types.ShapeKey.interpolation>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShapeKeyInterpolation {
+    Linear,
+    Cardinal,
+    CatmullRom,
+    Bspline,
+}
+
+impl TryFrom<&str> for ShapeKeyInterpolation {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "KEY_LINEAR" => Self::Linear,
+            "KEY_CARDINAL" => Self::Cardinal,
+            "KEY_CATMULL_ROM" => Self::CatmullRom,
+            "KEY_BSPLINE" => Self::Bspline,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid interpolation of shape keys: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for ShapeKeyInterpolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Linear => write!(f, "KEY_LINEAR"),
+            Self::Cardinal => write!(f, "KEY_CARDINAL"),
+            Self::CatmullRom => write!(f, "KEY_CATMULL_ROM"),
+            Self::Bspline => write!(f, "KEY_BSPLINE"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ShapeKeyInterpolation {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for ShapeKeyInterpolation {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,55 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.TrackToConstraint.html#bpy.types.TrackToConstraint.track_axis>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TrackAxis {
+    X,
+    Y,
+    Z,
+    NegativeX,
+    NegativeY,
+    NegativeZ,
+}
+
+impl TryFrom<&str> for TrackAxis {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "TRACK_X" => Self::X,
+            "TRACK_Y" => Self::Y,
+            "TRACK_Z" => Self::Z,
+            "TRACK_NEGATIVE_X" => Self::NegativeX,
+            "TRACK_NEGATIVE_Y" => Self::NegativeY,
+            "TRACK_NEGATIVE_Z" => Self::NegativeZ,
+            _ => Err(BlError::ValueError(format!("Invalid track axis: {s}")))?,
+        })
+    }
+}
+
+impl fmt::Display for TrackAxis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::X => write!(f, "TRACK_X"),
+            Self::Y => write!(f, "TRACK_Y"),
+            Self::Z => write!(f, "TRACK_Z"),
+            Self::NegativeX => write!(f, "TRACK_NEGATIVE_X"),
+            Self::NegativeY => write!(f, "TRACK_NEGATIVE_Y"),
+            Self::NegativeZ => write!(f, "TRACK_NEGATIVE_Z"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for TrackAxis {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for TrackAxis {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::{result::Result, BlError};
+use std::fmt;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.VertexGroup.html#bpy.types.VertexGroup.add>
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VertexGroupAssignMode {
+    Replace,
+    Add,
+    Subtract,
+}
+
+impl TryFrom<&str> for VertexGroupAssignMode {
+    type Error = BlError;
+
+    fn try_from(s: &str) -> Result<Self> {
+        Ok(match s {
+            "REPLACE" => Self::Replace,
+            "ADD" => Self::Add,
+            "SUBTRACT" => Self::Subtract,
+            _ => Err(BlError::ValueError(format!(
+                "Invalid assign mode of vertex groups: {s}"
+            )))?,
+        })
+    }
+}
+
+impl fmt::Display for VertexGroupAssignMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Replace => write!(f, "REPLACE"),
+            Self::Add => write!(f, "ADD"),
+            Self::Subtract => write!(f, "SUBTRACT"),
+        }
+    }
+}
+
+impl pyo3::FromPyObject<'_> for VertexGroupAssignMode {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(value.str()?.to_str()?.try_into()?)
+    }
+}
+
+impl pyo3::ToPyObject for VertexGroupAssignMode {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.to_string().to_object(py)
+    }
+}
@@ -1,6 +1,13 @@
 //! Utilities for exporting models and scenes.
 
-use crate::{bpy, enums::DirectionXYZ, error::BlError, macros::enum_wrap_inner_fn, result::Result};
+use crate::{
+    bpy,
+    enums::DirectionXYZ,
+    error::BlError,
+    macros::enum_wrap_inner_fn,
+    result::Result,
+    types::{CollectionImpl, Object, SelectionGuard},
+};
 use builder_derive_more::IntoBuilder;
 use derive_builder::Builder;
 use pyo3::{
@@ -21,6 +28,17 @@ pub trait BlendExport: IntoPyDict + Sized {
 
     fn fn_export(py: Python) -> Result<&PyAny>;
 
+    /// Limits the export to the selected objects, regardless of how the exporter names this option.
+    ///
+    /// The default implementation returns an error for exporters without such an option, so that
+    /// [`BlendExport::export_scoped`] never silently exports the whole scene.
+    fn set_use_selection(&mut self, _value: bool) -> Result<()> {
+        Err(BlError::ValueError(format!(
+            "Exporter for '{}' files cannot limit the export to selected objects",
+            self.extension()
+        )))
+    }
+
     fn export(self, filepath: impl AsRef<Path>) -> Result<PathBuf> {
         let filepath = self.check_export_filepath(filepath)?;
 
@@ -35,6 +53,31 @@ pub trait BlendExport: IntoPyDict + Sized {
         Ok(filepath)
     }
 
+    /// Exports only the given `objects`, see [`BlendExport::export_scoped`].
+    fn export_objects(self, objects: &[Object], filepath: impl AsRef<Path>) -> Result<PathBuf> {
+        self.export_scoped(&ExportScope::Objects(objects.to_vec()), filepath)
+    }
+
+    /// Exports only the objects within the given `scope`. The selected and active objects are
+    /// restored once the export finishes.
+    fn export_scoped(mut self, scope: &ExportScope, filepath: impl AsRef<Path>) -> Result<PathBuf> {
+        self.set_use_selection(true)?;
+
+        Python::with_gil(|py| -> Result<PathBuf> {
+            let selection = SelectionGuard::new(py)?;
+            match scope {
+                ExportScope::Selection => {}
+                ExportScope::Objects(objects) => selection.select_only(py, objects)?,
+                ExportScope::Collection(name) => {
+                    let collections = bpy::data::collections(py)?;
+                    let objects = collections.get(py, name)?.all_objects(py)?;
+                    selection.select_only(py, &objects)?;
+                }
+            }
+            self.export(filepath)
+        })
+    }
+
     fn check_export_filepath(&self, filepath: impl AsRef<Path>) -> Result<PathBuf> {
         let filepath = filepath.as_ref();
 
@@ -67,6 +110,17 @@ pub trait BlendExport: IntoPyDict + Sized {
     }
 }
 
+/// Objects that an export is limited to.
+#[derive(Debug, Clone)]
+pub enum ExportScope {
+    /// Objects that are currently selected.
+    Selection,
+    /// Only the given objects.
+    Objects(Vec<Object>),
+    /// All objects of the collection with the given name, including nested collections.
+    Collection(String),
+}
+
 pub enum BlendExporter {
     Abc(AbcExporter),
     Dae(DaeExporter),
@@ -145,6 +199,22 @@ impl BlendExporter {
             Self::Ply, Self::Stl, Self::Usd, Self::X3d,
         ]
     }
+
+    enum_wrap_inner_fn! {
+        { pub fn export_objects(self, objects: &[Object], filepath: impl AsRef<Path>) -> Result<PathBuf> }
+        for [
+            Self::Abc, Self::Dae, Self::Fbx, Self::Gltf, Self::Obj,
+            Self::Ply, Self::Stl, Self::Usd, Self::X3d,
+        ]
+    }
+
+    enum_wrap_inner_fn! {
+        { pub fn export_scoped(self, scope: &ExportScope, filepath: impl AsRef<Path>) -> Result<PathBuf> }
+        for [
+            Self::Abc, Self::Dae, Self::Fbx, Self::Gltf, Self::Obj,
+            Self::Ply, Self::Stl, Self::Usd, Self::X3d,
+        ]
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.wm.html>
@@ -238,6 +308,11 @@ impl BlendExport for AbcExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::wm::fn_alembic_export(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.selected = value;
+        Ok(())
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.wm.html>
@@ -339,6 +414,11 @@ impl BlendExport for DaeExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::wm::fn_collada_export(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.selected = value;
+        Ok(())
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.export_scene.html>
@@ -461,6 +541,11 @@ impl BlendExport for FbxExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::export_scene::fn_fbx(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.use_selection = value;
+        Ok(())
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.export_scene.html>
@@ -635,6 +720,11 @@ impl BlendExport for GltfExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::export_scene::fn_gltf(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.use_selection = value;
+        Ok(())
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.export_scene.html>
@@ -708,6 +798,11 @@ impl BlendExport for ObjExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::export_scene::fn_obj(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.use_selection = value;
+        Ok(())
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.export_mesh.html>
@@ -758,6 +853,11 @@ impl BlendExport for PlyExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::export_mesh::fn_ply(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.use_selection = value;
+        Ok(())
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.export_mesh.html>
@@ -814,6 +914,11 @@ impl BlendExport for StlExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::export_mesh::fn_stl(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.use_selection = value;
+        Ok(())
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.wm.html>
@@ -874,6 +979,11 @@ impl BlendExport for UsdExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::wm::fn_usd_export(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.selected_objects_only = value;
+        Ok(())
+    }
 }
 
 /// <https://docs.blender.org/api/latest/bpy.ops.export_scene.html>
@@ -931,4 +1041,9 @@ impl BlendExport for X3dExporter {
     fn fn_export(py: Python) -> Result<&PyAny> {
         Ok(bpy::ops::export_scene::fn_x3d(py)?)
     }
+
+    fn set_use_selection(&mut self, value: bool) -> Result<()> {
+        self.use_selection = value;
+        Ok(())
+    }
 }
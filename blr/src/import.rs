@@ -1,33 +1,41 @@
 //! Utilities for importing models and scenes.
 
-use crate::{bpy, enums::DirectionXYZ, error::BlError, macros::enum_wrap_inner_fn, result::Result};
+use crate::{
+    bpy,
+    enums::DirectionXYZ,
+    error::BlError,
+    macros::enum_wrap_inner_fn,
+    result::Result,
+    types::{CollectionImpl, Image, Material, Mesh, Object, SceneCollection},
+};
 use builder_derive_more::IntoBuilder;
 use derive_builder::Builder;
 use pyo3::{
+    exceptions::PyKeyError,
     intern,
     types::{IntoPyDict, PyAny},
-    Python,
+    PyObject, Python,
 };
-use std::{convert::From, fmt::Debug, path::Path};
+use std::{collections::HashSet, convert::From, fmt::Debug, path::Path};
 
 pub trait BlendImport: IntoPyDict + Sized {
     const EXTENSIONS: &'static [&'static str];
 
     fn fn_import(py: Python) -> Result<&PyAny>;
 
-    fn import(self, filepath: impl AsRef<Path>) -> Result<()> {
+    fn import(self, filepath: impl AsRef<Path>) -> Result<ImportResult> {
         let filepath = filepath.as_ref();
         self.check_import_filepath(filepath)?;
 
-        Python::with_gil(|py| -> Result<()> {
+        Python::with_gil(|py| -> Result<ImportResult> {
+            let snapshot = DataSnapshot::capture(py)?;
+
             let kwargs = self.into_py_dict(py);
             kwargs.set_item(intern!(py, "filepath"), filepath)?;
-
             Self::fn_import(py)?.call((), Some(kwargs))?;
-            Ok(())
-        })?;
 
-        Ok(())
+            snapshot.new_data_blocks(py)
+        })
     }
 
     fn check_import_filepath(&self, filepath: impl AsRef<Path>) -> Result<()> {
@@ -43,6 +51,153 @@ pub trait BlendImport: IntoPyDict + Sized {
     }
 }
 
+/// Data-blocks that were created by an importer.
+#[derive(Debug, Clone, Default)]
+pub struct ImportResult {
+    pub objects: Vec<Object>,
+    pub meshes: Vec<Mesh>,
+    pub materials: Vec<Material>,
+    pub images: Vec<Image>,
+}
+
+impl ImportResult {
+    /// Returns the imported objects that have no imported parent.
+    pub fn root_objects(&self, py: Python) -> Result<Vec<Object>> {
+        let pointers = self
+            .objects
+            .iter()
+            .map(|object| data_pointer(object.as_ref(py)))
+            .collect::<Result<HashSet<_>>>()?;
+        let mut root_objects = Vec::new();
+        for object in &self.objects {
+            let parent = object.getattr(py, intern!(py, "parent"))?;
+            if parent.is_none(py) || !pointers.contains(&data_pointer(parent.as_ref(py))?) {
+                root_objects.push(object.clone());
+            }
+        }
+        Ok(root_objects)
+    }
+
+    /// Moves all imported objects into collection `name`, which is created if it does not exist
+    /// yet. The collection is linked to the current scene unless it is already part of it. The
+    /// objects are unlinked from all other collections.
+    pub fn move_to_collection(&self, py: Python, name: &str) -> Result<SceneCollection> {
+        let scene_collection = SceneCollection::from_scene(py)?;
+        let collection = match bpy::data::collections(py)?.get(py, name) {
+            Ok(collection) => {
+                let mut is_in_scene = false;
+                for child in scene_collection.children_recursive(py)? {
+                    is_in_scene |= child.as_ref(py).eq(collection.as_ref(py))?;
+                }
+                if !is_in_scene {
+                    scene_collection.children(py)?.link(py, &collection)?;
+                }
+                collection
+            }
+            Err(err) if err.is_instance_of::<PyKeyError>(py) => {
+                scene_collection.new_child(py, name)?
+            }
+            Err(err) => return Err(err.into()),
+        };
+        for object in &self.objects {
+            object.move_to_collection(py, &collection)?;
+        }
+        Ok(collection)
+    }
+
+    /// Parents all imported root objects (see [`ImportResult::root_objects`]) under a new empty
+    /// object `name` with the given transform. The empty is linked to the collections of the
+    /// first root object, or to the collection of the current scene if there are none.
+    pub fn parent_to_empty(
+        &self,
+        py: Python,
+        name: &str,
+        location: [f32; 3],
+        rotation: [f32; 3],
+        scale: [f32; 3],
+    ) -> Result<Object> {
+        let mut empty: Object = bpy::data::objects(py)?
+            .call_method1(py, intern!(py, "new"), (name, py.None()))?
+            .into();
+        empty.set_location(py, location)?;
+        empty.set_rotation_euler(py, rotation)?;
+        empty.set_scale(py, scale)?;
+
+        let root_objects = self.root_objects(py)?;
+        let users_collection = match root_objects.first() {
+            Some(object) => object.users_collection(py)?,
+            None => Vec::new(),
+        };
+        if users_collection.is_empty() {
+            SceneCollection::from_scene(py)?
+                .objects(py)?
+                .link(py, &empty)?;
+        } else {
+            for collection in users_collection {
+                collection.objects(py)?.link(py, &empty)?;
+            }
+        }
+
+        for mut object in root_objects {
+            object.set_parent(py, &empty)?;
+        }
+
+        Ok(empty)
+    }
+}
+
+/// Pointers to all data-blocks that exist before importing, so that the new ones can be found.
+struct DataSnapshot {
+    objects: HashSet<usize>,
+    meshes: HashSet<usize>,
+    materials: HashSet<usize>,
+    images: HashSet<usize>,
+}
+
+impl DataSnapshot {
+    fn capture(py: Python) -> Result<Self> {
+        Ok(Self {
+            objects: data_pointers(bpy::data::objects(py)?.as_ref(py))?,
+            meshes: data_pointers(bpy::data::meshes(py)?.as_ref(py))?,
+            materials: data_pointers(bpy::data::materials(py)?.as_ref(py))?,
+            images: data_pointers(bpy::data::images(py)?.as_ref(py))?,
+        })
+    }
+
+    fn new_data_blocks(&self, py: Python) -> Result<ImportResult> {
+        Ok(ImportResult {
+            objects: new_data_blocks(bpy::data::objects(py)?.as_ref(py), &self.objects)?,
+            meshes: new_data_blocks(bpy::data::meshes(py)?.as_ref(py), &self.meshes)?,
+            materials: new_data_blocks(bpy::data::materials(py)?.as_ref(py), &self.materials)?,
+            images: new_data_blocks(bpy::data::images(py)?.as_ref(py), &self.images)?,
+        })
+    }
+}
+
+fn data_pointer(data_block: &PyAny) -> Result<usize> {
+    Ok(data_block
+        .call_method0(intern!(data_block.py(), "as_pointer"))?
+        .extract()?)
+}
+
+fn data_pointers(collection: &PyAny) -> Result<HashSet<usize>> {
+    collection.iter()?.map(|item| data_pointer(item?)).collect()
+}
+
+fn new_data_blocks<T: From<PyObject>>(
+    collection: &PyAny,
+    existing: &HashSet<usize>,
+) -> Result<Vec<T>> {
+    let mut data_blocks = Vec::new();
+    for item in collection.iter()? {
+        let item = item?;
+        if !existing.contains(&data_pointer(item)?) {
+            data_blocks.push(PyObject::from(item).into());
+        }
+    }
+    Ok(data_blocks)
+}
+
 pub enum BlendImporter {
     Abc(AbcImporter),
     Dae(DaeImporter),
@@ -107,7 +262,7 @@ impl BlendImporter {
     }
 
     enum_wrap_inner_fn! {
-        { pub fn import(self, filepath: impl AsRef<Path>) -> Result<()> }
+        { pub fn import(self, filepath: impl AsRef<Path>) -> Result<ImportResult> }
         for [
             Self::Abc, Self::Dae, Self::Fbx, Self::Gltf, Self::Obj,
             Self::Ply, Self::Stl, Self::Svg, Self::Usd, Self::X3d,
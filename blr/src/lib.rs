@@ -1,9 +1,12 @@
 //! Rust bindings for the Python API of Blender.
+pub mod bake;
 pub mod bpy;
 pub mod enums;
 pub mod export;
 pub mod import;
+pub mod library;
 pub mod project;
+pub mod render;
 pub mod types;
 pub mod utils;
 
@@ -13,7 +16,9 @@ pub mod prelude {
     pub use crate::error::BlError;
     pub use crate::project::BlendProject;
     pub use crate::result::BlResult;
-    pub use crate::types::{BpyID, BpyStruct, CollectionImpl, CurveImpl, ModifierImpl};
+    pub use crate::types::{
+        BpyID, BpyStruct, CollectionImpl, ConstraintImpl, CurveImpl, DataBlock, ModifierImpl,
+    };
     pub use crate::{
         utils::thread_safety::{bpy_thread_id, is_current_thread_bpy_safe},
         version::{bpy_version, bpy_version_major, bpy_version_minor, bpy_version_patch},
@@ -24,6 +29,8 @@ pub use enums::*;
 pub use project::BlendProject;
 pub use types::*;
 pub(crate) use utils::macros;
+#[cfg(feature = "mock-bpy")]
+pub use utils::mock_bpy;
 pub use utils::{
     error::{self, BlError},
     result::{self, BlResult},
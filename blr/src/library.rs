@@ -0,0 +1,223 @@
+//! Utilities for appending and linking data-blocks from external Blender project files.
+
+use crate::{
+    bpy,
+    enums::DataBlockKind,
+    error::BlError,
+    result::Result,
+    types::{DataBlock, Object},
+};
+use builder_derive_more::IntoBuilder;
+use derive_builder::Builder;
+use pyo3::{
+    intern,
+    types::{PyDict, PyList},
+    PyAny, PyObject, Python,
+};
+use std::path::{Path, PathBuf};
+
+/// Options for loading data-blocks via
+/// <https://docs.blender.org/api/latest/bpy.types.BlendDataLibraries.html#bpy.types.BlendDataLibraries.load>.
+#[derive(Debug, Clone, Builder, IntoBuilder)]
+#[builder(default, build_fn(private, name = "fallible_build", error = "BlError"))]
+pub struct LibraryLoadOptions {
+    /// Store the path of the library relative to the current Blender project file.
+    pub relative_path: bool,
+    /// Link loaded objects and collections to the collection of the current scene.
+    pub link_to_scene: bool,
+    /// Instance loaded collections in the current scene via empty objects instead of linking
+    /// them directly (only applies to [`DataBlockKind::Collection`]).
+    pub instance_collections: bool,
+}
+
+impl LibraryLoadOptionsBuilder {
+    #[must_use]
+    pub fn build(&self) -> LibraryLoadOptions {
+        self.fallible_build().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl Default for LibraryLoadOptions {
+    fn default() -> Self {
+        Self {
+            relative_path: false,
+            link_to_scene: true,
+            instance_collections: false,
+        }
+    }
+}
+
+/// Data-blocks that were appended or linked from a library.
+#[derive(Debug, Clone)]
+pub struct LibraryLoad<T> {
+    /// Loaded data-blocks in the order in which they were requested.
+    pub data_blocks: Vec<T>,
+    /// Empty objects that instance the loaded collections
+    /// (see [`LibraryLoadOptions::instance_collections`]).
+    pub instances: Vec<Object>,
+    /// Requested names that do not exist in the library or could not be loaded from it.
+    pub missing: Vec<String>,
+}
+
+impl<T> LibraryLoad<T> {
+    /// Returns `true` if all requested data-blocks were found in the library.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty()
+    }
+}
+
+/// Appends data-blocks of type `T` with the given `names` from library `filepath` into the current
+/// Blender project, see [`BlendProject::append`](crate::BlendProject::append).
+///
+/// # Errors
+///
+/// Returns an error if the given `filepath` does not point to a valid file.
+/// Furthermore, exceptions from the Python API of Blender are propagated.
+pub fn append<T: DataBlock>(
+    py: Python,
+    filepath: impl AsRef<Path>,
+    names: impl IntoIterator<Item = impl AsRef<str>>,
+    options: &LibraryLoadOptions,
+) -> Result<LibraryLoad<T>> {
+    load(py, filepath, names, false, options)
+}
+
+/// Links data-blocks of type `T` with the given `names` from library `filepath` into the current
+/// Blender project, see [`BlendProject::link`](crate::BlendProject::link).
+///
+/// # Errors
+///
+/// Returns an error if the given `filepath` does not point to a valid file.
+/// Furthermore, exceptions from the Python API of Blender are propagated.
+pub fn link<T: DataBlock>(
+    py: Python,
+    filepath: impl AsRef<Path>,
+    names: impl IntoIterator<Item = impl AsRef<str>>,
+    options: &LibraryLoadOptions,
+) -> Result<LibraryLoad<T>> {
+    load(py, filepath, names, true, options)
+}
+
+fn load<T: DataBlock>(
+    py: Python,
+    filepath: impl AsRef<Path>,
+    names: impl IntoIterator<Item = impl AsRef<str>>,
+    link: bool,
+    options: &LibraryLoadOptions,
+) -> Result<LibraryLoad<T>> {
+    let filepath = resolve_library_filepath(py, filepath)?;
+    let kind = T::KIND;
+    let attr = kind.data_collection();
+
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(intern!(py, "link"), link)?;
+    kwargs.set_item(intern!(py, "relative"), options.relative_path)?;
+    let libraries = bpy::data::libraries(py)?;
+    let library =
+        libraries
+            .as_ref(py)
+            .call_method(intern!(py, "load"), (&filepath,), Some(kwargs))?;
+
+    let (data_from, data_to): (&PyAny, &PyAny) =
+        library.call_method0(intern!(py, "__enter__"))?.extract()?;
+    let requested = select_available(data_from, data_to, attr, names);
+    library.call_method1(intern!(py, "__exit__"), (py.None(), py.None(), py.None()))?;
+    let (found, mut missing) = requested?;
+
+    let loaded: Vec<Option<&PyAny>> = data_to.getattr(attr)?.extract()?;
+    let mut data_blocks = Vec::with_capacity(found.len());
+    let mut instances = Vec::new();
+    for (name, data_block) in found.into_iter().zip(loaded) {
+        // Blender leaves `None` in place of data-blocks that exist but could not be loaded
+        let Some(data_block) = data_block else {
+            missing.push(name);
+            continue;
+        };
+        if options.link_to_scene {
+            match kind {
+                DataBlockKind::Object => link_to_scene(py, data_block, "objects")?,
+                DataBlockKind::Collection if options.instance_collections => {
+                    instances.push(instance_collection(py, data_block)?);
+                }
+                DataBlockKind::Collection => link_to_scene(py, data_block, "children")?,
+                _ => {}
+            }
+        }
+        data_blocks.push(PyObject::from(data_block).into());
+    }
+
+    Ok(LibraryLoad {
+        data_blocks,
+        instances,
+        missing,
+    })
+}
+
+/// Requests all `names` that exist in `data_from` to be loaded into `data_to`
+/// and returns the requested and missing names.
+fn select_available(
+    data_from: &PyAny,
+    data_to: &PyAny,
+    attr: &str,
+    names: impl IntoIterator<Item = impl AsRef<str>>,
+) -> Result<(Vec<String>, Vec<String>)> {
+    let available: Vec<String> = data_from.getattr(attr)?.extract()?;
+    let (found, missing): (Vec<String>, Vec<String>) = names
+        .into_iter()
+        .map(|name| name.as_ref().to_string())
+        .partition(|name| available.contains(name));
+    data_to.setattr(attr, PyList::new(data_to.py(), &found))?;
+    Ok((found, missing))
+}
+
+/// Resolves `filepath` that can also be relative to the current Blender project file
+/// (prefixed with `//`) and checks that it points to an existing file.
+fn resolve_library_filepath(py: Python, filepath: impl AsRef<Path>) -> Result<PathBuf> {
+    let filepath = filepath.as_ref();
+    let filepath = match filepath.to_str().and_then(|s| s.strip_prefix("//")) {
+        Some(relative) => {
+            let project_filepath = bpy::data::filepath(py)?;
+            if project_filepath.as_os_str().is_empty() {
+                return Err(BlError::ValueError(format!(
+                    "Filepath '{}' cannot be relative to a Blender project that is not saved",
+                    filepath.display()
+                )));
+            }
+            project_filepath
+                .parent()
+                .unwrap_or_else(|| Path::new(""))
+                .join(relative)
+        }
+        None => filepath.to_path_buf(),
+    };
+
+    if !filepath.is_file() {
+        return Err(BlError::ValueError(format!(
+            "Filepath '{}' does not point to a valid file.",
+            filepath.display()
+        )));
+    }
+    Ok(filepath)
+}
+
+fn link_to_scene(py: Python, data_block: &PyAny, children_attr: &str) -> Result<()> {
+    let scene = bpy::context::scene(py)?;
+    let collection = scene.collection(py)?;
+    let children = collection.as_ref(py).getattr(children_attr)?;
+    if !children.contains(data_block)? {
+        children.call_method1(intern!(py, "link"), (data_block,))?;
+    }
+    Ok(())
+}
+
+fn instance_collection(py: Python, collection: &PyAny) -> Result<Object> {
+    let name: String = collection.getattr(intern!(py, "name"))?.extract()?;
+    let instance: Object = bpy::data::objects(py)?
+        .call_method1(py, intern!(py, "new"), (name, py.None()))?
+        .into();
+    instance.setattr(py, intern!(py, "instance_type"), "COLLECTION")?;
+    instance.setattr(py, intern!(py, "instance_collection"), collection)?;
+    link_to_scene(py, instance.as_ref(py), "objects")?;
+    Ok(instance)
+}
@@ -1,12 +1,15 @@
 //! Safe and idiomatic interface for a Blender project.
 use crate::{
     bpy,
+    enums::ImageFileFormat,
     error::BlError,
     export::{BlendExport, BlendExporter},
-    import::{BlendImport, BlendImporter},
+    import::{BlendImport, BlendImporter, ImportResult},
+    library::{self, LibraryLoad, LibraryLoadOptions},
     result::Result,
+    types::DataBlock,
 };
-use pyo3::Python;
+use pyo3::{intern, Python};
 use std::path::{Path, PathBuf};
 
 /// Blender project using the Python API of Blender.
@@ -119,7 +122,8 @@ impl BlendProject {
         Ok(filepath)
     }
 
-    /// Imports the current Blender project to a given `filepath` using a given `importer`.
+    /// Imports a given `filepath` into the current Blender project using a given `importer`.
+    /// Returns the data-blocks that were created by the importer.
     ///
     /// # Arguments
     ///
@@ -130,12 +134,17 @@ impl BlendProject {
     ///
     /// Returns an error if the given `filepath` is not valid.
     /// Furthermore, exceptions from the Python API of Blender are propagated.
-    pub fn import<E: BlendImport>(&self, importer: E, filepath: impl AsRef<Path>) -> Result<()> {
+    pub fn import<E: BlendImport>(
+        &self,
+        importer: E,
+        filepath: impl AsRef<Path>,
+    ) -> Result<ImportResult> {
         importer.import(filepath)
     }
 
-    /// Imports the current Blender project to a given `filepath`. The importer is determined
+    /// Imports a given `filepath` into the current Blender project. The importer is determined
     /// by the file extension of the given `filepath` and used with default settings.
+    /// Returns the data-blocks that were created by the importer.
     ///
     /// # Arguments
     ///
@@ -146,7 +155,7 @@ impl BlendProject {
     /// Returns an error if the given `filepath` is not valid or if it cannot be mapped to a
     /// supported importer.
     /// Furthermore, exceptions from the Python API of Blender are propagated.
-    pub fn import_default(&self, filepath: impl AsRef<Path>) -> Result<()> {
+    pub fn import_default(&self, filepath: impl AsRef<Path>) -> Result<ImportResult> {
         BlendImporter::from_filepath_extension(&filepath)?.import(&filepath)
     }
 
@@ -185,6 +194,202 @@ impl BlendProject {
         BlendExporter::from_filepath_extension(&filepath)?.export(&filepath)
     }
 
+    /// Appends data-blocks of type `T` with the given `names` from a library `filepath` into the
+    /// current Blender project, see [`LibraryLoadOptions`] for the available options. The kind of
+    /// data-blocks is determined by [`DataBlock::KIND`].
+    ///
+    /// # Arguments
+    ///
+    /// * `filepath` - The path to the library, which can be relative to the current Blender
+    ///   project file if prefixed with `//`.
+    /// * `names` - The names of data-blocks to append.
+    /// * `options` - The options for loading the data-blocks.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use blr::{library::LibraryLoadOptions, BlendProject, Material};
+    ///
+    /// pyo3::Python::with_gil(|py| {
+    ///     let project = BlendProject::empty(py).unwrap();
+    ///     let materials = project
+    ///         .append::<Material>(
+    ///             py,
+    ///             "materials.blend",
+    ///             ["Steel", "Rubber"],
+    ///             &LibraryLoadOptions::default(),
+    ///         )
+    ///         .unwrap();
+    ///     assert!(materials.is_complete());
+    /// });
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given `filepath` does not point to a valid file.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn append<T: DataBlock>(
+        &self,
+        py: Python,
+        filepath: impl AsRef<Path>,
+        names: impl IntoIterator<Item = impl AsRef<str>>,
+        options: &LibraryLoadOptions,
+    ) -> Result<LibraryLoad<T>> {
+        library::append(py, filepath, names, options)
+    }
+
+    /// Links data-blocks of type `T` with the given `names` from a library `filepath` into the
+    /// current Blender project. Linked data-blocks remain read-only and are updated together
+    /// with the library. See [`BlendProject::append`] for the description of arguments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given `filepath` does not point to a valid file.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn link<T: DataBlock>(
+        &self,
+        py: Python,
+        filepath: impl AsRef<Path>,
+        names: impl IntoIterator<Item = impl AsRef<str>>,
+        options: &LibraryLoadOptions,
+    ) -> Result<LibraryLoad<T>> {
+        library::link(py, filepath, names, options)
+    }
+
+    /// Renders a still image of the current scene to a given `filepath`. The file format and all
+    /// other settings are taken from the render settings of the scene, see
+    /// [`RenderSettings`](crate::render::RenderSettings).
+    ///
+    /// # Arguments
+    ///
+    /// * `filepath` - The path to the rendered image.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given `filepath` is not valid for the file format of the scene.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn render_still(&self, py: Python, filepath: impl AsRef<Path>) -> Result<PathBuf> {
+        let file_format = Self::render_file_format(py)?;
+        let filepath = Self::check_render_filepath(filepath, file_format)?;
+
+        Self::render_to(py, &filepath)?;
+
+        Ok(filepath)
+    }
+
+    /// Renders the given `frames` of the current scene into a directory `dirpath`, one image per
+    /// frame. The files are named after their frame number, e.g. `0001.png`.
+    ///
+    /// # Arguments
+    ///
+    /// * `dirpath` - The path to the output directory (created if it does not exist).
+    /// * `frames` - The frames to render.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given `dirpath` is not valid or cannot be created.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn render_animation(
+        &self,
+        py: Python,
+        dirpath: impl AsRef<Path>,
+        frames: impl IntoIterator<Item = i32>,
+    ) -> Result<Vec<PathBuf>> {
+        let dirpath = Self::check_render_dirpath(dirpath)?;
+        let extension = Self::render_file_format(py)?.extension();
+
+        let scene = bpy::context::scene(py)?;
+        let original_frame = scene.frame_current(py)?;
+
+        let filepaths = frames
+            .into_iter()
+            .map(|frame| {
+                scene.frame_set(py, frame, 0.0)?;
+                let filepath = dirpath.join(format!("{frame:04}.{extension}"));
+                Self::render_to(py, &filepath)?;
+                Ok(filepath)
+            })
+            .collect::<Result<Vec<_>>>();
+
+        scene.frame_set(py, original_frame, 0.0)?;
+
+        filepaths
+    }
+
+    fn render_file_format(py: Python) -> Result<ImageFileFormat> {
+        Ok(bpy::context::scene(py)?
+            .render(py)?
+            .getattr(intern!(py, "image_settings"))?
+            .getattr(intern!(py, "file_format"))?
+            .extract()?)
+    }
+
+    fn render_to(py: Python, filepath: &Path) -> Result<()> {
+        let scene = bpy::context::scene(py)?;
+        let render = scene.render(py)?;
+        let original_filepath = render.getattr(intern!(py, "filepath"))?;
+
+        render.setattr(intern!(py, "filepath"), filepath)?;
+        let result = bpy::ops::render::render(py, false, true, false, "", "");
+        render.setattr(intern!(py, "filepath"), original_filepath)?;
+
+        Ok(result?)
+    }
+
+    fn check_render_filepath(
+        filepath: impl AsRef<Path>,
+        file_format: ImageFileFormat,
+    ) -> Result<PathBuf> {
+        let filepath = filepath.as_ref();
+
+        if filepath.as_os_str().is_empty() {
+            return Err(BlError::ValueError("Filepath cannot be empty".to_string()));
+        }
+
+        if filepath.is_dir() {
+            return Err(BlError::ValueError(format!(
+                "Filepath cannot be a directory: '{}'",
+                filepath.display()
+            )));
+        }
+
+        match filepath.extension() {
+            Some(valid_ext)
+                if file_format
+                    .extensions()
+                    .iter()
+                    .any(|ext| ext.eq_ignore_ascii_case(&valid_ext.to_string_lossy())) =>
+            {
+                Ok(filepath.into())
+            }
+            Some(invalid_ext) => Err(BlError::ValueError(format!(
+                "Invalid file extension (expected: '{expected_ext}', actual: '{invalid_ext}')",
+                expected_ext = file_format.extension(),
+                invalid_ext = invalid_ext.to_string_lossy()
+            ))),
+            _ => Ok(filepath.with_extension(file_format.extension())),
+        }
+    }
+
+    fn check_render_dirpath(dirpath: impl AsRef<Path>) -> Result<PathBuf> {
+        let dirpath = dirpath.as_ref();
+
+        if dirpath.as_os_str().is_empty() {
+            return Err(BlError::ValueError("Dirpath cannot be empty".to_string()));
+        }
+
+        if dirpath.is_file() {
+            return Err(BlError::ValueError(format!(
+                "Dirpath cannot be a file: '{}'",
+                dirpath.display()
+            )));
+        }
+
+        std::fs::create_dir_all(dirpath)?;
+
+        Ok(dirpath.into())
+    }
+
     fn check_save_filepath(filepath: impl AsRef<Path>) -> Result<PathBuf> {
         let filepath = filepath.as_ref();
 
@@ -0,0 +1,147 @@
+//! Utilities for rendering images and animations.
+
+use crate::{
+    enums::{ColorDepth, ImageFileFormat, RenderEngine},
+    error::BlError,
+    result::Result,
+    types::Scene,
+};
+use builder_derive_more::IntoBuilder;
+use derive_builder::Builder;
+use pyo3::{intern, Python};
+use std::path::PathBuf;
+
+/// Settings of <https://docs.blender.org/api/latest/bpy.types.RenderSettings.html>
+/// that are commonly adjusted for headless rendering.
+#[derive(Debug, Clone, Builder, IntoBuilder)]
+#[builder(default, build_fn(private, name = "fallible_build", error = "BlError"))]
+pub struct RenderSettings {
+    /// Render engine, where both identifiers of EEVEE apply the one of the running Blender
+    /// version (see [`RenderEngine::resolve`]).
+    pub engine: RenderEngine,
+    pub resolution_x: u32,
+    pub resolution_y: u32,
+    pub resolution_percentage: u32,
+    pub file_format: ImageFileFormat,
+    pub color_depth: ColorDepth,
+    pub film_transparent: bool,
+    /// Number of render samples (`None` keeps the current value of the engine).
+    #[builder(setter(strip_option))]
+    pub samples: Option<u32>,
+    #[builder(setter(into))]
+    pub filepath: PathBuf,
+}
+
+impl RenderSettingsBuilder {
+    #[must_use]
+    pub fn build(&self) -> RenderSettings {
+        self.fallible_build().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl Default for RenderSettings {
+    fn default() -> Self {
+        Self {
+            engine: RenderEngine::BlenderEevee,
+            resolution_x: 1920,
+            resolution_y: 1080,
+            resolution_percentage: 100,
+            file_format: ImageFileFormat::Png,
+            color_depth: ColorDepth::Bits8,
+            film_transparent: false,
+            samples: None,
+            filepath: PathBuf::from("/tmp/"),
+        }
+    }
+}
+
+impl RenderSettings {
+    /// Returns the render settings of the given `scene`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the current engine or file format is not supported.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn from_scene(py: Python, scene: &Scene) -> Result<Self> {
+        let render = scene.render(py)?;
+        let image_settings = render.getattr(intern!(py, "image_settings"))?;
+        let engine: RenderEngine = render.getattr(intern!(py, "engine"))?.extract()?;
+        let samples = match engine {
+            RenderEngine::BlenderEevee | RenderEngine::BlenderEeveeNext => Some(
+                scene
+                    .getattr(py, intern!(py, "eevee"))?
+                    .getattr(py, intern!(py, "taa_render_samples"))?
+                    .extract(py)?,
+            ),
+            RenderEngine::Cycles => Some(
+                scene
+                    .getattr(py, intern!(py, "cycles"))?
+                    .getattr(py, intern!(py, "samples"))?
+                    .extract(py)?,
+            ),
+            RenderEngine::BlenderWorkbench => None,
+        };
+
+        Ok(Self {
+            engine,
+            resolution_x: render.getattr(intern!(py, "resolution_x"))?.extract()?,
+            resolution_y: render.getattr(intern!(py, "resolution_y"))?.extract()?,
+            resolution_percentage: render
+                .getattr(intern!(py, "resolution_percentage"))?
+                .extract()?,
+            file_format: image_settings
+                .getattr(intern!(py, "file_format"))?
+                .extract()?,
+            color_depth: image_settings
+                .getattr(intern!(py, "color_depth"))?
+                .extract()?,
+            film_transparent: render.getattr(intern!(py, "film_transparent"))?.extract()?,
+            samples,
+            filepath: render.getattr(intern!(py, "filepath"))?.extract()?,
+        })
+    }
+
+    /// Applies the render settings to the given `scene`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `samples` cannot be applied to the selected engine.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn apply(&self, py: Python, scene: &mut Scene) -> Result<()> {
+        let render = scene.render(py)?;
+        render.setattr(intern!(py, "engine"), self.engine.resolve(py)?)?;
+        render.setattr(intern!(py, "resolution_x"), self.resolution_x)?;
+        render.setattr(intern!(py, "resolution_y"), self.resolution_y)?;
+        render.setattr(
+            intern!(py, "resolution_percentage"),
+            self.resolution_percentage,
+        )?;
+        render.setattr(intern!(py, "film_transparent"), self.film_transparent)?;
+        render.setattr(intern!(py, "filepath"), &self.filepath)?;
+
+        let image_settings = render.getattr(intern!(py, "image_settings"))?;
+        image_settings.setattr(intern!(py, "file_format"), self.file_format)?;
+        image_settings.setattr(intern!(py, "color_depth"), self.color_depth)?;
+
+        if let Some(samples) = self.samples {
+            match self.engine {
+                RenderEngine::BlenderEevee | RenderEngine::BlenderEeveeNext => scene
+                    .getattr(py, intern!(py, "eevee"))?
+                    .setattr(py, intern!(py, "taa_render_samples"), samples)?,
+                RenderEngine::Cycles => scene.getattr(py, intern!(py, "cycles"))?.setattr(
+                    py,
+                    intern!(py, "samples"),
+                    samples,
+                )?,
+                RenderEngine::BlenderWorkbench => {
+                    return Err(BlError::ValueError(format!(
+                        "Render samples cannot be set for engine '{}'",
+                        self.engine
+                    )))
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
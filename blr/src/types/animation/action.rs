@@ -0,0 +1,82 @@
+use crate::{
+    bpy,
+    enums::DataBlockKind,
+    result::Result,
+    types::{collections::ActionFCurves, BpyID, DataBlock, FCurve},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Action.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Action(PyObject);
+
+impl BpyID for Action {}
+
+impl DataBlock for Action {
+    const KIND: DataBlockKind = DataBlockKind::Action;
+}
+
+impl Action {
+    /// Creates a new action with the given `name`.
+    pub fn new(py: Python, name: &str) -> Result<Self> {
+        Ok(bpy::data::actions(py)?
+            .call_method1(py, intern!(py, "new"), (name,))?
+            .into())
+    }
+
+    /// Returns the F-curve that animates element `index` of `data_path`, which is created in
+    /// `group` if it does not exist yet.
+    pub fn fcurve_ensure(
+        &self,
+        py: Python,
+        data_path: &str,
+        index: usize,
+        group: Option<&str>,
+    ) -> Result<FCurve> {
+        let fcurves = self.fcurves(py)?;
+        match fcurves.find_fcurve(py, data_path, index)? {
+            Some(fcurve) => Ok(fcurve),
+            None => Ok(fcurves.new(py, data_path, index, group.unwrap_or_default())?),
+        }
+    }
+
+    bind_python! { self.fcurves => pub fn fcurves(&self, py: Python) -> Result<ActionFCurves> }
+    bind_python! { self.frame_end => pub fn frame_end(&self, py: Python) -> Result<f32> }
+    bind_python! { self.frame_end = pub fn set_frame_end(&mut self, py: Python, value: f32) }
+    bind_python! { self.frame_range => pub fn frame_range(&self, py: Python) -> Result<[f32; 2]> }
+    bind_python! { self.frame_start => pub fn frame_start(&self, py: Python) -> Result<f32> }
+    bind_python! { self.frame_start = pub fn set_frame_start(&mut self, py: Python, value: f32) }
+    bind_python! { self.id_root => pub fn id_root(&self, py: Python) -> Result<String> }
+    bind_python! { self.id_root = pub fn set_id_root(&mut self, py: Python, value: &str) }
+    bind_python! { self.use_cyclic => pub fn use_cyclic(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_cyclic = pub fn set_use_cyclic(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_frame_range => pub fn use_frame_range(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_frame_range = pub fn set_use_frame_range(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for Action {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Action {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Action {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Action {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
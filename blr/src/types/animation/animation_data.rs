@@ -0,0 +1,61 @@
+use crate::{
+    result::Result,
+    types::{Action, BpyStruct, FCurve},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyAny, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.AnimData.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct AnimationData(PyObject);
+
+impl BpyStruct for AnimationData {}
+
+impl AnimationData {
+    /// Returns the driver F-curve of element `index` of `data_path` if it exists.
+    pub fn find_driver(&self, py: Python, data_path: &str, index: usize) -> Result<Option<FCurve>> {
+        Ok(self
+            .getattr(py, intern!(py, "drivers"))?
+            .call_method1(py, intern!(py, "find"), (data_path, index))?
+            .extract(py)?)
+    }
+
+    bind_python! { self.action => pub fn action(&self, py: Python) -> Result<Option<Action>> }
+    bind_python! { self.action = pub fn set_action(&mut self, py: Python, value: Option<&Action>) }
+    bind_python! { self.action_blend_type => pub fn action_blend_type(&self, py: Python) -> Result<String> }
+    bind_python! { self.action_blend_type = pub fn set_action_blend_type(&mut self, py: Python, value: &str) }
+    bind_python! { self.action_extrapolation => pub fn action_extrapolation(&self, py: Python) -> Result<String> }
+    bind_python! { self.action_extrapolation = pub fn set_action_extrapolation(&mut self, py: Python, value: &str) }
+    bind_python! { self.action_influence => pub fn action_influence(&self, py: Python) -> Result<f32> }
+    bind_python! { self.action_influence = pub fn set_action_influence(&mut self, py: Python, value: f32) }
+    bind_python! { self.drivers => pub fn drivers(&self, py: Python) -> Result<Vec<FCurve>> }
+    bind_python! { self.nla_tracks => pub fn nla_tracks<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.use_nla => pub fn use_nla(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_nla = pub fn set_use_nla(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for AnimationData {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for AnimationData {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for AnimationData {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for AnimationData {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
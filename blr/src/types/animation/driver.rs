@@ -0,0 +1,138 @@
+use crate::{
+    enums::{DriverTransformSpace, DriverTransformType, DriverType, DriverVariableType},
+    result::Result,
+    types::{collections::DriverVariables, BpyID, BpyStruct, DriverVariable, Object},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Driver.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Driver(PyObject);
+
+impl BpyStruct for Driver {}
+
+impl Driver {
+    /// Adds a variable with the given `name` and `variable_type`, whose targets are not set yet.
+    pub fn add_variable(
+        &mut self,
+        py: Python,
+        name: &str,
+        variable_type: DriverVariableType,
+    ) -> Result<DriverVariable> {
+        let mut variable = self.variables(py)?.new(py)?;
+        variable.set_name(py, name)?;
+        variable.set_variable_type(py, variable_type)?;
+        Ok(variable)
+    }
+
+    /// Adds a variable with the value of property `data_path` of `id`.
+    pub fn add_single_property(
+        &mut self,
+        py: Python,
+        name: &str,
+        id: &impl BpyID,
+        data_path: &str,
+    ) -> Result<DriverVariable> {
+        let variable = self.add_variable(py, name, DriverVariableType::SingleProp)?;
+        let mut target = variable.target(py, 0)?;
+        target.set_id(py, id)?;
+        target.set_data_path(py, data_path)?;
+        Ok(variable)
+    }
+
+    /// Adds a variable with a transform channel of `object` in `transform_space`.
+    pub fn add_transform(
+        &mut self,
+        py: Python,
+        name: &str,
+        object: &Object,
+        transform_type: DriverTransformType,
+        transform_space: DriverTransformSpace,
+    ) -> Result<DriverVariable> {
+        let variable = self.add_variable(py, name, DriverVariableType::Transforms)?;
+        let mut target = variable.target(py, 0)?;
+        target.set_id(py, object)?;
+        target.set_transform_type(py, transform_type)?;
+        target.set_transform_space(py, transform_space)?;
+        Ok(variable)
+    }
+
+    /// Adds a variable with the angle between the rotations of `object_a` and `object_b`.
+    pub fn add_rotation_difference(
+        &mut self,
+        py: Python,
+        name: &str,
+        object_a: &Object,
+        object_b: &Object,
+    ) -> Result<DriverVariable> {
+        self.add_object_pair(
+            py,
+            name,
+            DriverVariableType::RotationDiff,
+            object_a,
+            object_b,
+        )
+    }
+
+    /// Adds a variable with the distance between the origins of `object_a` and `object_b`.
+    pub fn add_distance(
+        &mut self,
+        py: Python,
+        name: &str,
+        object_a: &Object,
+        object_b: &Object,
+    ) -> Result<DriverVariable> {
+        self.add_object_pair(py, name, DriverVariableType::LocDiff, object_a, object_b)
+    }
+
+    fn add_object_pair(
+        &mut self,
+        py: Python,
+        name: &str,
+        variable_type: DriverVariableType,
+        object_a: &Object,
+        object_b: &Object,
+    ) -> Result<DriverVariable> {
+        let variable = self.add_variable(py, name, variable_type)?;
+        variable.target(py, 0)?.set_id(py, object_a)?;
+        variable.target(py, 1)?.set_id(py, object_b)?;
+        Ok(variable)
+    }
+
+    bind_python! { self.expression => pub fn expression(&self, py: Python) -> Result<String> }
+    bind_python! { self.expression = pub fn set_expression(&mut self, py: Python, value: &str) }
+    bind_python! { self.is_simple_expression => pub fn is_simple_expression(&self, py: Python) -> Result<bool> }
+    bind_python! { self.is_valid => pub fn is_valid(&self, py: Python) -> Result<bool> }
+    bind_python! { self.type => pub fn driver_type(&self, py: Python) -> Result<DriverType> }
+    bind_python! { self.type = pub fn set_driver_type(&mut self, py: Python, value: DriverType) }
+    bind_python! { self.use_self => pub fn use_self(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_self = pub fn set_use_self(&mut self, py: Python, value: bool) }
+    bind_python! { self.variables => pub fn variables(&self, py: Python) -> Result<DriverVariables> }
+}
+
+impl From<pyo3::PyObject> for Driver {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Driver {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Driver {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Driver {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{
+    enums::{DriverTransformSpace, DriverTransformType},
+    result::Result,
+    types::{BpyID, BpyStruct},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.DriverTarget.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct DriverTarget(PyObject);
+
+impl BpyStruct for DriverTarget {}
+
+impl DriverTarget {
+    /// Sets the data-block of the target, whose type is adopted if the variable allows it.
+    pub fn set_id(&mut self, py: Python, id: &impl BpyID) -> Result<()> {
+        let id = id.to_object(py);
+        let id_type = id.getattr(py, intern!(py, "id_type"))?;
+        if !self
+            .getattr(py, intern!(py, "id_type"))?
+            .as_ref(py)
+            .eq(&id_type)?
+        {
+            self.setattr(py, intern!(py, "id_type"), id_type)?;
+        }
+        self.setattr(py, intern!(py, "id"), id)?;
+        Ok(())
+    }
+
+    bind_python! { self.bone_target => pub fn bone_target(&self, py: Python) -> Result<String> }
+    bind_python! { self.bone_target = pub fn set_bone_target(&mut self, py: Python, value: &str) }
+    bind_python! { self.data_path => pub fn data_path(&self, py: Python) -> Result<String> }
+    bind_python! { self.data_path = pub fn set_data_path(&mut self, py: Python, value: &str) }
+    bind_python! { self.id => pub fn id(&self, py: Python) -> Result<Option<PyObject>> }
+    bind_python! { self.id_type => pub fn id_type(&self, py: Python) -> Result<String> }
+    bind_python! { self.rotation_mode => pub fn rotation_mode(&self, py: Python) -> Result<String> }
+    bind_python! { self.rotation_mode = pub fn set_rotation_mode(&mut self, py: Python, value: &str) }
+    bind_python! { self.transform_space => pub fn transform_space(&self, py: Python) -> Result<DriverTransformSpace> }
+    bind_python! { self.transform_space = pub fn set_transform_space(&mut self, py: Python, value: DriverTransformSpace) }
+    bind_python! { self.transform_type => pub fn transform_type(&self, py: Python) -> Result<DriverTransformType> }
+    bind_python! { self.transform_type = pub fn set_transform_type(&mut self, py: Python, value: DriverTransformType) }
+}
+
+impl From<pyo3::PyObject> for DriverTarget {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for DriverTarget {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DriverTarget {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for DriverTarget {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{
+    enums::DriverVariableType,
+    error::BlError,
+    result::Result,
+    types::{BpyStruct, DriverTarget},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.DriverVariable.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct DriverVariable(PyObject);
+
+impl BpyStruct for DriverVariable {}
+
+impl DriverVariable {
+    /// Returns target `index` of the variable, whose number depends on the type of the variable.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable has no target at `index`.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn target(&self, py: Python, index: usize) -> Result<DriverTarget> {
+        let targets = self.targets(py)?;
+        targets.get(index).cloned().ok_or_else(|| {
+            BlError::ValueError(format!(
+                "Driver variable has {} targets, but target {index} is requested",
+                targets.len()
+            ))
+        })
+    }
+
+    /// Returns the targets of the variable, whose number depends on the type of the variable.
+    pub fn targets(&self, py: Python) -> Result<Vec<DriverTarget>> {
+        Ok(self
+            .getattr(py, intern!(py, "targets"))?
+            .as_ref(py)
+            .iter()?
+            .map(|target| target?.extract())
+            .collect::<pyo3::PyResult<_>>()?)
+    }
+
+    bind_python! { self.is_name_valid => pub fn is_name_valid(&self, py: Python) -> Result<bool> }
+    bind_python! { self.name => pub fn name(&self, py: Python) -> Result<String> }
+    bind_python! { self.name = pub fn set_name(&mut self, py: Python, value: &str) }
+    bind_python! { self.type => pub fn variable_type(&self, py: Python) -> Result<DriverVariableType> }
+    bind_python! { self.type = pub fn set_variable_type(&mut self, py: Python, value: DriverVariableType) }
+}
+
+impl From<pyo3::PyObject> for DriverVariable {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for DriverVariable {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DriverVariable {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for DriverVariable {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    enums::{FModifierType, KeyframeEasing, KeyframeInterpolation},
+    result::Result,
+    types::{
+        collections::{FCurveKeyframePoints, FCurveModifiers},
+        BpyStruct, CollectionImpl, Driver, FModifier,
+    },
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.FCurve.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct FCurve(PyObject);
+
+impl BpyStruct for FCurve {}
+
+impl FCurve {
+    /// Returns the frame and value of all keyframes.
+    pub fn keyframes(&self, py: Python) -> Result<Vec<[f32; 2]>> {
+        self.keyframe_points(py)?.foreach_get(py, "co")
+    }
+
+    /// Appends keyframes at the given `(frame, value)` points in bulk, whose handles are then
+    /// recalculated.
+    ///
+    /// Existing keyframes are kept, even if they share a frame with one of the new `points`.
+    pub fn insert_keyframes(
+        &mut self,
+        py: Python,
+        points: &[[f32; 2]],
+        interpolation: KeyframeInterpolation,
+    ) -> Result<()> {
+        let keyframe_points = self.keyframe_points(py)?;
+        let mut co: Vec<[f32; 2]> = keyframe_points.foreach_get(py, "co")?;
+        co.extend_from_slice(points);
+
+        let mut interpolations: Vec<i32> = keyframe_points.foreach_get(py, "interpolation")?;
+        interpolations.resize(co.len(), interpolation as i32);
+
+        keyframe_points.add(py, points.len())?;
+        keyframe_points.foreach_set(py, "co", &co)?;
+        keyframe_points.foreach_set(py, "interpolation", &interpolations)?;
+        self.update(py)?;
+        Ok(())
+    }
+
+    /// Sets the interpolation towards the next keyframe of all keyframes.
+    pub fn set_interpolation(
+        &mut self,
+        py: Python,
+        interpolation: KeyframeInterpolation,
+    ) -> Result<()> {
+        let keyframe_points = self.keyframe_points(py)?;
+        let interpolations = vec![interpolation as i32; keyframe_points.len(py)?];
+        keyframe_points.foreach_set(py, "interpolation", &interpolations)
+    }
+
+    /// Sets the easing of all keyframes, which applies to the dynamic interpolations
+    /// (e.g., [`KeyframeInterpolation::Bounce`]).
+    pub fn set_easing(&mut self, py: Python, easing: KeyframeEasing) -> Result<()> {
+        for mut keyframe in self.keyframe_points(py)?.values(py)? {
+            keyframe.set_easing(py, easing)?;
+        }
+        Ok(())
+    }
+
+    /// Adds a modifier that repeats the keyframes before and after their range.
+    pub fn add_cycles_modifier(&mut self, py: Python) -> Result<FModifier> {
+        Ok(self.modifiers(py)?.new(py, FModifierType::Cycles)?)
+    }
+
+    /// Adds a modifier that adds random noise of the given `scale` (in frames) and `strength`.
+    pub fn add_noise_modifier(
+        &mut self,
+        py: Python,
+        scale: f32,
+        strength: f32,
+    ) -> Result<FModifier> {
+        let mut modifier = self.modifiers(py)?.new(py, FModifierType::Noise)?;
+        modifier.set_scale(py, scale)?;
+        modifier.set_strength(py, strength)?;
+        Ok(modifier)
+    }
+
+    bind_python! { self.array_index => pub fn array_index(&self, py: Python) -> Result<usize> }
+    bind_python! { self.array_index = pub fn set_array_index(&mut self, py: Python, value: usize) }
+    bind_python! { self.data_path => pub fn data_path(&self, py: Python) -> Result<String> }
+    bind_python! { self.data_path = pub fn set_data_path(&mut self, py: Python, value: &str) }
+    bind_python! { self.driver => pub fn driver(&self, py: Python) -> Result<Option<Driver>> }
+    bind_python! { self.extrapolation => pub fn extrapolation(&self, py: Python) -> Result<String> }
+    bind_python! { self.extrapolation = pub fn set_extrapolation(&mut self, py: Python, value: &str) }
+    bind_python! { self.hide => pub fn hide(&self, py: Python) -> Result<bool> }
+    bind_python! { self.hide = pub fn set_hide(&mut self, py: Python, value: bool) }
+    bind_python! { self.is_empty => pub fn is_empty(&self, py: Python) -> Result<bool> }
+    bind_python! { self.is_valid => pub fn is_valid(&self, py: Python) -> Result<bool> }
+    bind_python! { self.keyframe_points => pub fn keyframe_points(&self, py: Python) -> Result<FCurveKeyframePoints> }
+    bind_python! { self.lock => pub fn lock(&self, py: Python) -> Result<bool> }
+    bind_python! { self.lock = pub fn set_lock(&mut self, py: Python, value: bool) }
+    bind_python! { self.modifiers => pub fn modifiers(&self, py: Python) -> Result<FCurveModifiers> }
+    bind_python! { self.mute => pub fn mute(&self, py: Python) -> Result<bool> }
+    bind_python! { self.mute = pub fn set_mute(&mut self, py: Python, value: bool) }
+    bind_python! { self.select => pub fn select(&self, py: Python) -> Result<bool> }
+    bind_python! { self.select = pub fn set_select(&mut self, py: Python, value: bool) }
+    bind_python! { self.evaluate() => pub fn evaluate(&self, py: Python, frame: f32) -> Result<f32> }
+    bind_python! { self.range() => pub fn range(&self, py: Python) -> Result<[f32; 2]> }
+    bind_python! { self.update() => pub fn update(&mut self, py: Python) }
+}
+
+impl From<pyo3::PyObject> for FCurve {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for FCurve {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for FCurve {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for FCurve {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,94 @@
+use crate::{enums::FModifierType, types::BpyStruct};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.FModifier.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct FModifier(PyObject);
+
+impl BpyStruct for FModifier {}
+
+impl FModifier {
+    bind_python! { self.active => pub fn active(&self, py: Python) -> Result<bool> }
+    bind_python! { self.active = pub fn set_active(&mut self, py: Python, value: bool) }
+    bind_python! { self.blend_in => pub fn blend_in(&self, py: Python) -> Result<f32> }
+    bind_python! { self.blend_in = pub fn set_blend_in(&mut self, py: Python, value: f32) }
+    bind_python! { self.blend_out => pub fn blend_out(&self, py: Python) -> Result<f32> }
+    bind_python! { self.blend_out = pub fn set_blend_out(&mut self, py: Python, value: f32) }
+    bind_python! { self.frame_end => pub fn frame_end(&self, py: Python) -> Result<f32> }
+    bind_python! { self.frame_end = pub fn set_frame_end(&mut self, py: Python, value: f32) }
+    bind_python! { self.frame_start => pub fn frame_start(&self, py: Python) -> Result<f32> }
+    bind_python! { self.frame_start = pub fn set_frame_start(&mut self, py: Python, value: f32) }
+    bind_python! { self.influence => pub fn influence(&self, py: Python) -> Result<f32> }
+    bind_python! { self.influence = pub fn set_influence(&mut self, py: Python, value: f32) }
+    bind_python! { self.is_valid => pub fn is_valid(&self, py: Python) -> Result<bool> }
+    bind_python! { self.mute => pub fn mute(&self, py: Python) -> Result<bool> }
+    bind_python! { self.mute = pub fn set_mute(&mut self, py: Python, value: bool) }
+    bind_python! { self.type => pub fn modifier_type(&self, py: Python) -> Result<FModifierType> }
+    bind_python! { self.use_influence => pub fn use_influence(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_influence = pub fn set_use_influence(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_restricted_range => pub fn use_restricted_range(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_restricted_range = pub fn set_use_restricted_range(&mut self, py: Python, value: bool) }
+}
+
+/// Settings of <https://docs.blender.org/api/latest/bpy.types.FModifierCycles.html>.
+impl FModifier {
+    bind_python! { self.cycles_after => pub fn cycles_after(&self, py: Python) -> Result<u32> }
+    bind_python! { self.cycles_after = pub fn set_cycles_after(&mut self, py: Python, value: u32) }
+    bind_python! { self.cycles_before => pub fn cycles_before(&self, py: Python) -> Result<u32> }
+    bind_python! { self.cycles_before = pub fn set_cycles_before(&mut self, py: Python, value: u32) }
+    bind_python! { self.mode_after => pub fn mode_after(&self, py: Python) -> Result<String> }
+    bind_python! { self.mode_after = pub fn set_mode_after(&mut self, py: Python, value: &str) }
+    bind_python! { self.mode_before => pub fn mode_before(&self, py: Python) -> Result<String> }
+    bind_python! { self.mode_before = pub fn set_mode_before(&mut self, py: Python, value: &str) }
+}
+
+/// Settings of <https://docs.blender.org/api/latest/bpy.types.FModifierNoise.html>.
+impl FModifier {
+    bind_python! { self.blend_type => pub fn blend_type(&self, py: Python) -> Result<String> }
+    bind_python! { self.blend_type = pub fn set_blend_type(&mut self, py: Python, value: &str) }
+    bind_python! { self.depth => pub fn depth(&self, py: Python) -> Result<u32> }
+    bind_python! { self.depth = pub fn set_depth(&mut self, py: Python, value: u32) }
+    bind_python! { self.offset => pub fn offset(&self, py: Python) -> Result<f32> }
+    bind_python! { self.offset = pub fn set_offset(&mut self, py: Python, value: f32) }
+    bind_python! { self.phase => pub fn phase(&self, py: Python) -> Result<f32> }
+    bind_python! { self.phase = pub fn set_phase(&mut self, py: Python, value: f32) }
+    bind_python! { self.scale => pub fn scale(&self, py: Python) -> Result<f32> }
+    bind_python! { self.scale = pub fn set_scale(&mut self, py: Python, value: f32) }
+    bind_python! { self.strength => pub fn strength(&self, py: Python) -> Result<f32> }
+    bind_python! { self.strength = pub fn set_strength(&mut self, py: Python, value: f32) }
+}
+
+/// Settings of <https://docs.blender.org/api/latest/bpy.types.FModifierStepped.html>.
+impl FModifier {
+    bind_python! { self.frame_offset => pub fn frame_offset(&self, py: Python) -> Result<f32> }
+    bind_python! { self.frame_offset = pub fn set_frame_offset(&mut self, py: Python, value: f32) }
+    bind_python! { self.frame_step => pub fn frame_step(&self, py: Python) -> Result<f32> }
+    bind_python! { self.frame_step = pub fn set_frame_step(&mut self, py: Python, value: f32) }
+}
+
+impl From<pyo3::PyObject> for FModifier {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for FModifier {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for FModifier {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for FModifier {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{
+    enums::{KeyframeEasing, KeyframeInterpolation},
+    types::BpyStruct,
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Keyframe.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Keyframe(PyObject);
+
+impl BpyStruct for Keyframe {}
+
+impl Keyframe {
+    bind_python! { self.amplitude => pub fn amplitude(&self, py: Python) -> Result<f32> }
+    bind_python! { self.amplitude = pub fn set_amplitude(&mut self, py: Python, value: f32) }
+    bind_python! { self.back => pub fn back(&self, py: Python) -> Result<f32> }
+    bind_python! { self.back = pub fn set_back(&mut self, py: Python, value: f32) }
+    bind_python! { self.co => pub fn co(&self, py: Python) -> Result<[f32; 2]> }
+    bind_python! { self.co = pub fn set_co(&mut self, py: Python, value: [f32; 2]) }
+    bind_python! { self.easing => pub fn easing(&self, py: Python) -> Result<KeyframeEasing> }
+    bind_python! { self.easing = pub fn set_easing(&mut self, py: Python, value: KeyframeEasing) }
+    bind_python! { self.handle_left => pub fn handle_left(&self, py: Python) -> Result<[f32; 2]> }
+    bind_python! { self.handle_left = pub fn set_handle_left(&mut self, py: Python, value: [f32; 2]) }
+    bind_python! { self.handle_left_type => pub fn handle_left_type(&self, py: Python) -> Result<String> }
+    bind_python! { self.handle_left_type = pub fn set_handle_left_type(&mut self, py: Python, value: &str) }
+    bind_python! { self.handle_right => pub fn handle_right(&self, py: Python) -> Result<[f32; 2]> }
+    bind_python! { self.handle_right = pub fn set_handle_right(&mut self, py: Python, value: [f32; 2]) }
+    bind_python! { self.handle_right_type => pub fn handle_right_type(&self, py: Python) -> Result<String> }
+    bind_python! { self.handle_right_type = pub fn set_handle_right_type(&mut self, py: Python, value: &str) }
+    bind_python! { self.interpolation => pub fn interpolation(&self, py: Python) -> Result<KeyframeInterpolation> }
+    bind_python! { self.interpolation = pub fn set_interpolation(&mut self, py: Python, value: KeyframeInterpolation) }
+    bind_python! { self.period => pub fn period(&self, py: Python) -> Result<f32> }
+    bind_python! { self.period = pub fn set_period(&mut self, py: Python, value: f32) }
+    bind_python! { self.select_control_point => pub fn select_control_point(&self, py: Python) -> Result<bool> }
+    bind_python! { self.select_control_point = pub fn set_select_control_point(&mut self, py: Python, value: bool) }
+    bind_python! { self.type => pub fn keyframe_type(&self, py: Python) -> Result<String> }
+    bind_python! { self.type = pub fn set_keyframe_type(&mut self, py: Python, value: &str) }
+}
+
+impl From<pyo3::PyObject> for Keyframe {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Keyframe {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Keyframe {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Keyframe {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
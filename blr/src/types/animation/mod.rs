@@ -0,0 +1,17 @@
+pub mod action;
+pub mod animation_data;
+pub mod driver;
+pub mod driver_target;
+pub mod driver_variable;
+pub mod fcurve;
+pub mod fmodifier;
+pub mod keyframe;
+
+pub use action::Action;
+pub use animation_data::AnimationData;
+pub use driver::Driver;
+pub use driver_target::DriverTarget;
+pub use driver_variable::DriverVariable;
+pub use fcurve::FCurve;
+pub use fmodifier::FModifier;
+pub use keyframe::Keyframe;
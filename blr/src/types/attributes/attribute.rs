@@ -0,0 +1,113 @@
+use crate::{
+    enums::{AttributeDomain, AttributeType},
+    error::BlError,
+    result::Result,
+    types::{
+        collections::{foreach, ForeachScalar, ForeachValue},
+        BpyStruct,
+    },
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyAny, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Attribute.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Attribute(PyObject);
+
+impl BpyStruct for Attribute {}
+
+impl Attribute {
+    /// Returns the values of all elements of the attribute.
+    ///
+    /// The value type `T` must match the data type of the attribute, e.g. `f32` for
+    /// [`AttributeType::Float`], `[f32; 3]` for [`AttributeType::FloatVector`] or `[f32; 4]`
+    /// for [`AttributeType::FloatColor`].
+    pub fn data<T: ForeachValue>(&self, py: Python) -> Result<Vec<T>> {
+        let data_type = self.check_value_type::<T>(py)?;
+        foreach::foreach_get(self.raw_data(py)?, data_type.value_field())
+    }
+
+    /// Overwrites the values of all elements of the attribute.
+    ///
+    /// See [`Attribute::data`] for the supported value types.
+    pub fn set_data<T: ForeachValue>(&mut self, py: Python, values: &[T]) -> Result<()> {
+        let data_type = self.check_value_type::<T>(py)?;
+        let data = self.raw_data(py)?;
+        let n_elements = data.len()?;
+        if values.len() != n_elements {
+            return Err(BlError::ValueError(format!(
+                "Number of values does not match the size of attribute '{name}' (expected: {n_elements}, actual: {})",
+                values.len(),
+                name = self.name(py)?,
+            )));
+        }
+        foreach::foreach_set(data, data_type.value_field(), values)
+    }
+
+    fn check_value_type<T: ForeachValue>(&self, py: Python) -> Result<AttributeType> {
+        let data_type = self.data_type(py)?;
+        let scalar = match data_type {
+            AttributeType::Float
+            | AttributeType::FloatVector
+            | AttributeType::FloatColor
+            | AttributeType::ByteColor
+            | AttributeType::Float2
+            | AttributeType::Quaternion => f32::NAME,
+            AttributeType::Int | AttributeType::Int8 | AttributeType::Int32_2D => i32::NAME,
+            AttributeType::Boolean => bool::NAME,
+            AttributeType::String => {
+                return Err(BlError::TypeError(format!(
+                    "Attribute '{name}' of type '{data_type}' cannot be accessed in bulk",
+                    name = self.name(py)?,
+                )))
+            }
+        };
+        if scalar != T::Scalar::NAME || data_type.components() != T::COMPONENTS {
+            return Err(BlError::TypeError(format!(
+                "Attribute '{name}' of type '{data_type}' cannot be accessed as {}x {} (expected: {}x {scalar})",
+                T::COMPONENTS,
+                T::Scalar::NAME,
+                data_type.components(),
+                name = self.name(py)?,
+            )));
+        }
+        Ok(data_type)
+    }
+
+    fn raw_data<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> {
+        Ok(self.as_ref(py).getattr(intern!(py, "data"))?)
+    }
+
+    bind_python! { self.data_type => pub fn data_type(&self, py: Python) -> Result<AttributeType> }
+    bind_python! { self.domain => pub fn domain(&self, py: Python) -> Result<AttributeDomain> }
+    bind_python! { self.is_internal => pub fn is_internal(&self, py: Python) -> Result<bool> }
+    bind_python! { self.is_required => pub fn is_required(&self, py: Python) -> Result<bool> }
+    bind_python! { self.name => pub fn name(&self, py: Python) -> Result<String> }
+    bind_python! { self.name = pub fn set_name(&mut self, py: Python, value: &str) }
+}
+
+impl From<pyo3::PyObject> for Attribute {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Attribute {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Attribute {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Attribute {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
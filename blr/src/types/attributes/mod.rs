@@ -0,0 +1,3 @@
+pub mod attribute;
+
+pub use attribute::Attribute;
@@ -0,0 +1,58 @@
+use crate::types::BpyStruct;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Bone.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Bone(PyObject);
+
+impl BpyStruct for Bone {}
+
+impl Bone {
+    bind_python! { self.children => pub fn children(&self, py: Python) -> Result<Vec<Bone>> }
+    bind_python! { self.head => pub fn head(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.head_local => pub fn head_local(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.hide => pub fn hide(&self, py: Python) -> Result<bool> }
+    bind_python! { self.hide = pub fn set_hide(&mut self, py: Python, value: bool) }
+    bind_python! { self.length => pub fn length(&self, py: Python) -> Result<f32> }
+    bind_python! { self.matrix => pub fn matrix(&self, py: Python) -> Result<[[f32; 3]; 3]> }
+    bind_python! { self.matrix_local => pub fn matrix_local(&self, py: Python) -> Result<[[f32; 4]; 4]> }
+    bind_python! { self.name => pub fn name(&self, py: Python) -> Result<String> }
+    bind_python! { self.name = pub fn set_name(&mut self, py: Python, value: &str) }
+    bind_python! { self.parent => pub fn parent(&self, py: Python) -> Result<Option<Bone>> }
+    bind_python! { self.select => pub fn select(&self, py: Python) -> Result<bool> }
+    bind_python! { self.select = pub fn set_select(&mut self, py: Python, value: bool) }
+    bind_python! { self.tail => pub fn tail(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.tail_local => pub fn tail_local(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.use_connect => pub fn use_connect(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_deform => pub fn use_deform(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_deform = pub fn set_use_deform(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_inherit_rotation => pub fn use_inherit_rotation(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_inherit_rotation = pub fn set_use_inherit_rotation(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for Bone {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Bone {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Bone {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Bone {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::enums::AxisXYZ;
+
+/// Declaration of a single bone, see [`Armature::add_bones`](crate::types::Armature::add_bones).
+#[derive(Clone, Debug)]
+pub struct BoneSpec {
+    pub(crate) name: String,
+    pub(crate) head: [f32; 3],
+    pub(crate) tail: [f32; 3],
+    pub(crate) roll: f32,
+    pub(crate) parent: Option<String>,
+    pub(crate) use_connect: bool,
+    pub(crate) use_deform: bool,
+    pub(crate) joint_axis: Option<AxisXYZ>,
+}
+
+impl BoneSpec {
+    /// Declares a bone from `head` to `tail` in the local space of the armature.
+    #[must_use]
+    pub fn new(name: &str, head: [f32; 3], tail: [f32; 3]) -> Self {
+        Self {
+            name: name.to_string(),
+            head,
+            tail,
+            roll: 0.0,
+            parent: None,
+            use_connect: false,
+            use_deform: true,
+            joint_axis: None,
+        }
+    }
+
+    #[must_use]
+    pub fn roll(mut self, roll: f32) -> Self {
+        self.roll = roll;
+        self
+    }
+
+    /// Parents the bone to the bone called `parent`, which has to be declared before it.
+    #[must_use]
+    pub fn parent(mut self, parent: &str) -> Self {
+        self.parent = Some(parent.to_string());
+        self
+    }
+
+    /// Connects the head of the bone to the tail of its parent.
+    #[must_use]
+    pub fn use_connect(mut self, use_connect: bool) -> Self {
+        self.use_connect = use_connect;
+        self
+    }
+
+    #[must_use]
+    pub fn use_deform(mut self, use_deform: bool) -> Self {
+        self.use_deform = use_deform;
+        self
+    }
+
+    /// Makes the bone a revolute joint about `axis` by locking the other rotation axes of its
+    /// pose bone, see [`PoseBone::set_joint_angle`](crate::types::PoseBone::set_joint_angle).
+    ///
+    /// Pose bones only exist outside of edit mode, so the axis is applied by
+    /// [`Object::new_armature`](crate::types::Object::new_armature) or
+    /// [`Object::apply_joint_axes`](crate::types::Object::apply_joint_axes).
+    #[must_use]
+    pub fn joint_axis(mut self, axis: AxisXYZ) -> Self {
+        self.joint_axis = Some(axis);
+        self
+    }
+}
@@ -0,0 +1,9 @@
+pub mod bone;
+pub mod bone_spec;
+pub mod edit_bone;
+pub mod pose_bone;
+
+pub use bone::Bone;
+pub use bone_spec::BoneSpec;
+pub use edit_bone::EditBone;
+pub use pose_bone::PoseBone;
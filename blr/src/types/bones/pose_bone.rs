@@ -0,0 +1,149 @@
+use crate::{
+    enums::{AxisXYZ, RotationMode},
+    error::BlError,
+    result::Result,
+    types::{Bone, BpyStruct},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.PoseBone.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct PoseBone(PyObject);
+
+impl BpyStruct for PoseBone {}
+
+impl PoseBone {
+    /// Rotates the bone by `angle` (in radians) about its only unlocked rotation axis, e.g., to
+    /// pose a revolute joint of a robot arm.
+    ///
+    /// The rotation is written to the property that matches the rotation mode of the bone. Euler
+    /// angles of the locked axes are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not exactly one rotation axis of the bone is unlocked.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_joint_angle(&mut self, py: Python, angle: f32) -> Result<()> {
+        let axis = self.joint_axis(py)?;
+        self.set_angle_about_axis(py, axis, angle)
+    }
+
+    /// Rotates the bone by `angle` about `axis` (X = 0, Y = 1, Z = 2), see
+    /// [`PoseBone::set_joint_angle`].
+    pub(crate) fn set_angle_about_axis(
+        &mut self,
+        py: Python,
+        axis: usize,
+        angle: f32,
+    ) -> Result<()> {
+        match self.rotation_mode(py)? {
+            RotationMode::Quaternion => {
+                let (sin, cos) = (angle / 2.0).sin_cos();
+                let mut quaternion = [cos, 0.0, 0.0, 0.0];
+                quaternion[axis + 1] = sin;
+                self.set_rotation_quaternion(py, quaternion)?;
+            }
+            RotationMode::AxisAngle => {
+                let mut axis_angle = [angle, 0.0, 0.0, 0.0];
+                axis_angle[axis + 1] = 1.0;
+                self.set_rotation_axis_angle(py, axis_angle)?;
+            }
+            _ => {
+                let mut euler = self.rotation_euler(py)?;
+                euler[axis] = angle;
+                self.set_rotation_euler(py, euler)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Makes `axis` the only unlocked rotation axis, i.e., the axis of a revolute joint.
+    pub fn set_joint_axis(&mut self, py: Python, axis: AxisXYZ) -> Result<()> {
+        let mut lock_rotation = [true; 3];
+        lock_rotation[axis as usize] = false;
+        Ok(self.set_lock_rotation(py, lock_rotation)?)
+    }
+
+    /// Returns the index (X = 0, Y = 1, Z = 2) of the only rotation axis that is not locked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if not exactly one rotation axis of the bone is unlocked.
+    pub fn joint_axis(&self, py: Python) -> Result<usize> {
+        let unlocked: Vec<usize> = self
+            .lock_rotation(py)?
+            .iter()
+            .enumerate()
+            .filter_map(|(axis, locked)| (!locked).then_some(axis))
+            .collect();
+        match unlocked[..] {
+            [axis] => Ok(axis),
+            _ => Err(BlError::ValueError(format!(
+                "Pose bone '{}' requires exactly one unlocked rotation axis to be used as joint, but {} are unlocked",
+                self.name(py)?,
+                unlocked.len()
+            ))),
+        }
+    }
+
+    bind_python! { self.bone => pub fn bone(&self, py: Python) -> Result<Bone> }
+    bind_python! { self.children => pub fn children(&self, py: Python) -> Result<Vec<PoseBone>> }
+    bind_python! { self.head => pub fn head(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.length => pub fn length(&self, py: Python) -> Result<f32> }
+    bind_python! { self.location => pub fn location(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.location = pub fn set_location(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.lock_location => pub fn lock_location(&self, py: Python) -> Result<[bool; 3]> }
+    bind_python! { self.lock_location = pub fn set_lock_location(&mut self, py: Python, value: [bool; 3]) }
+    bind_python! { self.lock_rotation => pub fn lock_rotation(&self, py: Python) -> Result<[bool; 3]> }
+    bind_python! { self.lock_rotation = pub fn set_lock_rotation(&mut self, py: Python, value: [bool; 3]) }
+    bind_python! { self.lock_rotation_w => pub fn lock_rotation_w(&self, py: Python) -> Result<bool> }
+    bind_python! { self.lock_rotation_w = pub fn set_lock_rotation_w(&mut self, py: Python, value: bool) }
+    bind_python! { self.lock_rotations_4d => pub fn lock_rotations_4d(&self, py: Python) -> Result<bool> }
+    bind_python! { self.lock_rotations_4d = pub fn set_lock_rotations_4d(&mut self, py: Python, value: bool) }
+    bind_python! { self.lock_scale => pub fn lock_scale(&self, py: Python) -> Result<[bool; 3]> }
+    bind_python! { self.lock_scale = pub fn set_lock_scale(&mut self, py: Python, value: [bool; 3]) }
+    bind_python! { self.matrix => pub fn matrix(&self, py: Python) -> Result<[[f32; 4]; 4]> }
+    bind_python! { self.matrix = pub fn set_matrix(&mut self, py: Python, value: [[f32; 4]; 4]) }
+    bind_python! { self.matrix_basis => pub fn matrix_basis(&self, py: Python) -> Result<[[f32; 4]; 4]> }
+    bind_python! { self.matrix_basis = pub fn set_matrix_basis(&mut self, py: Python, value: [[f32; 4]; 4]) }
+    bind_python! { self.name => pub fn name(&self, py: Python) -> Result<String> }
+    bind_python! { self.parent => pub fn parent(&self, py: Python) -> Result<Option<PoseBone>> }
+    bind_python! { self.rotation_axis_angle => pub fn rotation_axis_angle(&self, py: Python) -> Result<[f32; 4]> }
+    bind_python! { self.rotation_axis_angle = pub fn set_rotation_axis_angle(&mut self, py: Python, value: [f32; 4]) }
+    bind_python! { self.rotation_euler => pub fn rotation_euler(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.rotation_euler = pub fn set_rotation_euler(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.rotation_mode => pub fn rotation_mode(&self, py: Python) -> Result<RotationMode> }
+    bind_python! { self.rotation_mode = pub fn set_rotation_mode(&mut self, py: Python, value: RotationMode) }
+    bind_python! { self.rotation_quaternion => pub fn rotation_quaternion(&self, py: Python) -> Result<[f32; 4]> }
+    bind_python! { self.rotation_quaternion = pub fn set_rotation_quaternion(&mut self, py: Python, value: [f32; 4]) }
+    bind_python! { self.scale => pub fn scale(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.scale = pub fn set_scale(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.tail => pub fn tail(&self, py: Python) -> Result<[f32; 3]> }
+}
+
+impl From<pyo3::PyObject> for PoseBone {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for PoseBone {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for PoseBone {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for PoseBone {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
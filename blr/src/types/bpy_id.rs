@@ -1,5 +1,10 @@
-use crate::types::{Scene, ViewLayer};
-use pyo3::{PyAny, PyObject};
+use crate::{
+    enums::{DataBlockKind, DriverType, KeyframeInterpolation},
+    error::BlError,
+    result::Result,
+    types::{bpy_struct, Action, AnimationData, Depsgraph, Driver, FCurve, Scene, ViewLayer},
+};
+use pyo3::{PyAny, PyObject, Python};
 use pyo3_macros_more::bind_python;
 use std::{
     collections::HashSet,
@@ -30,20 +35,123 @@ pub trait BpyID: Deref<Target = PyObject> + DerefMut<Target = PyObject> + pyo3::
     bind_python! { self.use_fake_user => fn use_fake_user(&self, py: Python) -> Result<bool> }
     bind_python! { self.use_fake_user = fn set_use_fake_user(&mut self, py: Python, value: bool) }
     bind_python! { self.users => fn users(&self, py: Python) -> Result<u32> }
-    bind_python! { self.evaluated_get() => fn evaluated_get<'py>(&'py self, py: Python<'py>, depsgraph: &PyAny) -> Result<&'py PyAny> }
+    bind_python! { self.evaluated_get() => fn evaluated_get<'py>(&'py self, py: Python<'py>, depsgraph: &Depsgraph) -> Result<&'py PyAny> }
     bind_python! { self.copy() => fn copy<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
     bind_python! { self.asset_mark() => fn asset_mark(&self, py: Python) }
     bind_python! { self.asset_clear() => fn asset_clear(&self, py: Python) }
     bind_python! { self.asset_generate_preview() => fn asset_generate_preview(&self, py: Python) }
     bind_python! { self.override_create() => fn override_create<'py>(&'py self, py: Python<'py>, remap_local_usages: bool) -> Result<&'py PyAny> }
-    bind_python! { self.override_hierarchy_create() => fn override_hierarchy_create<'py>(&'py self, py: Python<'py>, scene: Scene, view_layer: ViewLayer, reference: Option<impl BpyID>, do_fully_editable: bool) -> Result<&'py PyAny> }
+    bind_python! { self.override_hierarchy_create() => fn override_hierarchy_create<'py>(&'py self, py: Python<'py>, scene: &Scene, view_layer: &ViewLayer, reference: Option<impl BpyID>, do_fully_editable: bool) -> Result<&'py PyAny> }
     bind_python! { self.override_template_create() => fn override_template_create(&self, py: Python)}
     bind_python! { self.user_clear() => fn user_clear(&self, py: Python)}
     bind_python! { self.user_remap() => fn user_remap(&self, py: Python, new_id: impl BpyID) }
     bind_python! { self.make_local() => fn make_local<'py>(&'py self, py: Python<'py>, clear_proxy: bool) -> Result<&'py PyAny> }
     bind_python! { self.user_of_id() => fn user_of_id(&self, py: Python, id: impl BpyID) -> Result<u32> }
-    bind_python! { self.animation_data_create() => fn animation_data_create<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.animation_data_create() => fn animation_data_create(&self, py: Python) -> Result<AnimationData> }
     bind_python! { self.animation_data_clear() => fn animation_data_clear(&self, py: Python) }
+    bind_python! { self.driver_remove() => fn driver_remove(&self, py: Python, path: &str, index: isize) -> Result<bool> }
     bind_python! { self.update_tag() => fn update_tag(&self, py: Python, refresh: HashSet<String>) }
     bind_python! { self.preview_ensure() => fn preview_ensure<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+
+    /// Adds a driver to element `index` of property `path` and returns its F-curve.
+    ///
+    /// Use index `-1` for properties that are not arrays.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index` is `-1` but the property is an array, whose elements are
+    /// driven by separate F-curves (see [`BpyID::driver_add_all`]).
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    fn driver_add(&self, py: Python, path: &str, index: isize) -> Result<FCurve> {
+        bpy_struct::driver_add(self.as_ref(py), path, index)
+    }
+
+    /// Adds drivers to all elements of property `path` and returns their F-curves, which is a
+    /// single F-curve for properties that are not arrays.
+    fn driver_add_all(&self, py: Python, path: &str) -> Result<Vec<FCurve>> {
+        bpy_struct::driver_add_all(self.as_ref(py), path)
+    }
+
+    /// Returns the action that animates this data-block, which is created (together with the
+    /// animation data) if it does not exist yet.
+    fn action_ensure(&self, py: Python) -> Result<Action> {
+        let mut animation_data = self.animation_data_create(py)?;
+        if let Some(action) = animation_data.action(py)? {
+            return Ok(action);
+        }
+        let action = Action::new(py, &format!("{}Action", self.name(py)?))?;
+        animation_data.set_action(py, Some(&action))?;
+        Ok(action)
+    }
+
+    /// Inserts keyframes of all `N` elements of the property at `data_path` in bulk, e.g.,
+    /// `"location"` with one `[x, y, z]` per frame or `"[\"prop\"]"` with one `[value]` per frame.
+    ///
+    /// Returns the F-curves of the elements, which are created in the action of the data-block
+    /// if necessary (see [`BpyID::action_ensure`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of `frames` and `values` differs.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    fn insert_keyframes<const N: usize>(
+        &self,
+        py: Python,
+        data_path: &str,
+        frames: &[f32],
+        values: &[[f32; N]],
+        interpolation: KeyframeInterpolation,
+    ) -> Result<Vec<FCurve>> {
+        if frames.len() != values.len() {
+            return Err(BlError::ValueError(format!(
+                "Keyframes of '{data_path}' require one value per frame, but {} frames and {} values are given",
+                frames.len(),
+                values.len()
+            )));
+        }
+        let action = self.action_ensure(py)?;
+        (0..N)
+            .map(|index| {
+                let points: Vec<[f32; 2]> = frames
+                    .iter()
+                    .zip(values)
+                    .map(|(&frame, value)| [frame, value[index]])
+                    .collect();
+                let mut fcurve = action.fcurve_ensure(py, data_path, index, None)?;
+                fcurve.insert_keyframes(py, &points, interpolation)?;
+                Ok(fcurve)
+            })
+            .collect()
+    }
+
+    /// Drives element `index` of property `data_path` by property `source_data_path` of `source`,
+    /// which is available as variable `var` in `expression` (e.g., `"var * 2"`).
+    ///
+    /// Use index `-1` for properties that are not arrays.
+    fn add_property_driver(
+        &self,
+        py: Python,
+        data_path: &str,
+        index: isize,
+        source: &impl BpyID,
+        source_data_path: &str,
+        expression: &str,
+    ) -> Result<Driver> {
+        let Some(mut driver) = self.driver_add(py, data_path, index)?.driver(py)? else {
+            return Err(BlError::ValueError(format!(
+                "Driver of '{data_path}' cannot be created"
+            )));
+        };
+        driver.set_driver_type(py, DriverType::Scripted)?;
+        driver.add_single_property(py, "var", source, source_data_path)?;
+        driver.set_expression(py, expression)?;
+        Ok(driver)
+    }
+}
+
+/// Data-block of a single [`DataBlockKind`], e.g., to append or link it from a library via
+/// [`library::append`](crate::library::append).
+pub trait DataBlock: BpyID + From<PyObject> {
+    /// Kind of the data-block, which determines the collection of `bpy.data` it is stored in.
+    const KIND: DataBlockKind;
 }
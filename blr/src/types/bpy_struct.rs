@@ -1,13 +1,20 @@
-use pyo3::{PyAny, PyObject};
+use crate::{error::BlError, result::Result, types::FCurve};
+use pyo3::{
+    intern,
+    types::{PyList, PyString},
+    PyAny, PyObject, Python,
+};
 use pyo3_macros_more::bind_python;
-use std::ops::{Deref, DerefMut};
+use std::{
+    collections::HashSet,
+    ops::{Deref, DerefMut},
+};
 
 /// Wrapper all subclasses of around <https://docs.blender.org/api/latest/bpy.types.bpy_struct.html>
 pub trait BpyStruct:
     Deref<Target = PyObject> + DerefMut<Target = PyObject> + pyo3::ToPyObject
 {
     bind_python! { self.as_pointer() => fn as_pointer(&self, py: Python) -> Result<usize> }
-    bind_python! { self.driver_add() => fn driver_add<'py>(&'py self, py: Python<'py>, path: &str, index: isize) -> Result<&'py PyAny> }
     bind_python! { self.driver_remove() => fn driver_remove(&self, py: Python, path: &str, index: isize) -> Result<bool> }
     bind_python! { self.get() => fn get<'py>(&'py self, py: Python<'py>, key: &str) -> Result<&'py PyAny> }
     bind_python! { self.id_properties_clear() => fn id_properties_clear(&self, py: Python) }
@@ -19,7 +26,7 @@ pub trait BpyStruct:
     bind_python! { self.is_property_set() => fn is_property_set(&self, py: Python, property: &PyAny, ghost: bool) -> Result<bool> }
     bind_python! { self.items() => fn items<'py>(&'py self, py: Python<'py>) -> Result<Vec<(String, &'py PyAny)>> }
     bind_python! { self.keyframe_delete() => fn keyframe_delete(&self, py: Python, data_path: &str, index: isize, frame: f32, group: &str) -> Result<bool> }
-    bind_python! { self.keyframe_insert() => fn keyframe_insert(&self, py: Python, data_path: &str, index: isize, frame: f32, group: &str, options: &PyAny) -> Result<bool> }
+    bind_python! { self.keyframe_insert() => fn keyframe_insert(&self, py: Python, data_path: &str, index: isize, frame: f32, group: &str, options: HashSet<String>) -> Result<bool> }
     bind_python! { self.keys() => fn keys(&self, py: Python) -> Result<Vec<String>> }
     bind_python! { self.path_from_id() => fn path_from_id(&self, py: Python, property: &str) -> Result<String> }
     bind_python! { self.path_resolve() => fn path_resolve(&self, py: Python, path: &str, coerce: bool) }
@@ -28,4 +35,52 @@ pub trait BpyStruct:
     bind_python! { self.property_unset() => fn property_unset(&self, py: Python, property: &PyAny) }
     bind_python! { self.type_recast() => fn type_recast<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
     bind_python! { self.values() => fn values<'py>(&'py self, py: Python<'py>) -> Result<Vec<&'py PyAny>> }
+
+    /// Adds a driver to element `index` of property `path` and returns its F-curve.
+    ///
+    /// Use index `-1` for properties that are not arrays.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `index` is `-1` but the property is an array, whose elements are
+    /// driven by separate F-curves (see [`BpyStruct::driver_add_all`]).
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    fn driver_add(&self, py: Python, path: &str, index: isize) -> Result<FCurve> {
+        driver_add(self.as_ref(py), path, index)
+    }
+
+    /// Adds drivers to all elements of property `path` and returns their F-curves, which is a
+    /// single F-curve for properties that are not arrays.
+    fn driver_add_all(&self, py: Python, path: &str) -> Result<Vec<FCurve>> {
+        driver_add_all(self.as_ref(py), path)
+    }
+}
+
+/// Shared implementation of [`BpyStruct::driver_add`] and
+/// [`BpyID::driver_add`](crate::types::BpyID::driver_add).
+pub(crate) fn driver_add(owner: &PyAny, path: &str, index: isize) -> Result<FCurve> {
+    let py = owner.py();
+    if index == -1 {
+        // Checked beforehand, as Blender would already add drivers to all elements
+        let value = owner.call_method1(intern!(py, "path_resolve"), (path,))?;
+        if value.len().is_ok() && !value.is_instance_of::<PyString>() {
+            return Err(BlError::ValueError(format!(
+                "Property '{path}' is an array, so a driver requires the index of an element"
+            )));
+        }
+    }
+    Ok(owner
+        .call_method1(intern!(py, "driver_add"), (path, index))?
+        .extract()?)
+}
+
+/// Shared implementation of [`BpyStruct::driver_add_all`] and
+/// [`BpyID::driver_add_all`](crate::types::BpyID::driver_add_all).
+pub(crate) fn driver_add_all(owner: &PyAny, path: &str) -> Result<Vec<FCurve>> {
+    let fcurves = owner.call_method1(intern!(owner.py(), "driver_add"), (path, -1))?;
+    if fcurves.is_instance_of::<PyList>() {
+        Ok(fcurves.extract()?)
+    } else {
+        Ok(vec![fcurves.extract()?])
+    }
 }
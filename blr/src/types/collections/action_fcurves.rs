@@ -0,0 +1,46 @@
+use super::CollectionImpl;
+use crate::{result::Result, types::FCurve};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.ActionFCurves.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct ActionFCurves(PyObject);
+
+impl<'py> CollectionImpl<'py> for ActionFCurves {
+    type Item = FCurve;
+}
+
+impl ActionFCurves {
+    /// Returns the F-curve that animates element `index` of `data_path` if it exists.
+    pub fn find_fcurve(&self, py: Python, data_path: &str, index: usize) -> Result<Option<FCurve>> {
+        Ok(self
+            .as_ref(py)
+            .call_method1(intern!(py, "find"), (data_path, index))?
+            .extract()?)
+    }
+
+    bind_python! { self.new() => pub fn new(&self, py: Python, data_path: &str, index: usize, action_group: &str) -> Result<FCurve> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, fcurve: &FCurve) }
+    bind_python! { self.clear() => pub fn clear(&self, py: Python) }
+}
+
+impl From<pyo3::PyObject> for ActionFCurves {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for ActionFCurves {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ActionFCurves {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
@@ -0,0 +1,36 @@
+use super::CollectionImpl;
+use crate::types::Bone;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.ArmatureBones.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct ArmatureBones(PyObject);
+
+impl<'py> CollectionImpl<'py> for ArmatureBones {
+    type Item = Bone;
+}
+
+impl ArmatureBones {
+    bind_python! { self.active => pub fn active(&self, py: Python) -> Result<Option<Bone>> }
+}
+
+impl From<pyo3::PyObject> for ArmatureBones {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for ArmatureBones {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ArmatureBones {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
@@ -0,0 +1,47 @@
+use super::CollectionImpl;
+use crate::{result::Result, types::EditBone};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.ArmatureEditBones.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct ArmatureEditBones(PyObject);
+
+impl<'py> CollectionImpl<'py> for ArmatureEditBones {
+    type Item = EditBone;
+}
+
+impl ArmatureEditBones {
+    /// Removes all edit bones.
+    pub fn clear(&self, py: Python) -> Result<()> {
+        for bone in self.values(py)? {
+            self.remove(py, &bone)?;
+        }
+        Ok(())
+    }
+
+    bind_python! { self.active => pub fn active(&self, py: Python) -> Result<Option<EditBone>> }
+    bind_python! { self.active = pub fn set_active(&mut self, py: Python, value: &EditBone) }
+    bind_python! { self.new() => pub fn new(&self, py: Python, name: &str) -> Result<EditBone> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, bone: &EditBone) }
+}
+
+impl From<pyo3::PyObject> for ArmatureEditBones {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for ArmatureEditBones {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ArmatureEditBones {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
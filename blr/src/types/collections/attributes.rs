@@ -0,0 +1,83 @@
+use super::{CollectionImpl, ForeachValue};
+use crate::{
+    enums::{AttributeDomain, AttributeType},
+    error::BlError,
+    result::Result,
+    types::Attribute,
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.AttributeGroup.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Attributes(PyObject);
+
+impl<'py> CollectionImpl<'py> for Attributes {
+    type Item = Attribute;
+}
+
+impl Attributes {
+    /// Returns attribute `name` if it is stored on the given `domain`.
+    pub fn get_on_domain(
+        &self,
+        py: Python,
+        name: &str,
+        domain: AttributeDomain,
+    ) -> Result<Attribute> {
+        let attribute = self.get(py, name)?;
+        let attribute_domain = attribute.domain(py)?;
+        if attribute_domain == domain {
+            Ok(attribute)
+        } else {
+            Err(BlError::ValueError(format!(
+                "Attribute '{name}' is stored on domain '{attribute_domain}' instead of '{domain}'"
+            )))
+        }
+    }
+
+    /// Returns the values of attribute `name`, see [`Attribute::data`].
+    pub fn get_data<T: ForeachValue>(&self, py: Python, name: &str) -> Result<Vec<T>> {
+        self.get(py, name)?.data(py)
+    }
+
+    /// Overwrites the values of attribute `name`, see [`Attribute::set_data`].
+    pub fn set_data<T: ForeachValue>(&self, py: Python, name: &str, values: &[T]) -> Result<()> {
+        self.get(py, name)?.set_data(py, values)
+    }
+
+    pub fn active(&self, py: Python) -> Result<Option<Attribute>> {
+        Ok(self.getattr(py, intern!(py, "active"))?.extract(py)?)
+    }
+
+    bind_python! { self.active = pub fn set_active(&mut self, py: Python, value: &Attribute) }
+    bind_python! { self.active_index => pub fn active_index(&self, py: Python) -> Result<isize> }
+    bind_python! { self.active_index = pub fn set_active_index(&mut self, py: Python, value: isize) }
+    bind_python! { self.new() => pub fn new(&self, py: Python, name: &str, r#type: AttributeType, domain: AttributeDomain) -> Result<Attribute> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, attribute: &Attribute) }
+}
+
+impl From<pyo3::PyObject> for Attributes {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Attributes {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Attributes {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Attributes {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
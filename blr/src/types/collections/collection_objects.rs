@@ -0,0 +1,37 @@
+use super::CollectionImpl;
+use crate::types::Object;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.CollectionObjects.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct CollectionObjects(PyObject);
+
+impl<'py> CollectionImpl<'py> for CollectionObjects {
+    type Item = Object;
+}
+
+impl CollectionObjects {
+    bind_python! { self.link() => pub fn link(&self, py: Python, object: &Object) }
+    bind_python! { self.unlink() => pub fn unlink(&self, py: Python, object: &Object) }
+}
+
+impl From<pyo3::PyObject> for CollectionObjects {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for CollectionObjects {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for CollectionObjects {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
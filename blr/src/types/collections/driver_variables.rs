@@ -0,0 +1,37 @@
+use super::CollectionImpl;
+use crate::types::DriverVariable;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.ChannelDriverVariables.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct DriverVariables(PyObject);
+
+impl<'py> CollectionImpl<'py> for DriverVariables {
+    type Item = DriverVariable;
+}
+
+impl DriverVariables {
+    bind_python! { self.new() => pub fn new(&self, py: Python) -> Result<DriverVariable> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, variable: &DriverVariable) }
+}
+
+impl From<pyo3::PyObject> for DriverVariables {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for DriverVariables {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DriverVariables {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
@@ -0,0 +1,40 @@
+use super::CollectionImpl;
+use crate::types::Keyframe;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+use std::collections::HashSet;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.FCurveKeyframePoints.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct FCurveKeyframePoints(PyObject);
+
+impl<'py> CollectionImpl<'py> for FCurveKeyframePoints {
+    type Item = Keyframe;
+}
+
+impl FCurveKeyframePoints {
+    bind_python! { self.add() => pub fn add(&self, py: Python, count: usize) }
+    bind_python! { self.insert() => pub fn insert(&self, py: Python, frame: f32, value: f32, options: HashSet<String>, keyframe_type: &str) -> Result<Keyframe> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, keyframe: &Keyframe, fast: bool) }
+    bind_python! { self.clear() => pub fn clear(&self, py: Python) }
+}
+
+impl From<pyo3::PyObject> for FCurveKeyframePoints {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for FCurveKeyframePoints {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for FCurveKeyframePoints {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
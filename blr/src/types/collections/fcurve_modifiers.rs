@@ -0,0 +1,38 @@
+use super::CollectionImpl;
+use crate::{enums::FModifierType, types::FModifier};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.FCurveModifiers.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct FCurveModifiers(PyObject);
+
+impl<'py> CollectionImpl<'py> for FCurveModifiers {
+    type Item = FModifier;
+}
+
+impl FCurveModifiers {
+    bind_python! { self.active => pub fn active(&self, py: Python) -> Result<Option<FModifier>> }
+    bind_python! { self.new() => pub fn new(&self, py: Python, r#type: FModifierType) -> Result<FModifier> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, modifier: &FModifier) }
+}
+
+impl From<pyo3::PyObject> for FCurveModifiers {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for FCurveModifiers {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for FCurveModifiers {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
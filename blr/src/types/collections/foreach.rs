@@ -0,0 +1,248 @@
+//! Bulk access to properties of collection items via
+//! [`foreach_get`](https://docs.blender.org/api/latest/bpy.types.bpy_prop_collection.html#bpy.types.bpy_prop_collection.foreach_get)
+//! and [`foreach_set`](https://docs.blender.org/api/latest/bpy.types.bpy_prop_collection.html#bpy.types.bpy_prop_collection.foreach_set).
+//!
+//! Numeric data is transferred through a `memoryview` that borrows the memory of the returned
+//! (or given) Rust values, so that Blender reads and writes them in place without any copies.
+use crate::result::Result;
+use pyo3::{
+    intern,
+    types::{PyList, PyString},
+    PyAny, Python,
+};
+
+/// Scalar type that can be transferred in bulk between Blender and Rust.
+pub trait ForeachScalar: Sized + Copy {
+    /// Name of the Rust type, used in error messages.
+    const NAME: &'static str;
+
+    /// Value that buffers are initialised with before they are filled by Blender.
+    const ZERO: Self;
+
+    /// Reads property `attr` of all items of `collection` into `buffer`, or all values of the
+    /// array property `collection` itself if `attr` is `None`.
+    fn foreach_get(collection: &PyAny, attr: Option<&str>, buffer: &mut [Self]) -> Result<()>;
+
+    /// Writes `values` into property `attr` of all items of `collection`, or into the array
+    /// property `collection` itself if `attr` is `None`.
+    fn foreach_set(collection: &PyAny, attr: Option<&str>, values: &[Self]) -> Result<()>;
+}
+
+macro_rules! impl_foreach_scalar_via_memoryview {
+    ($scalar:ty, $zero:literal, $format:literal) => {
+        impl ForeachScalar for $scalar {
+            const NAME: &'static str = stringify!($scalar);
+            const ZERO: Self = $zero;
+
+            fn foreach_get(
+                collection: &PyAny,
+                attr: Option<&str>,
+                buffer: &mut [Self],
+            ) -> Result<()> {
+                let py = collection.py();
+                let len = buffer.len();
+                with_memoryview(py, buffer.as_mut_ptr(), len, true, $format, |view| {
+                    call_foreach(collection, intern!(py, "foreach_get"), attr, view)
+                })
+            }
+
+            fn foreach_set(collection: &PyAny, attr: Option<&str>, values: &[Self]) -> Result<()> {
+                let py = collection.py();
+                with_memoryview(
+                    py,
+                    values.as_ptr().cast_mut(),
+                    values.len(),
+                    false,
+                    $format,
+                    |view| call_foreach(collection, intern!(py, "foreach_set"), attr, view),
+                )
+            }
+        }
+    };
+}
+
+impl_foreach_scalar_via_memoryview!(f32, 0.0, "f");
+impl_foreach_scalar_via_memoryview!(i32, 0, "i");
+
+/// Booleans have no buffer format that Blender accepts, so a list is used instead.
+impl ForeachScalar for bool {
+    const NAME: &'static str = "bool";
+    const ZERO: Self = false;
+
+    fn foreach_get(collection: &PyAny, attr: Option<&str>, buffer: &mut [Self]) -> Result<()> {
+        let py = collection.py();
+
+        let list = PyList::new(py, vec![false; buffer.len()]);
+        call_foreach(collection, intern!(py, "foreach_get"), attr, list)?;
+        buffer.copy_from_slice(&list.extract::<Vec<bool>>()?);
+        Ok(())
+    }
+
+    fn foreach_set(collection: &PyAny, attr: Option<&str>, values: &[Self]) -> Result<()> {
+        let py = collection.py();
+
+        call_foreach(
+            collection,
+            intern!(py, "foreach_set"),
+            attr,
+            PyList::new(py, values),
+        )
+    }
+}
+
+/// Calls `method` of `collection` with `sequence`, preceded by `attr` for collections of items.
+fn call_foreach(
+    collection: &PyAny,
+    method: &PyString,
+    attr: Option<&str>,
+    sequence: &PyAny,
+) -> Result<()> {
+    match attr {
+        Some(attr) => collection.call_method1(method, (attr, sequence))?,
+        None => collection.call_method1(method, (sequence,))?,
+    };
+    Ok(())
+}
+
+/// Value composed of a fixed number of scalars, e.g. a 3D vector.
+///
+/// # Safety
+///
+/// The memory layout of the type must be identical to `[Self::Scalar; Self::COMPONENTS]`,
+/// because slices of values are reinterpreted as slices of scalars.
+pub unsafe trait ForeachValue: Sized + Copy {
+    type Scalar: ForeachScalar;
+
+    /// Number of scalars per value.
+    const COMPONENTS: usize;
+
+    /// Value that buffers are initialised with before they are filled by Blender.
+    const ZERO: Self;
+}
+
+macro_rules! impl_foreach_value_for_scalar {
+    ($($scalar:ty),+) => {$(
+        // SAFETY: A scalar has the layout of an array with a single scalar.
+        unsafe impl ForeachValue for $scalar {
+            type Scalar = $scalar;
+
+            const COMPONENTS: usize = 1;
+            const ZERO: Self = <$scalar as ForeachScalar>::ZERO;
+        }
+    )+};
+}
+
+impl_foreach_value_for_scalar!(f32, i32, bool);
+
+// SAFETY: Arrays are laid out contiguously without any padding.
+unsafe impl<T: ForeachScalar, const N: usize> ForeachValue for [T; N] {
+    type Scalar = T;
+
+    const COMPONENTS: usize = N;
+    const ZERO: Self = [T::ZERO; N];
+}
+
+/// Reads property `attr` of all items of `collection` as values of type `T`.
+pub(crate) fn foreach_get<T: ForeachValue>(collection: &PyAny, attr: &str) -> Result<Vec<T>> {
+    get_values(collection, Some(attr), collection.len()?)
+}
+
+/// Writes `values` into property `attr` of all items of `collection`.
+pub(crate) fn foreach_set<T: ForeachValue>(
+    collection: &PyAny,
+    attr: &str,
+    values: &[T],
+) -> Result<()> {
+    set_values(collection, Some(attr), values)
+}
+
+/// Reads all `len` values of an array property, e.g.
+/// [`Image.pixels`](https://docs.blender.org/api/latest/bpy.types.Image.html#bpy.types.Image.pixels).
+pub(crate) fn array_get<T: ForeachValue>(array: &PyAny, len: usize) -> Result<Vec<T>> {
+    get_values(array, None, len)
+}
+
+/// Writes all `values` into an array property.
+pub(crate) fn array_set<T: ForeachValue>(array: &PyAny, values: &[T]) -> Result<()> {
+    set_values(array, None, values)
+}
+
+fn get_values<T: ForeachValue>(
+    collection: &PyAny,
+    attr: Option<&str>,
+    len: usize,
+) -> Result<Vec<T>> {
+    let mut values = vec![T::ZERO; len];
+    // SAFETY: `T` has the layout of `[T::Scalar; T::COMPONENTS]` (see `ForeachValue`).
+    let scalars = unsafe {
+        std::slice::from_raw_parts_mut(
+            values.as_mut_ptr().cast::<T::Scalar>(),
+            values.len() * T::COMPONENTS,
+        )
+    };
+    T::Scalar::foreach_get(collection, attr, scalars)?;
+    Ok(values)
+}
+
+fn set_values<T: ForeachValue>(collection: &PyAny, attr: Option<&str>, values: &[T]) -> Result<()> {
+    // SAFETY: `T` has the layout of `[T::Scalar; T::COMPONENTS]` (see `ForeachValue`).
+    let scalars = unsafe {
+        std::slice::from_raw_parts(
+            values.as_ptr().cast::<T::Scalar>(),
+            values.len() * T::COMPONENTS,
+        )
+    };
+    T::Scalar::foreach_set(collection, attr, scalars)
+}
+
+/// Calls `f` with a `memoryview` of the given buffer `format` (e.g., `"f"`) over `len` values
+/// starting at `data`, which is released afterwards so that Python cannot outlive the borrow (see
+/// [`release_or_abort`]).
+///
+/// The caller must ensure that `data` is valid (and writable if `writable`) for `len` values.
+fn with_memoryview<T, R>(
+    py: Python,
+    data: *mut T,
+    len: usize,
+    writable: bool,
+    format: &str,
+    f: impl FnOnce(&PyAny) -> Result<R>,
+) -> Result<R> {
+    // Flags of `PyMemoryView_FromMemory`, which are not exposed by `pyo3::ffi` for the limited API
+    const PYBUF_READ: std::os::raw::c_int = 0x100;
+    const PYBUF_WRITE: std::os::raw::c_int = 0x200;
+
+    let flags = if writable { PYBUF_WRITE } else { PYBUF_READ };
+    // SAFETY: `data` is valid for `len` values of `T` during this call (see above), and the
+    // views are released before returning.
+    let bytes: &PyAny = unsafe {
+        py.from_owned_ptr_or_err(pyo3::ffi::PyMemoryView_FromMemory(
+            data.cast(),
+            (len * std::mem::size_of::<T>()) as pyo3::ffi::Py_ssize_t,
+            flags,
+        ))?
+    };
+    let view = match bytes.call_method1(intern!(py, "cast"), (format,)) {
+        Ok(view) => view,
+        Err(err) => {
+            release_or_abort(bytes);
+            return Err(err.into());
+        }
+    };
+
+    let result = f(view);
+
+    release_or_abort(view);
+    release_or_abort(bytes);
+    result
+}
+
+/// Releases a `memoryview` of Rust memory, which fails if Python still holds an export of it
+/// (e.g., a callee kept a buffer). The process is aborted in that case, as Python could otherwise
+/// access the memory after it has been freed.
+fn release_or_abort(view: &PyAny) {
+    if let Err(err) = view.call_method0(intern!(view.py(), "release")) {
+        err.print(view.py());
+        std::process::abort();
+    }
+}
@@ -0,0 +1,67 @@
+use super::CollectionImpl;
+use crate::{error::BlError, result::Result, types::Image};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, types::PyDict, PyObject, Python, ToPyObject};
+use pyo3_macros_more::bind_python;
+use std::path::Path;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.BlendDataImages.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Images(PyObject);
+
+impl<'py> CollectionImpl<'py> for Images {
+    type Item = Image;
+}
+
+impl Images {
+    /// Loads the image at `filepath`, which is reused if it is already loaded and
+    /// `check_existing` is enabled.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given `filepath` does not point to a valid file.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn load(
+        &self,
+        py: Python,
+        filepath: impl AsRef<Path>,
+        check_existing: bool,
+    ) -> Result<Image> {
+        let filepath = filepath.as_ref();
+        if !filepath.is_file() {
+            return Err(BlError::ValueError(format!(
+                "Filepath '{}' does not point to a valid file.",
+                filepath.display()
+            )));
+        }
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "check_existing"), check_existing)?;
+        Ok(self
+            .as_ref(py)
+            .call_method(intern!(py, "load"), (filepath.to_object(py),), Some(kwargs))?
+            .extract()?)
+    }
+
+    bind_python! { self.new() => pub fn new(&self, py: Python, name: &str, width: u32, height: u32, alpha: bool, float_buffer: bool) -> Result<Image> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, image: &Image, do_unlink: bool, do_id_user: bool, do_ui_user: bool) }
+    bind_python! { self.tag() => pub fn tag(&self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for Images {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Images {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Images {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
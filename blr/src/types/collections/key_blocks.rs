@@ -0,0 +1,31 @@
+use super::CollectionImpl;
+use crate::types::ShapeKey;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Key.html#bpy.types.Key.key_blocks>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct KeyBlocks(PyObject);
+
+impl<'py> CollectionImpl<'py> for KeyBlocks {
+    type Item = ShapeKey;
+}
+
+impl From<pyo3::PyObject> for KeyBlocks {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for KeyBlocks {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for KeyBlocks {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
@@ -1,22 +1,57 @@
+pub mod action_fcurves;
+pub mod armature_bones;
+pub mod armature_edit_bones;
+pub mod attributes;
 pub mod collection;
+pub mod collection_children;
+pub mod collection_objects;
+pub mod driver_variables;
+pub mod fcurve_keyframe_points;
+pub mod fcurve_modifiers;
+pub mod foreach;
+pub mod images;
+pub mod key_blocks;
 pub mod materials;
 pub mod node_links;
+pub mod node_sockets;
 pub mod node_trees;
 pub mod node_trees_io;
 pub mod nodes;
 pub mod object_collection;
+pub mod object_constraints;
 pub mod object_modifiers;
+pub mod pose_bones;
+pub mod scene_collections;
+pub mod vertex_groups;
 
+use crate::result::Result;
+pub use action_fcurves::ActionFCurves;
+pub use armature_bones::ArmatureBones;
+pub use armature_edit_bones::ArmatureEditBones;
+pub use attributes::Attributes;
 pub use collection::Collection;
+pub use collection_children::CollectionChildren;
+pub use collection_objects::CollectionObjects;
+pub use driver_variables::DriverVariables;
+pub use fcurve_keyframe_points::FCurveKeyframePoints;
+pub use fcurve_modifiers::FCurveModifiers;
+pub use foreach::{ForeachScalar, ForeachValue};
+pub use images::Images;
+pub use key_blocks::KeyBlocks;
 pub use materials::Materials;
 pub use node_links::NodeLinks;
+pub use node_sockets::{NodeIO, NodeInputs, NodeOutputs};
 pub use node_trees::NodeTrees;
 pub use node_trees_io::{NodeTreeInputs, NodeTreeOutputs};
 pub use nodes::Nodes;
 pub use object_collection::ObjectCollection;
+pub use object_constraints::ObjectConstraints;
 pub use object_modifiers::ObjectModifiers;
+pub use pose_bones::PoseBones;
 pub use pyo3::{intern, FromPyObject, PyObject, PyResult, Python};
 use pyo3_macros_more::bind_python;
+pub use scene_collections::SceneCollections;
+pub use vertex_groups::VertexGroups;
 
 /// Implementation of <https://docs.blender.org/api/latest/bpy.types.bpy_prop_collection.html>
 pub trait CollectionImpl<'py>:
@@ -48,6 +83,28 @@ pub trait CollectionImpl<'py>:
         }
     }
 
+    fn get_index(&'py self, py: Python<'py>, index: usize) -> PyResult<Self::Item> {
+        self.as_ref(py).get_item(index)?.extract()
+    }
+
+    fn len(&self, py: Python) -> PyResult<usize> {
+        self.as_ref(py).len()
+    }
+
+    fn is_empty(&self, py: Python) -> PyResult<bool> {
+        Ok(self.len(py)? == 0)
+    }
+
+    /// Reads property `attr` of all items in bulk, see [`foreach`].
+    fn foreach_get<T: ForeachValue>(&self, py: Python, attr: &str) -> Result<Vec<T>> {
+        foreach::foreach_get(self.as_ref(py), attr)
+    }
+
+    /// Writes property `attr` of all items in bulk, see [`foreach`].
+    fn foreach_set<T: ForeachValue>(&self, py: Python, attr: &str, values: &[T]) -> Result<()> {
+        foreach::foreach_set(self.as_ref(py), attr, values)
+    }
+
     bind_python! { self.items() => fn items(&'py self, py: Python<'py>) -> Result<Vec<(String, Self::Item)>> }
     bind_python! { self.keys() => fn keys(&self, py: Python) -> Result<Vec<String>> }
     bind_python! { self.values() => fn values(&'py self, py: Python<'py>) -> Result<Vec<Self::Item>> }
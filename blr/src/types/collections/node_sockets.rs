@@ -0,0 +1,66 @@
+use super::CollectionImpl;
+use crate::{error::BlError, result::Result, types::nodes::NodeSocket};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.NodeInputs.html>
+pub type NodeInputs = NodeIO;
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.NodeOutputs.html>
+pub type NodeOutputs = NodeIO;
+
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct NodeIO(PyObject);
+
+impl<'py> CollectionImpl<'py> for NodeIO {
+    type Item = NodeSocket;
+}
+
+impl NodeIO {
+    /// Returns the socket with the given unique `identifier`, which can differ from its name
+    /// (e.g., for multiple sockets with the same name).
+    pub fn get_identifier(&self, py: Python, identifier: &str) -> Result<NodeSocket> {
+        for socket in self.as_ref(py).iter()? {
+            let socket = socket?;
+            if socket
+                .getattr(intern!(py, "identifier"))?
+                .extract::<&str>()?
+                == identifier
+            {
+                return Ok(socket.into());
+            }
+        }
+        Err(BlError::ValueError(format!(
+            "Socket with identifier '{identifier}' cannot be found in collection"
+        )))
+    }
+
+    pub fn r#move(&self, py: Python, from_index: usize, to_index: usize) -> Result<()> {
+        self.as_ref(py)
+            .call_method1(intern!(py, "move"), (from_index, to_index))?;
+        Ok(())
+    }
+
+    bind_python! { self.new() => pub fn new(&self, py: Python, r#type: &str, name: &str, identifier: &str) -> Result<NodeSocket> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, socket: &NodeSocket) }
+    bind_python! { self.clear() => pub fn clear(&self, py: Python) }
+}
+
+impl From<pyo3::PyObject> for NodeIO {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for NodeIO {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for NodeIO {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
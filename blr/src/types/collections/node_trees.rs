@@ -15,6 +15,7 @@ impl<'py> CollectionImpl<'py> for NodeTrees {
 
 impl NodeTrees {
     bind_python! { self.new() => pub fn new(&self, py: Python, r#type: &str) -> Result<NodeTree> }
+    bind_python! { self.new() => pub fn new_named(&self, py: Python, name: &str, r#type: &str) -> Result<NodeTree> }
     bind_python! { self.remove() => pub fn remove(&self, py: Python, tree: &NodeTree, do_unlink: bool, do_id_user: bool, do_ui_user: bool) }
     bind_python! { self.tag() => pub fn tag(&self, py: Python, value: bool) }
 }
@@ -0,0 +1,63 @@
+use super::CollectionImpl;
+use crate::{enums::ConstraintType, result::Result, types::Constraint};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct ObjectConstraints(PyObject);
+
+impl<'py> CollectionImpl<'py> for ObjectConstraints {
+    type Item = Constraint;
+}
+
+impl ObjectConstraints {
+    pub fn get_of_type(&self, py: Python, r#type: ConstraintType) -> Result<Vec<Constraint>> {
+        Ok(self
+            .values(py)?
+            .into_iter()
+            .filter(|constraint| Into::<ConstraintType>::into(constraint) == r#type)
+            .collect())
+    }
+
+    pub fn find_of_type(&self, py: Python, r#type: ConstraintType) -> Result<Vec<usize>> {
+        Ok(self
+            .values(py)?
+            .into_iter()
+            .enumerate()
+            .filter_map(
+                |(index, constraint)| match Into::<ConstraintType>::into(constraint) {
+                    constraint_type if constraint_type == r#type => Some(index),
+                    _ => None,
+                },
+            )
+            .collect())
+    }
+
+    bind_python! { self.active => pub fn active(&self, py: Python) -> Result<Option<Constraint>> }
+    bind_python! { self.active = pub fn set_active(&mut self, py: Python, value: &Constraint) }
+    bind_python! { self.new() => pub fn new(&self, py: Python, r#type: ConstraintType) -> Result<Constraint> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, constraint: Constraint) }
+    bind_python! { self.clear() => pub fn clear(&self, py: Python) }
+    bind_python! { self.move() => pub fn r#move(&self, py: Python, from_index: usize, to_index: usize) }
+    bind_python! { self.copy() => pub fn copy(&self, py: Python, constraint: &Constraint) -> Result<Constraint> }
+}
+
+impl From<pyo3::PyObject> for ObjectConstraints {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for ObjectConstraints {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ObjectConstraints {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
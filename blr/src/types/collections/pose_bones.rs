@@ -0,0 +1,31 @@
+use super::CollectionImpl;
+use crate::types::PoseBone;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Pose.html#bpy.types.Pose.bones>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct PoseBones(PyObject);
+
+impl<'py> CollectionImpl<'py> for PoseBones {
+    type Item = PoseBone;
+}
+
+impl From<pyo3::PyObject> for PoseBones {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for PoseBones {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for PoseBones {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
@@ -0,0 +1,37 @@
+use super::CollectionImpl;
+use crate::types::SceneCollection;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.BlendDataCollections.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct SceneCollections(PyObject);
+
+impl<'py> CollectionImpl<'py> for SceneCollections {
+    type Item = SceneCollection;
+}
+
+impl SceneCollections {
+    bind_python! { self.new() => pub fn new(&self, py: Python, name: &str) -> Result<SceneCollection> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, collection: &SceneCollection, do_unlink: bool, do_id_user: bool, do_ui_user: bool) }
+}
+
+impl From<pyo3::PyObject> for SceneCollections {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for SceneCollections {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for SceneCollections {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
@@ -0,0 +1,41 @@
+use super::CollectionImpl;
+use crate::types::VertexGroup;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.VertexGroups.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct VertexGroups(PyObject);
+
+impl<'py> CollectionImpl<'py> for VertexGroups {
+    type Item = VertexGroup;
+}
+
+impl VertexGroups {
+    bind_python! { self.active => pub fn active(&self, py: Python) -> Result<Option<VertexGroup>> }
+    bind_python! { self.active_index => pub fn active_index(&self, py: Python) -> Result<usize> }
+    bind_python! { self.active_index = pub fn set_active_index(&mut self, py: Python, value: usize) }
+    bind_python! { self.new() => pub fn new(&self, py: Python, name: &str) -> Result<VertexGroup> }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, group: &VertexGroup) }
+    bind_python! { self.clear() => pub fn clear(&self, py: Python) }
+}
+
+impl From<pyo3::PyObject> for VertexGroups {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for VertexGroups {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for VertexGroups {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
@@ -0,0 +1,68 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::{BpyStruct, Object};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.ChildOfConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct ChildOfConstraint(PyObject);
+
+impl BpyStruct for ChildOfConstraint {}
+impl ConstraintImpl for ChildOfConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::ChildOf;
+}
+
+impl ChildOfConstraint {
+    bind_python! { self.inverse_matrix => pub fn inverse_matrix(&self, py: Python) -> Result<[[f32; 4]; 4]> }
+    bind_python! { self.inverse_matrix = pub fn set_inverse_matrix(&mut self, py: Python, value: [[f32; 4]; 4]) }
+    bind_python! { self.set_inverse_pending => pub fn set_inverse_pending(&self, py: Python) -> Result<bool> }
+    bind_python! { self.set_inverse_pending = pub fn set_set_inverse_pending(&mut self, py: Python, value: bool) }
+    bind_python! { self.subtarget => pub fn subtarget(&self, py: Python) -> Result<String> }
+    bind_python! { self.subtarget = pub fn set_subtarget(&mut self, py: Python, value: &str) }
+    bind_python! { self.target => pub fn target(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.target = pub fn set_target(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.use_location_x => pub fn use_location_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_location_x = pub fn set_use_location_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_location_y => pub fn use_location_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_location_y = pub fn set_use_location_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_location_z => pub fn use_location_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_location_z = pub fn set_use_location_z(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_rotation_x => pub fn use_rotation_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_rotation_x = pub fn set_use_rotation_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_rotation_y => pub fn use_rotation_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_rotation_y = pub fn set_use_rotation_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_rotation_z => pub fn use_rotation_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_rotation_z = pub fn set_use_rotation_z(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_scale_x => pub fn use_scale_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_scale_x = pub fn set_use_scale_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_scale_y => pub fn use_scale_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_scale_y = pub fn set_use_scale_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_scale_z => pub fn use_scale_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_scale_z = pub fn set_use_scale_z(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for ChildOfConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for ChildOfConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ChildOfConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for ChildOfConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,43 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::BpyStruct;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.ClampToConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct ClampToConstraint(PyObject);
+
+impl BpyStruct for ClampToConstraint {}
+impl ConstraintImpl for ClampToConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::ClampTo;
+}
+
+impl ClampToConstraint {
+    bind_python! {}
+}
+
+impl From<pyo3::PyObject> for ClampToConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for ClampToConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ClampToConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for ClampToConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,436 @@
+use super::{
+    ActionConstraint, ArmatureConstraint, CameraSolverConstraint, ChildOfConstraint,
+    ClampToConstraint, ConstraintImpl, CopyLocationConstraint, CopyRotationConstraint,
+    CopyScaleConstraint, CopyTransformsConstraint, DampedTrackConstraint, FloorConstraint,
+    FollowPathConstraint, FollowTrackConstraint, KinematicConstraint, LimitDistanceConstraint,
+    LimitLocationConstraint, LimitRotationConstraint, LimitScaleConstraint, LockedTrackConstraint,
+    MaintainVolumeConstraint, ObjectSolverConstraint, PivotConstraint, ShrinkwrapConstraint,
+    SplineIkConstraint, StretchToConstraint, TrackToConstraint, TransformCacheConstraint,
+    TransformConstraint,
+};
+use crate::{
+    bpy,
+    enums::ConstraintType,
+    error::BlError,
+    macros::enum_wrap_inner_fn,
+    result::Result,
+    types::{Object, TempOverride},
+};
+use derive_more::Display;
+use pyo3::{intern, types::PyDict, PyResult, Python, ToPyObject};
+
+/// Wrapper around all supported object constraints
+#[derive(Clone, Debug, Display)]
+pub enum Constraint {
+    Action(ActionConstraint),
+    Armature(ArmatureConstraint),
+    CameraSolver(CameraSolverConstraint),
+    ChildOf(ChildOfConstraint),
+    ClampTo(ClampToConstraint),
+    CopyLocation(CopyLocationConstraint),
+    CopyRotation(CopyRotationConstraint),
+    CopyScale(CopyScaleConstraint),
+    CopyTransforms(CopyTransformsConstraint),
+    DampedTrack(DampedTrackConstraint),
+    Floor(FloorConstraint),
+    FollowPath(FollowPathConstraint),
+    FollowTrack(FollowTrackConstraint),
+    Ik(KinematicConstraint),
+    LimitDistance(LimitDistanceConstraint),
+    LimitLocation(LimitLocationConstraint),
+    LimitRotation(LimitRotationConstraint),
+    LimitScale(LimitScaleConstraint),
+    LockedTrack(LockedTrackConstraint),
+    MaintainVolume(MaintainVolumeConstraint),
+    ObjectSolver(ObjectSolverConstraint),
+    Pivot(PivotConstraint),
+    Shrinkwrap(ShrinkwrapConstraint),
+    SplineIk(SplineIkConstraint),
+    StretchTo(StretchToConstraint),
+    TrackTo(TrackToConstraint),
+    Transform(TransformConstraint),
+    TransformCache(TransformCacheConstraint),
+}
+
+impl Constraint {
+    pub fn new(py: Python, object: &Object, r#type: ConstraintType) -> Result<Self> {
+        Ok(object.constraints(py)?.new(py, r#type)?)
+    }
+
+    pub fn remove(self, py: Python, object: &Object) -> Result<()> {
+        Ok(object.constraints(py)?.remove(py, self)?)
+    }
+
+    /// Applies the constraint to the transform of `object` (i.e., bakes its current effect into
+    /// the location, rotation and scale) and removes it afterwards.
+    pub fn apply(self, py: Python, object: &Object) -> Result<()> {
+        let name = self.name(py)?;
+        let overrides = PyDict::new(py);
+        overrides.set_item(intern!(py, "object"), object.to_object(py))?;
+        overrides.set_item(intern!(py, "active_object"), object.to_object(py))?;
+        let _temp_override = TempOverride::new(py, overrides)?;
+        Ok(bpy::ops::constraint::apply(py, &name, "OBJECT", false)?)
+    }
+
+    pub fn constraint_type(&self, py: Python) -> Result<ConstraintType> {
+        Ok(self
+            .to_object(py)
+            .getattr(py, intern!(py, "type"))?
+            .extract(py)?)
+    }
+
+    enum_wrap_inner_fn! {
+        { pub fn enabled(&self, py: Python) -> PyResult<bool> }
+        for [
+            Self::Action, Self::Armature, Self::CameraSolver, Self::ChildOf, Self::ClampTo,
+            Self::CopyLocation, Self::CopyRotation, Self::CopyScale, Self::CopyTransforms, Self::DampedTrack,
+            Self::Floor, Self::FollowPath, Self::FollowTrack, Self::Ik, Self::LimitDistance,
+            Self::LimitLocation, Self::LimitRotation, Self::LimitScale, Self::LockedTrack, Self::MaintainVolume,
+            Self::ObjectSolver, Self::Pivot, Self::Shrinkwrap, Self::SplineIk, Self::StretchTo,
+            Self::TrackTo, Self::Transform, Self::TransformCache,
+        ]
+    }
+
+    enum_wrap_inner_fn! {
+        { pub fn set_enabled(&mut self, py: Python, value: bool) -> PyResult<()> }
+        for [
+            Self::Action, Self::Armature, Self::CameraSolver, Self::ChildOf, Self::ClampTo,
+            Self::CopyLocation, Self::CopyRotation, Self::CopyScale, Self::CopyTransforms, Self::DampedTrack,
+            Self::Floor, Self::FollowPath, Self::FollowTrack, Self::Ik, Self::LimitDistance,
+            Self::LimitLocation, Self::LimitRotation, Self::LimitScale, Self::LockedTrack, Self::MaintainVolume,
+            Self::ObjectSolver, Self::Pivot, Self::Shrinkwrap, Self::SplineIk, Self::StretchTo,
+            Self::TrackTo, Self::Transform, Self::TransformCache,
+        ]
+    }
+
+    enum_wrap_inner_fn! {
+        { pub fn influence(&self, py: Python) -> PyResult<f32> }
+        for [
+            Self::Action, Self::Armature, Self::CameraSolver, Self::ChildOf, Self::ClampTo,
+            Self::CopyLocation, Self::CopyRotation, Self::CopyScale, Self::CopyTransforms, Self::DampedTrack,
+            Self::Floor, Self::FollowPath, Self::FollowTrack, Self::Ik, Self::LimitDistance,
+            Self::LimitLocation, Self::LimitRotation, Self::LimitScale, Self::LockedTrack, Self::MaintainVolume,
+            Self::ObjectSolver, Self::Pivot, Self::Shrinkwrap, Self::SplineIk, Self::StretchTo,
+            Self::TrackTo, Self::Transform, Self::TransformCache,
+        ]
+    }
+
+    enum_wrap_inner_fn! {
+        { pub fn set_influence(&mut self, py: Python, value: f32) -> PyResult<()> }
+        for [
+            Self::Action, Self::Armature, Self::CameraSolver, Self::ChildOf, Self::ClampTo,
+            Self::CopyLocation, Self::CopyRotation, Self::CopyScale, Self::CopyTransforms, Self::DampedTrack,
+            Self::Floor, Self::FollowPath, Self::FollowTrack, Self::Ik, Self::LimitDistance,
+            Self::LimitLocation, Self::LimitRotation, Self::LimitScale, Self::LockedTrack, Self::MaintainVolume,
+            Self::ObjectSolver, Self::Pivot, Self::Shrinkwrap, Self::SplineIk, Self::StretchTo,
+            Self::TrackTo, Self::Transform, Self::TransformCache,
+        ]
+    }
+
+    enum_wrap_inner_fn! {
+        { pub fn is_valid(&self, py: Python) -> PyResult<bool> }
+        for [
+            Self::Action, Self::Armature, Self::CameraSolver, Self::ChildOf, Self::ClampTo,
+            Self::CopyLocation, Self::CopyRotation, Self::CopyScale, Self::CopyTransforms, Self::DampedTrack,
+            Self::Floor, Self::FollowPath, Self::FollowTrack, Self::Ik, Self::LimitDistance,
+            Self::LimitLocation, Self::LimitRotation, Self::LimitScale, Self::LockedTrack, Self::MaintainVolume,
+            Self::ObjectSolver, Self::Pivot, Self::Shrinkwrap, Self::SplineIk, Self::StretchTo,
+            Self::TrackTo, Self::Transform, Self::TransformCache,
+        ]
+    }
+
+    enum_wrap_inner_fn! {
+        { pub fn name(&self, py: Python) -> PyResult<String> }
+        for [
+            Self::Action, Self::Armature, Self::CameraSolver, Self::ChildOf, Self::ClampTo,
+            Self::CopyLocation, Self::CopyRotation, Self::CopyScale, Self::CopyTransforms, Self::DampedTrack,
+            Self::Floor, Self::FollowPath, Self::FollowTrack, Self::Ik, Self::LimitDistance,
+            Self::LimitLocation, Self::LimitRotation, Self::LimitScale, Self::LockedTrack, Self::MaintainVolume,
+            Self::ObjectSolver, Self::Pivot, Self::Shrinkwrap, Self::SplineIk, Self::StretchTo,
+            Self::TrackTo, Self::Transform, Self::TransformCache,
+        ]
+    }
+
+    enum_wrap_inner_fn! {
+        { pub fn set_name(&mut self, py: Python, value: &str) -> PyResult<()> }
+        for [
+            Self::Action, Self::Armature, Self::CameraSolver, Self::ChildOf, Self::ClampTo,
+            Self::CopyLocation, Self::CopyRotation, Self::CopyScale, Self::CopyTransforms, Self::DampedTrack,
+            Self::Floor, Self::FollowPath, Self::FollowTrack, Self::Ik, Self::LimitDistance,
+            Self::LimitLocation, Self::LimitRotation, Self::LimitScale, Self::LockedTrack, Self::MaintainVolume,
+            Self::ObjectSolver, Self::Pivot, Self::Shrinkwrap, Self::SplineIk, Self::StretchTo,
+            Self::TrackTo, Self::Transform, Self::TransformCache,
+        ]
+    }
+}
+
+impl pyo3::ToPyObject for Constraint {
+    enum_wrap_inner_fn! {
+        { fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject  }
+        for [
+            Self::Action, Self::Armature, Self::CameraSolver, Self::ChildOf, Self::ClampTo,
+            Self::CopyLocation, Self::CopyRotation, Self::CopyScale, Self::CopyTransforms, Self::DampedTrack,
+            Self::Floor, Self::FollowPath, Self::FollowTrack, Self::Ik, Self::LimitDistance,
+            Self::LimitLocation, Self::LimitRotation, Self::LimitScale, Self::LockedTrack, Self::MaintainVolume,
+            Self::ObjectSolver, Self::Pivot, Self::Shrinkwrap, Self::SplineIk, Self::StretchTo,
+            Self::TrackTo, Self::Transform, Self::TransformCache,
+        ]
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Constraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        let constraint_type: ConstraintType =
+            value.getattr(intern!(value.py(), "type"))?.extract()?;
+        Ok(match constraint_type {
+            ConstraintType::Action => Self::Action(value.into()),
+            ConstraintType::Armature => Self::Armature(value.into()),
+            ConstraintType::CameraSolver => Self::CameraSolver(value.into()),
+            ConstraintType::ChildOf => Self::ChildOf(value.into()),
+            ConstraintType::ClampTo => Self::ClampTo(value.into()),
+            ConstraintType::CopyLocation => Self::CopyLocation(value.into()),
+            ConstraintType::CopyRotation => Self::CopyRotation(value.into()),
+            ConstraintType::CopyScale => Self::CopyScale(value.into()),
+            ConstraintType::CopyTransforms => Self::CopyTransforms(value.into()),
+            ConstraintType::DampedTrack => Self::DampedTrack(value.into()),
+            ConstraintType::Floor => Self::Floor(value.into()),
+            ConstraintType::FollowPath => Self::FollowPath(value.into()),
+            ConstraintType::FollowTrack => Self::FollowTrack(value.into()),
+            ConstraintType::Ik => Self::Ik(value.into()),
+            ConstraintType::LimitDistance => Self::LimitDistance(value.into()),
+            ConstraintType::LimitLocation => Self::LimitLocation(value.into()),
+            ConstraintType::LimitRotation => Self::LimitRotation(value.into()),
+            ConstraintType::LimitScale => Self::LimitScale(value.into()),
+            ConstraintType::LockedTrack => Self::LockedTrack(value.into()),
+            ConstraintType::MaintainVolume => Self::MaintainVolume(value.into()),
+            ConstraintType::ObjectSolver => Self::ObjectSolver(value.into()),
+            ConstraintType::Pivot => Self::Pivot(value.into()),
+            ConstraintType::Shrinkwrap => Self::Shrinkwrap(value.into()),
+            ConstraintType::SplineIk => Self::SplineIk(value.into()),
+            ConstraintType::StretchTo => Self::StretchTo(value.into()),
+            ConstraintType::TrackTo => Self::TrackTo(value.into()),
+            ConstraintType::Transform => Self::Transform(value.into()),
+            ConstraintType::TransformCache => Self::TransformCache(value.into()),
+        })
+    }
+}
+
+impl From<Constraint> for ConstraintType {
+    fn from(value: Constraint) -> Self {
+        match value {
+            Constraint::Action(_) => Self::Action,
+            Constraint::Armature(_) => Self::Armature,
+            Constraint::CameraSolver(_) => Self::CameraSolver,
+            Constraint::ChildOf(_) => Self::ChildOf,
+            Constraint::ClampTo(_) => Self::ClampTo,
+            Constraint::CopyLocation(_) => Self::CopyLocation,
+            Constraint::CopyRotation(_) => Self::CopyRotation,
+            Constraint::CopyScale(_) => Self::CopyScale,
+            Constraint::CopyTransforms(_) => Self::CopyTransforms,
+            Constraint::DampedTrack(_) => Self::DampedTrack,
+            Constraint::Floor(_) => Self::Floor,
+            Constraint::FollowPath(_) => Self::FollowPath,
+            Constraint::FollowTrack(_) => Self::FollowTrack,
+            Constraint::Ik(_) => Self::Ik,
+            Constraint::LimitDistance(_) => Self::LimitDistance,
+            Constraint::LimitLocation(_) => Self::LimitLocation,
+            Constraint::LimitRotation(_) => Self::LimitRotation,
+            Constraint::LimitScale(_) => Self::LimitScale,
+            Constraint::LockedTrack(_) => Self::LockedTrack,
+            Constraint::MaintainVolume(_) => Self::MaintainVolume,
+            Constraint::ObjectSolver(_) => Self::ObjectSolver,
+            Constraint::Pivot(_) => Self::Pivot,
+            Constraint::Shrinkwrap(_) => Self::Shrinkwrap,
+            Constraint::SplineIk(_) => Self::SplineIk,
+            Constraint::StretchTo(_) => Self::StretchTo,
+            Constraint::TrackTo(_) => Self::TrackTo,
+            Constraint::Transform(_) => Self::Transform,
+            Constraint::TransformCache(_) => Self::TransformCache,
+        }
+    }
+}
+
+impl From<&Constraint> for ConstraintType {
+    fn from(value: &Constraint) -> Self {
+        match value {
+            Constraint::Action(_) => Self::Action,
+            Constraint::Armature(_) => Self::Armature,
+            Constraint::CameraSolver(_) => Self::CameraSolver,
+            Constraint::ChildOf(_) => Self::ChildOf,
+            Constraint::ClampTo(_) => Self::ClampTo,
+            Constraint::CopyLocation(_) => Self::CopyLocation,
+            Constraint::CopyRotation(_) => Self::CopyRotation,
+            Constraint::CopyScale(_) => Self::CopyScale,
+            Constraint::CopyTransforms(_) => Self::CopyTransforms,
+            Constraint::DampedTrack(_) => Self::DampedTrack,
+            Constraint::Floor(_) => Self::Floor,
+            Constraint::FollowPath(_) => Self::FollowPath,
+            Constraint::FollowTrack(_) => Self::FollowTrack,
+            Constraint::Ik(_) => Self::Ik,
+            Constraint::LimitDistance(_) => Self::LimitDistance,
+            Constraint::LimitLocation(_) => Self::LimitLocation,
+            Constraint::LimitRotation(_) => Self::LimitRotation,
+            Constraint::LimitScale(_) => Self::LimitScale,
+            Constraint::LockedTrack(_) => Self::LockedTrack,
+            Constraint::MaintainVolume(_) => Self::MaintainVolume,
+            Constraint::ObjectSolver(_) => Self::ObjectSolver,
+            Constraint::Pivot(_) => Self::Pivot,
+            Constraint::Shrinkwrap(_) => Self::Shrinkwrap,
+            Constraint::SplineIk(_) => Self::SplineIk,
+            Constraint::StretchTo(_) => Self::StretchTo,
+            Constraint::TrackTo(_) => Self::TrackTo,
+            Constraint::Transform(_) => Self::Transform,
+            Constraint::TransformCache(_) => Self::TransformCache,
+        }
+    }
+}
+
+macro_rules! impl_constraint_conversions_for {
+    ($constraint_type:path, $constraint_variant:path, $constraint_struct:ty) => {
+        impl From<$constraint_struct> for Constraint {
+            fn from(value: $constraint_struct) -> Self {
+                $constraint_variant(value)
+            }
+        }
+
+        impl TryFrom<Constraint> for $constraint_struct {
+            type Error = BlError;
+
+            fn try_from(value: Constraint) -> Result<Self> {
+                match value {
+                    $constraint_variant(value) => Ok(value),
+                    incorrect_type => Err(BlError::TypeError(format!(
+                        "Blender constraint {name} is of type {incorrect_type}, but {correct_type} is expected",
+                        name = Python::with_gil(|py| incorrect_type.name(py))?,
+                        incorrect_type = Into::<ConstraintType>::into(&incorrect_type),
+                        correct_type = $constraint_type,
+                    ))),
+                }
+            }
+        }
+    };
+}
+impl_constraint_conversions_for!(ConstraintType::Action, Constraint::Action, ActionConstraint);
+impl_constraint_conversions_for!(
+    ConstraintType::Armature,
+    Constraint::Armature,
+    ArmatureConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::CameraSolver,
+    Constraint::CameraSolver,
+    CameraSolverConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::ChildOf,
+    Constraint::ChildOf,
+    ChildOfConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::ClampTo,
+    Constraint::ClampTo,
+    ClampToConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::CopyLocation,
+    Constraint::CopyLocation,
+    CopyLocationConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::CopyRotation,
+    Constraint::CopyRotation,
+    CopyRotationConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::CopyScale,
+    Constraint::CopyScale,
+    CopyScaleConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::CopyTransforms,
+    Constraint::CopyTransforms,
+    CopyTransformsConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::DampedTrack,
+    Constraint::DampedTrack,
+    DampedTrackConstraint
+);
+impl_constraint_conversions_for!(ConstraintType::Floor, Constraint::Floor, FloorConstraint);
+impl_constraint_conversions_for!(
+    ConstraintType::FollowPath,
+    Constraint::FollowPath,
+    FollowPathConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::FollowTrack,
+    Constraint::FollowTrack,
+    FollowTrackConstraint
+);
+impl_constraint_conversions_for!(ConstraintType::Ik, Constraint::Ik, KinematicConstraint);
+impl_constraint_conversions_for!(
+    ConstraintType::LimitDistance,
+    Constraint::LimitDistance,
+    LimitDistanceConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::LimitLocation,
+    Constraint::LimitLocation,
+    LimitLocationConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::LimitRotation,
+    Constraint::LimitRotation,
+    LimitRotationConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::LimitScale,
+    Constraint::LimitScale,
+    LimitScaleConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::LockedTrack,
+    Constraint::LockedTrack,
+    LockedTrackConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::MaintainVolume,
+    Constraint::MaintainVolume,
+    MaintainVolumeConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::ObjectSolver,
+    Constraint::ObjectSolver,
+    ObjectSolverConstraint
+);
+impl_constraint_conversions_for!(ConstraintType::Pivot, Constraint::Pivot, PivotConstraint);
+impl_constraint_conversions_for!(
+    ConstraintType::Shrinkwrap,
+    Constraint::Shrinkwrap,
+    ShrinkwrapConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::SplineIk,
+    Constraint::SplineIk,
+    SplineIkConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::StretchTo,
+    Constraint::StretchTo,
+    StretchToConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::TrackTo,
+    Constraint::TrackTo,
+    TrackToConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::Transform,
+    Constraint::Transform,
+    TransformConstraint
+);
+impl_constraint_conversions_for!(
+    ConstraintType::TransformCache,
+    Constraint::TransformCache,
+    TransformCacheConstraint
+);
@@ -0,0 +1,64 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::{BpyStruct, Object};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.CopyLocationConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct CopyLocationConstraint(PyObject);
+
+impl BpyStruct for CopyLocationConstraint {}
+impl ConstraintImpl for CopyLocationConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::CopyLocation;
+}
+
+impl CopyLocationConstraint {
+    bind_python! { self.head_tail => pub fn head_tail(&self, py: Python) -> Result<f32> }
+    bind_python! { self.head_tail = pub fn set_head_tail(&mut self, py: Python, value: f32) }
+    bind_python! { self.invert_x => pub fn invert_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.invert_x = pub fn set_invert_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.invert_y => pub fn invert_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.invert_y = pub fn set_invert_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.invert_z => pub fn invert_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.invert_z = pub fn set_invert_z(&mut self, py: Python, value: bool) }
+    bind_python! { self.subtarget => pub fn subtarget(&self, py: Python) -> Result<String> }
+    bind_python! { self.subtarget = pub fn set_subtarget(&mut self, py: Python, value: &str) }
+    bind_python! { self.target => pub fn target(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.target = pub fn set_target(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.use_bbone_shape => pub fn use_bbone_shape(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_bbone_shape = pub fn set_use_bbone_shape(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_offset => pub fn use_offset(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_offset = pub fn set_use_offset(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_x => pub fn use_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_x = pub fn set_use_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_y => pub fn use_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_y = pub fn set_use_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_z => pub fn use_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_z = pub fn set_use_z(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for CopyLocationConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for CopyLocationConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for CopyLocationConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for CopyLocationConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
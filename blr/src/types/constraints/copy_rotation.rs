@@ -0,0 +1,62 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::{BpyStruct, Object};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.CopyRotationConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct CopyRotationConstraint(PyObject);
+
+impl BpyStruct for CopyRotationConstraint {}
+impl ConstraintImpl for CopyRotationConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::CopyRotation;
+}
+
+impl CopyRotationConstraint {
+    bind_python! { self.euler_order => pub fn euler_order(&self, py: Python) -> Result<String> }
+    bind_python! { self.euler_order = pub fn set_euler_order(&mut self, py: Python, value: &str) }
+    bind_python! { self.invert_x => pub fn invert_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.invert_x = pub fn set_invert_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.invert_y => pub fn invert_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.invert_y = pub fn set_invert_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.invert_z => pub fn invert_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.invert_z = pub fn set_invert_z(&mut self, py: Python, value: bool) }
+    bind_python! { self.mix_mode => pub fn mix_mode(&self, py: Python) -> Result<String> }
+    bind_python! { self.mix_mode = pub fn set_mix_mode(&mut self, py: Python, value: &str) }
+    bind_python! { self.subtarget => pub fn subtarget(&self, py: Python) -> Result<String> }
+    bind_python! { self.subtarget = pub fn set_subtarget(&mut self, py: Python, value: &str) }
+    bind_python! { self.target => pub fn target(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.target = pub fn set_target(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.use_x => pub fn use_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_x = pub fn set_use_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_y => pub fn use_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_y = pub fn set_use_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_z => pub fn use_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_z = pub fn set_use_z(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for CopyRotationConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for CopyRotationConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for CopyRotationConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for CopyRotationConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
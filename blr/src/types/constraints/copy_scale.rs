@@ -0,0 +1,43 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::BpyStruct;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.CopyScaleConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct CopyScaleConstraint(PyObject);
+
+impl BpyStruct for CopyScaleConstraint {}
+impl ConstraintImpl for CopyScaleConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::CopyScale;
+}
+
+impl CopyScaleConstraint {
+    bind_python! {}
+}
+
+impl From<pyo3::PyObject> for CopyScaleConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for CopyScaleConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for CopyScaleConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for CopyScaleConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
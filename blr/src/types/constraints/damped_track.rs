@@ -0,0 +1,55 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::{
+    enums::TrackAxis,
+    types::{BpyStruct, Object},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.DampedTrackConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct DampedTrackConstraint(PyObject);
+
+impl BpyStruct for DampedTrackConstraint {}
+impl ConstraintImpl for DampedTrackConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::DampedTrack;
+}
+
+impl DampedTrackConstraint {
+    bind_python! { self.head_tail => pub fn head_tail(&self, py: Python) -> Result<f32> }
+    bind_python! { self.head_tail = pub fn set_head_tail(&mut self, py: Python, value: f32) }
+    bind_python! { self.subtarget => pub fn subtarget(&self, py: Python) -> Result<String> }
+    bind_python! { self.subtarget = pub fn set_subtarget(&mut self, py: Python, value: &str) }
+    bind_python! { self.target => pub fn target(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.target = pub fn set_target(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.track_axis => pub fn track_axis(&self, py: Python) -> Result<TrackAxis> }
+    bind_python! { self.track_axis = pub fn set_track_axis(&mut self, py: Python, value: TrackAxis) }
+    bind_python! { self.use_bbone_shape => pub fn use_bbone_shape(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_bbone_shape = pub fn set_use_bbone_shape(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for DampedTrackConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for DampedTrackConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for DampedTrackConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for DampedTrackConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,52 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::{BpyStruct, Object};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.FloorConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct FloorConstraint(PyObject);
+
+impl BpyStruct for FloorConstraint {}
+impl ConstraintImpl for FloorConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::Floor;
+}
+
+impl FloorConstraint {
+    bind_python! { self.floor_location => pub fn floor_location(&self, py: Python) -> Result<String> }
+    bind_python! { self.floor_location = pub fn set_floor_location(&mut self, py: Python, value: &str) }
+    bind_python! { self.offset => pub fn offset(&self, py: Python) -> Result<f32> }
+    bind_python! { self.offset = pub fn set_offset(&mut self, py: Python, value: f32) }
+    bind_python! { self.subtarget => pub fn subtarget(&self, py: Python) -> Result<String> }
+    bind_python! { self.subtarget = pub fn set_subtarget(&mut self, py: Python, value: &str) }
+    bind_python! { self.target => pub fn target(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.target = pub fn set_target(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.use_rotation => pub fn use_rotation(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_rotation = pub fn set_use_rotation(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for FloorConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for FloorConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for FloorConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for FloorConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
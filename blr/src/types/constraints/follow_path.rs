@@ -0,0 +1,58 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::{BpyStruct, Object};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.FollowPathConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct FollowPathConstraint(PyObject);
+
+impl BpyStruct for FollowPathConstraint {}
+impl ConstraintImpl for FollowPathConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::FollowPath;
+}
+
+impl FollowPathConstraint {
+    bind_python! { self.forward_axis => pub fn forward_axis(&self, py: Python) -> Result<String> }
+    bind_python! { self.forward_axis = pub fn set_forward_axis(&mut self, py: Python, value: &str) }
+    bind_python! { self.offset => pub fn offset(&self, py: Python) -> Result<f32> }
+    bind_python! { self.offset = pub fn set_offset(&mut self, py: Python, value: f32) }
+    bind_python! { self.offset_factor => pub fn offset_factor(&self, py: Python) -> Result<f32> }
+    bind_python! { self.offset_factor = pub fn set_offset_factor(&mut self, py: Python, value: f32) }
+    bind_python! { self.target => pub fn target(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.target = pub fn set_target(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.up_axis => pub fn up_axis(&self, py: Python) -> Result<String> }
+    bind_python! { self.up_axis = pub fn set_up_axis(&mut self, py: Python, value: &str) }
+    bind_python! { self.use_curve_follow => pub fn use_curve_follow(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_curve_follow = pub fn set_use_curve_follow(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_curve_radius => pub fn use_curve_radius(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_curve_radius = pub fn set_use_curve_radius(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_fixed_location => pub fn use_fixed_location(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_fixed_location = pub fn set_use_fixed_location(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for FollowPathConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for FollowPathConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for FollowPathConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for FollowPathConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
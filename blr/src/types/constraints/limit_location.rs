@@ -0,0 +1,68 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::BpyStruct;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.LimitLocationConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct LimitLocationConstraint(PyObject);
+
+impl BpyStruct for LimitLocationConstraint {}
+impl ConstraintImpl for LimitLocationConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::LimitLocation;
+}
+
+impl LimitLocationConstraint {
+    bind_python! { self.max_x => pub fn max_x(&self, py: Python) -> Result<f32> }
+    bind_python! { self.max_x = pub fn set_max_x(&mut self, py: Python, value: f32) }
+    bind_python! { self.max_y => pub fn max_y(&self, py: Python) -> Result<f32> }
+    bind_python! { self.max_y = pub fn set_max_y(&mut self, py: Python, value: f32) }
+    bind_python! { self.max_z => pub fn max_z(&self, py: Python) -> Result<f32> }
+    bind_python! { self.max_z = pub fn set_max_z(&mut self, py: Python, value: f32) }
+    bind_python! { self.min_x => pub fn min_x(&self, py: Python) -> Result<f32> }
+    bind_python! { self.min_x = pub fn set_min_x(&mut self, py: Python, value: f32) }
+    bind_python! { self.min_y => pub fn min_y(&self, py: Python) -> Result<f32> }
+    bind_python! { self.min_y = pub fn set_min_y(&mut self, py: Python, value: f32) }
+    bind_python! { self.min_z => pub fn min_z(&self, py: Python) -> Result<f32> }
+    bind_python! { self.min_z = pub fn set_min_z(&mut self, py: Python, value: f32) }
+    bind_python! { self.use_max_x => pub fn use_max_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_max_x = pub fn set_use_max_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_max_y => pub fn use_max_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_max_y = pub fn set_use_max_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_max_z => pub fn use_max_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_max_z = pub fn set_use_max_z(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_min_x => pub fn use_min_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_min_x = pub fn set_use_min_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_min_y => pub fn use_min_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_min_y = pub fn set_use_min_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_min_z => pub fn use_min_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_min_z = pub fn set_use_min_z(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_transform_limit => pub fn use_transform_limit(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_transform_limit = pub fn set_use_transform_limit(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for LimitLocationConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for LimitLocationConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for LimitLocationConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for LimitLocationConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
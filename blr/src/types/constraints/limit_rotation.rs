@@ -0,0 +1,66 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::BpyStruct;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.LimitRotationConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct LimitRotationConstraint(PyObject);
+
+impl BpyStruct for LimitRotationConstraint {}
+impl ConstraintImpl for LimitRotationConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::LimitRotation;
+}
+
+impl LimitRotationConstraint {
+    bind_python! { self.euler_order => pub fn euler_order(&self, py: Python) -> Result<String> }
+    bind_python! { self.euler_order = pub fn set_euler_order(&mut self, py: Python, value: &str) }
+    bind_python! { self.max_x => pub fn max_x(&self, py: Python) -> Result<f32> }
+    bind_python! { self.max_x = pub fn set_max_x(&mut self, py: Python, value: f32) }
+    bind_python! { self.max_y => pub fn max_y(&self, py: Python) -> Result<f32> }
+    bind_python! { self.max_y = pub fn set_max_y(&mut self, py: Python, value: f32) }
+    bind_python! { self.max_z => pub fn max_z(&self, py: Python) -> Result<f32> }
+    bind_python! { self.max_z = pub fn set_max_z(&mut self, py: Python, value: f32) }
+    bind_python! { self.min_x => pub fn min_x(&self, py: Python) -> Result<f32> }
+    bind_python! { self.min_x = pub fn set_min_x(&mut self, py: Python, value: f32) }
+    bind_python! { self.min_y => pub fn min_y(&self, py: Python) -> Result<f32> }
+    bind_python! { self.min_y = pub fn set_min_y(&mut self, py: Python, value: f32) }
+    bind_python! { self.min_z => pub fn min_z(&self, py: Python) -> Result<f32> }
+    bind_python! { self.min_z = pub fn set_min_z(&mut self, py: Python, value: f32) }
+    bind_python! { self.use_legacy_behavior => pub fn use_legacy_behavior(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_legacy_behavior = pub fn set_use_legacy_behavior(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_limit_x => pub fn use_limit_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_limit_x = pub fn set_use_limit_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_limit_y => pub fn use_limit_y(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_limit_y = pub fn set_use_limit_y(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_limit_z => pub fn use_limit_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_limit_z = pub fn set_use_limit_z(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_transform_limit => pub fn use_transform_limit(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_transform_limit = pub fn set_use_transform_limit(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for LimitRotationConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for LimitRotationConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for LimitRotationConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for LimitRotationConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,43 @@
+use super::{ConstraintImpl, ConstraintType};
+use crate::types::BpyStruct;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.MaintainVolumeConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct MaintainVolumeConstraint(PyObject);
+
+impl BpyStruct for MaintainVolumeConstraint {}
+impl ConstraintImpl for MaintainVolumeConstraint {
+    const CONSTRAINT_TYPE: ConstraintType = ConstraintType::MaintainVolume;
+}
+
+impl MaintainVolumeConstraint {
+    bind_python! {}
+}
+
+impl From<pyo3::PyObject> for MaintainVolumeConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for MaintainVolumeConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for MaintainVolumeConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for MaintainVolumeConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,119 @@
+pub mod action;
+pub mod armature;
+pub mod camera_solver;
+pub mod child_of;
+pub mod clamp_to;
+pub mod constraint;
+pub mod copy_location;
+pub mod copy_rotation;
+pub mod copy_scale;
+pub mod copy_transforms;
+pub mod damped_track;
+pub mod floor;
+pub mod follow_path;
+pub mod follow_track;
+pub mod kinematic;
+pub mod limit_distance;
+pub mod limit_location;
+pub mod limit_rotation;
+pub mod limit_scale;
+pub mod locked_track;
+pub mod maintain_volume;
+pub mod object_solver;
+pub mod pivot;
+pub mod shrinkwrap;
+pub mod spline_ik;
+pub mod stretch_to;
+pub mod track_to;
+pub mod transform;
+pub mod transform_cache;
+
+pub use action::ActionConstraint;
+pub use armature::ArmatureConstraint;
+pub use camera_solver::CameraSolverConstraint;
+pub use child_of::ChildOfConstraint;
+pub use clamp_to::ClampToConstraint;
+pub use constraint::Constraint;
+pub use copy_location::CopyLocationConstraint;
+pub use copy_rotation::CopyRotationConstraint;
+pub use copy_scale::CopyScaleConstraint;
+pub use copy_transforms::CopyTransformsConstraint;
+pub use damped_track::DampedTrackConstraint;
+pub use floor::FloorConstraint;
+pub use follow_path::FollowPathConstraint;
+pub use follow_track::FollowTrackConstraint;
+pub use kinematic::KinematicConstraint;
+pub use limit_distance::LimitDistanceConstraint;
+pub use limit_location::LimitLocationConstraint;
+pub use limit_rotation::LimitRotationConstraint;
+pub use limit_scale::LimitScaleConstraint;
+pub use locked_track::LockedTrackConstraint;
+pub use maintain_volume::MaintainVolumeConstraint;
+pub use object_solver::ObjectSolverConstraint;
+pub use pivot::PivotConstraint;
+pub use shrinkwrap::ShrinkwrapConstraint;
+pub use spline_ik::SplineIkConstraint;
+pub use stretch_to::StretchToConstraint;
+pub use track_to::TrackToConstraint;
+pub use transform::TransformConstraint;
+pub use transform_cache::TransformCacheConstraint;
+
+use crate::{
+    enums::{ConstraintSpace, ConstraintType},
+    error::BlError,
+    result::Result,
+    types::Object,
+};
+use pyo3::{intern, PyObject, Python};
+use pyo3_macros_more::bind_python;
+use std::ops::{Deref, DerefMut};
+
+/// Wrapper around <https://docs.blender.org/api/latest/bpy.types.Constraint.html>
+pub trait ConstraintImpl:
+    Deref<Target = PyObject> + DerefMut<Target = PyObject> + pyo3::ToPyObject + Into<Constraint> + Sized
+where
+    Constraint: TryInto<Self, Error = BlError>,
+{
+    const CONSTRAINT_TYPE: ConstraintType;
+
+    fn new(py: Python, object: &Object, name: &str) -> Result<Self> {
+        let mut constraint: Self = object
+            .constraints(py)?
+            .new(py, Self::CONSTRAINT_TYPE)?
+            .try_into()?;
+        constraint.set_name(py, name)?;
+        Ok(constraint)
+    }
+
+    fn remove(self, py: Python, object: &Object) -> Result<()> {
+        Ok(object.constraints(py)?.remove(py, self.into())?)
+    }
+
+    /// See [`Constraint::apply`].
+    fn apply(self, py: Python, object: &Object) -> Result<()> {
+        self.into().apply(py, object)
+    }
+
+    fn constraint_type(&self, py: Python) -> Result<ConstraintType> {
+        Ok(self.getattr(py, intern!(py, "type"))?.extract(py)?)
+    }
+
+    bind_python! { self.active => fn active(&self, py: Python) -> Result<bool> }
+    bind_python! { self.active = fn set_active(&mut self, py: Python, value: bool) }
+    bind_python! { self.enabled => fn enabled(&self, py: Python) -> Result<bool> }
+    bind_python! { self.enabled = fn set_enabled(&mut self, py: Python, value: bool) }
+    bind_python! { self.error_location => fn error_location(&self, py: Python) -> Result<f32> }
+    bind_python! { self.error_rotation => fn error_rotation(&self, py: Python) -> Result<f32> }
+    bind_python! { self.influence => fn influence(&self, py: Python) -> Result<f32> }
+    bind_python! { self.influence = fn set_influence(&mut self, py: Python, value: f32) }
+    bind_python! { self.is_override_data => fn is_override_data(&self, py: Python) -> Result<bool> }
+    bind_python! { self.is_valid => fn is_valid(&self, py: Python) -> Result<bool> }
+    bind_python! { self.name => fn name(&self, py: Python) -> Result<String> }
+    bind_python! { self.name = fn set_name(&mut self, py: Python, value: &str) }
+    bind_python! { self.owner_space => fn owner_space(&self, py: Python) -> Result<ConstraintSpace> }
+    bind_python! { self.owner_space = fn set_owner_space(&mut self, py: Python, value: ConstraintSpace) }
+    bind_python! { self.show_expanded => fn show_expanded(&self, py: Python) -> Result<bool> }
+    bind_python! { self.show_expanded = fn set_show_expanded(&mut self, py: Python, value: bool) }
+    bind_python! { self.target_space => fn target_space(&self, py: Python) -> Result<ConstraintSpace> }
+    bind_python! { self.target_space = fn set_target_space(&mut self, py: Python, value: ConstraintSpace) }
+}
@@ -0,0 +1,57 @@
+use crate::{
+    result::Result,
+    types::{BpyID, BpyStruct, Collection, Scene, ViewLayer},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Depsgraph.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Depsgraph(PyObject);
+
+impl BpyStruct for Depsgraph {}
+
+impl Depsgraph {
+    /// Returns the evaluated version of `id`, i.e. with modifiers, constraints, drivers and
+    /// animation applied.
+    pub fn evaluated<T: BpyID + From<PyObject>>(&self, py: Python, id: &T) -> Result<T> {
+        Ok(self
+            .call_method1(py, intern!(py, "id_eval_get"), (id.to_object(py),))?
+            .into())
+    }
+
+    bind_python! { self.ids => pub fn ids(&self, py: Python) -> Result<Collection> }
+    bind_python! { self.mode => pub fn mode(&self, py: Python) -> Result<String> }
+    bind_python! { self.objects => pub fn objects(&self, py: Python) -> Result<Collection> }
+    bind_python! { self.scene => pub fn scene(&self, py: Python) -> Result<Scene> }
+    bind_python! { self.scene_eval => pub fn scene_eval(&self, py: Python) -> Result<Scene> }
+    bind_python! { self.view_layer => pub fn view_layer(&self, py: Python) -> Result<ViewLayer> }
+    bind_python! { self.view_layer_eval => pub fn view_layer_eval(&self, py: Python) -> Result<ViewLayer> }
+    bind_python! { self.update() => pub fn update(&self, py: Python) }
+}
+
+impl From<pyo3::PyObject> for Depsgraph {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Depsgraph {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Depsgraph {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Depsgraph {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,29 @@
+use crate::{enums::ObjectMode, result::Result, types::Object};
+use pyo3::Python;
+
+/// Guard that switches an object into edit mode (e.g., to access the edit bones of an armature)
+/// and back to object mode once it is dropped.
+#[derive(Debug)]
+pub struct EditModeGuard {
+    object: Object,
+}
+
+impl EditModeGuard {
+    pub fn new(py: Python, object: &Object) -> Result<Self> {
+        Object::force_object_mode(py)?;
+        object.set_mode(py, ObjectMode::Edit)?;
+        Ok(Self {
+            object: object.clone(),
+        })
+    }
+}
+
+impl Drop for EditModeGuard {
+    fn drop(&mut self) {
+        Python::with_gil(|py| {
+            if let Err(err) = self.object.set_mode(py, ObjectMode::Object) {
+                pyo3::PyErr::from(err).print(py);
+            }
+        });
+    }
+}
@@ -0,0 +1,250 @@
+use crate::{
+    bpy,
+    enums::{ColorDepth, DataBlockKind, ImageFileFormat},
+    error::BlError,
+    result::Result,
+    types::{
+        collections::foreach::{array_get, array_set},
+        BpyID, DataBlock, Scene,
+    },
+};
+use builder_derive_more::IntoBuilder;
+use derive_builder::Builder;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, types::PyDict, PyAny, PyObject, Python, ToPyObject};
+use pyo3_macros_more::bind_python;
+use std::path::Path;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Image.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Image(PyObject);
+
+impl BpyID for Image {}
+
+impl DataBlock for Image {
+    const KIND: DataBlockKind = DataBlockKind::Image;
+}
+
+/// Options of a new image, see [`Image::new`].
+#[derive(Debug, Clone, Default, Builder, IntoBuilder)]
+#[builder(default, build_fn(private, name = "fallible_build", error = "BlError"))]
+pub struct ImageOptions {
+    /// Use the alpha channel.
+    pub alpha: bool,
+    /// Store the pixels with 32-bit floating point precision instead of 8 bits.
+    pub float_buffer: bool,
+}
+
+impl ImageOptionsBuilder {
+    #[must_use]
+    pub fn build(&self) -> ImageOptions {
+        self.fallible_build().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl Image {
+    /// Loads the image at `filepath`, reusing it if it is already loaded.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the given `filepath` does not point to a valid file.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn load(py: Python, filepath: impl AsRef<Path>) -> Result<Self> {
+        bpy::data::images(py)?.load(py, filepath, true)
+    }
+
+    /// Creates a new image of `width` × `height` pixels that is filled with opaque black.
+    pub fn new(
+        py: Python,
+        name: &str,
+        width: u32,
+        height: u32,
+        options: &ImageOptions,
+    ) -> Result<Self> {
+        Ok(bpy::data::images(py)?.new(
+            py,
+            name,
+            width,
+            height,
+            options.alpha,
+            options.float_buffer,
+        )?)
+    }
+
+    /// Returns the result of the last render, which only provides its pixels to
+    /// [`Image::save_render`] (Blender does not expose them via [`Image::pixels`]).
+    pub fn render_result(py: Python) -> Result<Option<Self>> {
+        Ok(bpy::data::images(py)?
+            .as_ref(py)
+            .call_method1(intern!(py, "get"), ("Render Result",))?
+            .extract()?)
+    }
+
+    /// Returns the number of pixels in x and y.
+    pub fn size(&self, py: Python) -> Result<[u32; 2]> {
+        Ok(self.getattr(py, intern!(py, "size"))?.extract(py)?)
+    }
+
+    /// Returns all pixels as RGBA values in row-major order, starting with the bottom row.
+    pub fn pixels(&self, py: Python) -> Result<Vec<f32>> {
+        let len = self.pixels_len(py)?;
+        array_get(self.getattr(py, intern!(py, "pixels"))?.as_ref(py), len)
+    }
+
+    /// Replaces all pixels with RGBA values in row-major order, starting with the bottom row.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of values does not match the size of the image.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_pixels(&mut self, py: Python, pixels: &[f32]) -> Result<()> {
+        let len = self.pixels_len(py)?;
+        if pixels.len() != len {
+            return Err(BlError::ValueError(format!(
+                "Image '{}' requires {len} pixel values, but {} are given",
+                self.name(py)?,
+                pixels.len()
+            )));
+        }
+        array_set(self.getattr(py, intern!(py, "pixels"))?.as_ref(py), pixels)?;
+        self.update(py)?;
+        Ok(())
+    }
+
+    fn pixels_len(&self, py: Python) -> Result<usize> {
+        let [width, height] = self.size(py)?;
+        Ok(width as usize * height as usize * self.channels(py)? as usize)
+    }
+
+    /// Saves the image in `file_format` at `filepath`, which also becomes the path of the image.
+    pub fn save_as(
+        &mut self,
+        py: Python,
+        filepath: impl AsRef<Path>,
+        file_format: ImageFileFormat,
+    ) -> Result<()> {
+        self.set_filepath_raw(py, &filepath.as_ref().to_string_lossy())?;
+        self.set_file_format(py, file_format)?;
+        self.save(py)?;
+        Ok(())
+    }
+
+    /// Saves the image with color management of `scene` applied, e.g., the result of a render.
+    ///
+    /// # Arguments
+    ///
+    /// * `file_format` - The file format, which overrides the one of the scene while saving.
+    /// * `color_depth` - The bit depth per channel, which must be supported by the file format.
+    pub fn save_render(
+        &self,
+        py: Python,
+        filepath: impl AsRef<Path>,
+        scene: &Scene,
+        file_format: ImageFileFormat,
+        color_depth: ColorDepth,
+    ) -> Result<()> {
+        let image_settings = scene.render(py)?.getattr(intern!(py, "image_settings"))?;
+        let _guard = ImageSettingsGuard::new(image_settings)?;
+        image_settings.setattr(intern!(py, "file_format"), file_format.to_object(py))?;
+        image_settings.setattr(intern!(py, "color_depth"), color_depth)?;
+
+        let kwargs = PyDict::new(py);
+        kwargs.set_item(intern!(py, "scene"), scene.to_object(py))?;
+        self.as_ref(py).call_method(
+            intern!(py, "save_render"),
+            (filepath.as_ref().to_object(py),),
+            Some(kwargs),
+        )?;
+        Ok(())
+    }
+
+    /// Returns `true` if the image is packed into the Blender project file.
+    pub fn is_packed(&self, py: Python) -> Result<bool> {
+        Ok(!self.getattr(py, intern!(py, "packed_file"))?.is_none(py))
+    }
+
+    bind_python! { self.alpha_mode => pub fn alpha_mode(&self, py: Python) -> Result<String> }
+    bind_python! { self.alpha_mode = pub fn set_alpha_mode(&mut self, py: Python, value: &str) }
+    bind_python! { self.channels => pub fn channels(&self, py: Python) -> Result<u32> }
+    bind_python! { self.colorspace_settings.name => pub fn colorspace(&self, py: Python) -> Result<String> }
+    bind_python! { self.colorspace_settings.name = pub fn set_colorspace(&mut self, py: Python, value: &str) }
+    bind_python! { self.depth => pub fn depth(&self, py: Python) -> Result<u32> }
+    bind_python! { self.file_format => pub fn file_format(&self, py: Python) -> Result<ImageFileFormat> }
+    bind_python! { self.file_format = pub fn set_file_format(&mut self, py: Python, value: ImageFileFormat) }
+    bind_python! { self.filepath => pub fn filepath(&self, py: Python) -> Result<String> }
+    bind_python! { self.filepath = pub fn set_filepath(&mut self, py: Python, value: &str) }
+    bind_python! { self.filepath_raw => pub fn filepath_raw(&self, py: Python) -> Result<String> }
+    bind_python! { self.filepath_raw = pub fn set_filepath_raw(&mut self, py: Python, value: &str) }
+    bind_python! { self.has_data => pub fn has_data(&self, py: Python) -> Result<bool> }
+    bind_python! { self.is_dirty => pub fn is_dirty(&self, py: Python) -> Result<bool> }
+    bind_python! { self.is_float => pub fn is_float(&self, py: Python) -> Result<bool> }
+    bind_python! { self.source => pub fn source(&self, py: Python) -> Result<String> }
+    bind_python! { self.source = pub fn set_source(&mut self, py: Python, value: &str) }
+    bind_python! { self.use_half_precision => pub fn use_half_precision(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_half_precision = pub fn set_use_half_precision(&mut self, py: Python, value: bool) }
+    bind_python! { self.pack() => pub fn pack(&mut self, py: Python) }
+    bind_python! { self.reload() => pub fn reload(&mut self, py: Python) }
+    bind_python! { self.save() => pub fn save(&mut self, py: Python) }
+    bind_python! { self.scale() => pub fn scale(&mut self, py: Python, width: u32, height: u32) }
+    bind_python! { self.unpack() => pub fn unpack(&mut self, py: Python, method: &str) }
+    bind_python! { self.update() => pub fn update(&mut self, py: Python) }
+}
+
+/// Restores the file format and color depth of image settings when dropped.
+struct ImageSettingsGuard {
+    image_settings: PyObject,
+    file_format: PyObject,
+    color_depth: PyObject,
+}
+
+impl ImageSettingsGuard {
+    fn new(image_settings: &PyAny) -> Result<Self> {
+        let py = image_settings.py();
+        Ok(Self {
+            image_settings: image_settings.into(),
+            file_format: image_settings.getattr(intern!(py, "file_format"))?.into(),
+            color_depth: image_settings.getattr(intern!(py, "color_depth"))?.into(),
+        })
+    }
+}
+
+impl Drop for ImageSettingsGuard {
+    fn drop(&mut self) {
+        Python::with_gil(|py| {
+            let image_settings = self.image_settings.as_ref(py);
+            for (attr, value) in [
+                (intern!(py, "file_format"), &self.file_format),
+                (intern!(py, "color_depth"), &self.color_depth),
+            ] {
+                if let Err(err) = image_settings.setattr(attr, value) {
+                    err.print(py);
+                }
+            }
+        });
+    }
+}
+
+impl From<pyo3::PyObject> for Image {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Image {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Image {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Image {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{types::BpyID, NodeTree};
+use crate::{
+    enums::DataBlockKind,
+    types::{AnimationData, BpyID, DataBlock},
+    NodeTree,
+};
 use derive_more::{Deref, DerefMut, Display};
 use pyo3::{PyAny, PyObject};
 use pyo3_macros_more::bind_python;
@@ -10,10 +14,14 @@ pub struct Material(PyObject);
 
 impl BpyID for Material {}
 
+impl DataBlock for Material {
+    const KIND: DataBlockKind = DataBlockKind::Material;
+}
+
 impl Material {
     bind_python! { self.alpha_threshold => pub fn alpha_threshold(&self, py: Python) -> Result<f32> }
     bind_python! { self.alpha_threshold = pub fn set_alpha_threshold(&mut self, py: Python, value: &f32) }
-    bind_python! { self.animation_data => pub fn animation_data<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny>  }
+    bind_python! { self.animation_data => pub fn animation_data(&self, py: Python) -> Result<Option<AnimationData>> }
     bind_python! { self.blend_method => pub fn blend_method(&self, py: Python) -> Result<String> }
     bind_python! { self.blend_method = pub fn set_blend_method(&mut self, py: Python, value: &str) }
     bind_python! { self.cycles => pub fn cycles<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny>  }
@@ -1,7 +1,9 @@
 pub mod id_materials;
 pub mod material;
+pub mod pbr_material;
 
 pub use id_materials::IdMaterials;
 pub use material::Material;
+pub use pbr_material::{PbrInput, PbrMaterial, PbrMaterialBuilder};
 
 pub type Texture<'py> = &'py pyo3::PyAny;
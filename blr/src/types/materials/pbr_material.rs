@@ -0,0 +1,336 @@
+use crate::{
+    bpy,
+    error::BlError,
+    result::Result,
+    types::{
+        BpyID, CollectionImpl, Image, Material, NodeGraph, NodeRef, NodeSocketValue, NodeSpec,
+        SocketRef,
+    },
+    version::bpy_version_major,
+};
+use builder_derive_more::IntoBuilder;
+use derive_builder::Builder;
+use pyo3::Python;
+use std::path::{Path, PathBuf};
+
+/// Input of a [`PbrMaterial`] channel, either a constant value or an image texture.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PbrInput<T> {
+    Constant(T),
+    Texture(PathBuf),
+}
+
+impl<T> PbrInput<T> {
+    pub fn texture(filepath: impl Into<PathBuf>) -> Self {
+        Self::Texture(filepath.into())
+    }
+}
+
+impl<T> From<T> for PbrInput<T> {
+    fn from(value: T) -> Self {
+        Self::Constant(value)
+    }
+}
+
+/// Physically based material built around the Principled BSDF, see
+/// <https://docs.blender.org/api/latest/bpy.types.ShaderNodeBsdfPrincipled.html>.
+///
+/// Color maps (base color and emission) are read as sRGB, while all other maps are read as
+/// non-color data.
+#[derive(Debug, Clone, Builder, IntoBuilder)]
+#[builder(default, build_fn(private, name = "fallible_build", error = "BlError"))]
+pub struct PbrMaterial {
+    #[builder(setter(into))]
+    pub name: String,
+    #[builder(setter(into))]
+    pub base_color: PbrInput<[f32; 4]>,
+    #[builder(setter(into))]
+    pub metallic: PbrInput<f32>,
+    #[builder(setter(into))]
+    pub roughness: PbrInput<f32>,
+    /// Tangent-space normal map.
+    #[builder(setter(into, strip_option))]
+    pub normal_map: Option<PathBuf>,
+    pub normal_strength: f32,
+    #[builder(setter(into))]
+    pub emission: PbrInput<[f32; 4]>,
+    pub emission_strength: f32,
+    #[builder(setter(into))]
+    pub alpha: PbrInput<f32>,
+    /// Ambient occlusion map, which is multiplied with the base color.
+    #[builder(setter(into, strip_option))]
+    pub ambient_occlusion_map: Option<PathBuf>,
+    /// UV map of the textures (`None` uses the active UV map).
+    #[builder(setter(into, strip_option))]
+    pub uv_map: Option<String>,
+    /// Scale of the texture coordinates.
+    pub uv_scale: [f32; 3],
+}
+
+impl PbrMaterialBuilder {
+    #[must_use]
+    pub fn build(&self) -> PbrMaterial {
+        self.fallible_build().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl Default for PbrMaterial {
+    fn default() -> Self {
+        Self {
+            name: "Material".to_string(),
+            base_color: PbrInput::Constant([0.8, 0.8, 0.8, 1.0]),
+            metallic: PbrInput::Constant(0.0),
+            roughness: PbrInput::Constant(0.5),
+            normal_map: None,
+            normal_strength: 1.0,
+            emission: PbrInput::Constant([0.0, 0.0, 0.0, 1.0]),
+            emission_strength: 1.0,
+            alpha: PbrInput::Constant(1.0),
+            ambient_occlusion_map: None,
+            uv_map: None,
+            uv_scale: [1.0, 1.0, 1.0],
+        }
+    }
+}
+
+impl PbrMaterial {
+    /// Creates a new material with the node setup of the PBR material, which can be assigned via
+    /// [`Object::set_active_material`](crate::types::Object::set_active_material).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a texture cannot be loaded, in which case the material and all images
+    /// loaded for it are removed again.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn create(&self, py: Python) -> Result<Material> {
+        let images = bpy::data::images(py)?;
+        let existing_images = images.keys(py)?;
+        let materials = bpy::data::materials(py)?;
+        let mut material = materials.new(py, &self.name)?;
+        if let Err(err) = self.setup_nodes(py, &mut material) {
+            materials.remove(py, &material, true, true, true)?;
+            for image in images.values(py)? {
+                if !existing_images.contains(&image.name(py)?) {
+                    images.remove(py, &image, true, true, true)?;
+                }
+            }
+            return Err(err);
+        }
+        Ok(material)
+    }
+
+    /// Creates the node setup of the PBR material inside `material`.
+    fn setup_nodes(&self, py: Python, material: &mut Material) -> Result<()> {
+        material.set_use_nodes(py, &true)?;
+        self.apply_viewport_settings(py, material)?;
+
+        let mut graph = NodeGraph::new();
+        graph.clear_existing(true);
+        let bsdf = graph.add(
+            NodeSpec::new("ShaderNodeBsdfPrincipled")
+                .input("Emission Strength", self.emission_strength),
+        );
+        let output = graph.add(NodeSpec::new("ShaderNodeOutputMaterial"));
+        graph.link(bsdf.output("BSDF"), output.input("Surface"));
+
+        let mut textures = TextureNodes::new(py, &mut graph, self);
+        let base_color = textures.channel(&self.base_color, ColorSpace::Srgb)?;
+        match &self.ambient_occlusion_map {
+            Some(filepath) => {
+                let occlusion = textures.image(filepath, ColorSpace::NonColor)?;
+                let multiply = match base_color {
+                    Channel::Constant(color) => {
+                        NodeSpec::new("ShaderNodeMix").input("A_Color", color)
+                    }
+                    Channel::Texture(_) => NodeSpec::new("ShaderNodeMix"),
+                };
+                let multiply = textures.graph.add(
+                    multiply
+                        .property("data_type", "RGBA")
+                        .property("blend_type", "MULTIPLY")
+                        .input("Factor_Float", 1.0_f32),
+                );
+                if let Channel::Texture(texture) = base_color {
+                    textures
+                        .graph
+                        .link(texture.output("Color"), multiply.input("A_Color"));
+                }
+                textures
+                    .graph
+                    .link(occlusion.output("Color"), multiply.input("B_Color"))
+                    .link(multiply.output("Result_Color"), bsdf.input("Base Color"));
+            }
+            None => textures.connect(base_color, bsdf.input("Base Color")),
+        }
+
+        let metallic = textures.channel(&self.metallic, ColorSpace::NonColor)?;
+        textures.connect(metallic, bsdf.input("Metallic"));
+        let roughness = textures.channel(&self.roughness, ColorSpace::NonColor)?;
+        textures.connect(roughness, bsdf.input("Roughness"));
+        let alpha = textures.channel(&self.alpha, ColorSpace::NonColor)?;
+        textures.connect(alpha, bsdf.input("Alpha"));
+        let emission_input = if bpy_version_major(py)? >= 4 {
+            "Emission Color"
+        } else {
+            "Emission"
+        };
+        let emission = textures.channel(&self.emission, ColorSpace::Srgb)?;
+        textures.connect(emission, bsdf.input(emission_input));
+
+        if let Some(filepath) = &self.normal_map {
+            let texture = textures.image(filepath, ColorSpace::NonColor)?;
+            let mut normal_map =
+                NodeSpec::new("ShaderNodeNormalMap").input("Strength", self.normal_strength);
+            if let Some(uv_map) = &self.uv_map {
+                normal_map = normal_map.property("uv_map", uv_map.clone());
+            }
+            let normal_map = textures.graph.add(normal_map);
+            textures
+                .graph
+                .link(texture.output("Color"), normal_map.input("Color"))
+                .link(normal_map.output("Normal"), bsdf.input("Normal"));
+        }
+
+        graph.build(py, &material.node_tree(py)?)?;
+        Ok(())
+    }
+
+    /// Mirrors constant values in the settings of the viewport and enables transparency.
+    fn apply_viewport_settings(&self, py: Python, material: &mut Material) -> Result<()> {
+        if let PbrInput::Constant(color) = self.base_color {
+            material.set_diffuse_color(py, color)?;
+        }
+        if let PbrInput::Constant(metallic) = self.metallic {
+            material.set_metallic(py, &metallic)?;
+        }
+        if let PbrInput::Constant(roughness) = self.roughness {
+            material.set_roughness(py, &roughness)?;
+        }
+        if self.alpha != PbrInput::Constant(1.0) {
+            material.set_blend_method(py, "HASHED")?;
+            material.set_shadow_method(py, "HASHED")?;
+        }
+        Ok(())
+    }
+}
+
+/// Color space of the image of a texture.
+#[derive(Clone, Copy, Debug)]
+enum ColorSpace {
+    Srgb,
+    NonColor,
+}
+
+impl ColorSpace {
+    fn name(self) -> &'static str {
+        match self {
+            Self::Srgb => "sRGB",
+            Self::NonColor => "Non-Color",
+        }
+    }
+}
+
+/// Loads the image at `filepath` in the given color space. Images that are already loaded are
+/// only reused if they have the same color space, because changing it would also affect all other
+/// textures (and materials) that use the image.
+fn load_image(py: Python, filepath: &Path, color_space: ColorSpace) -> Result<Image> {
+    let images = bpy::data::images(py)?;
+    let n_images = images.len(py)?;
+    let mut existing = images.load(py, filepath, true)?;
+    if existing.colorspace(py)? == color_space.name() {
+        return Ok(existing);
+    }
+    if images.len(py)? > n_images {
+        // Freshly loaded, i.e. not shared yet
+        existing.set_colorspace(py, color_space.name())?;
+        return Ok(existing);
+    }
+
+    let existing_filepath = existing.filepath(py)?;
+    for image in images.values(py)? {
+        if image.filepath(py)? == existing_filepath && image.colorspace(py)? == color_space.name() {
+            return Ok(image);
+        }
+    }
+
+    let mut image = images.load(py, filepath, false)?;
+    image.set_colorspace(py, color_space.name())?;
+    Ok(image)
+}
+
+/// Resolved input of a channel.
+enum Channel<T> {
+    Constant(T),
+    Texture(NodeRef),
+}
+
+/// Creates Image Texture nodes that share a single (scaled) texture coordinate.
+struct TextureNodes<'a> {
+    py: Python<'a>,
+    graph: &'a mut NodeGraph,
+    material: &'a PbrMaterial,
+    coordinates: Option<SocketRef>,
+}
+
+impl<'a> TextureNodes<'a> {
+    fn new(py: Python<'a>, graph: &'a mut NodeGraph, material: &'a PbrMaterial) -> Self {
+        Self {
+            py,
+            graph,
+            material,
+            coordinates: None,
+        }
+    }
+
+    fn channel<T: Copy>(
+        &mut self,
+        input: &PbrInput<T>,
+        color_space: ColorSpace,
+    ) -> Result<Channel<T>> {
+        Ok(match input {
+            PbrInput::Constant(value) => Channel::Constant(*value),
+            PbrInput::Texture(filepath) => Channel::Texture(self.image(filepath, color_space)?),
+        })
+    }
+
+    fn connect<T: NodeSocketValue + 'static>(&mut self, channel: Channel<T>, to: SocketRef) {
+        match channel {
+            Channel::Constant(value) => self.graph.set_input(to, value),
+            Channel::Texture(texture) => self.graph.link(texture.output("Color"), to),
+        };
+    }
+
+    fn image(&mut self, filepath: &Path, color_space: ColorSpace) -> Result<NodeRef> {
+        let image = load_image(self.py, filepath, color_space)?;
+        let vector = self.coordinates();
+        let texture = self
+            .graph
+            .add(NodeSpec::new("ShaderNodeTexImage").property("image", image));
+        self.graph.link(vector, texture.input("Vector"));
+        Ok(texture)
+    }
+
+    /// Returns the output of the scaled texture coordinates, which are created on first use.
+    fn coordinates(&mut self) -> SocketRef {
+        if let Some(coordinates) = &self.coordinates {
+            return coordinates.clone();
+        }
+        let uv = match &self.material.uv_map {
+            Some(uv_map) => self
+                .graph
+                .add(NodeSpec::new("ShaderNodeUVMap").property("uv_map", uv_map.clone()))
+                .output("UV"),
+            None => self
+                .graph
+                .add(NodeSpec::new("ShaderNodeTexCoord"))
+                .output("UV"),
+        };
+        let mapping = self
+            .graph
+            .add(NodeSpec::new("ShaderNodeMapping").input("Scale", self.material.uv_scale));
+        self.graph.link(uv, mapping.input("Vector"));
+        let coordinates = mapping.output("Vector");
+        self.coordinates = Some(coordinates.clone());
+        coordinates
+    }
+}
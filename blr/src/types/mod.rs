@@ -1,30 +1,64 @@
 //! Idiomatic wrappers around Blender [types](https://docs.blender.org/api/latest/bpy.types.html).
 
+pub mod animation;
+pub mod attributes;
+pub mod bones;
 pub mod bpy_id;
 pub mod bpy_struct;
 pub mod collections;
+pub mod constraints;
+pub mod depsgraph;
+pub mod edit_mode_guard;
+pub mod image;
 pub mod materials;
 pub mod modifiers;
 pub mod nodes;
 pub mod objects;
+pub mod physics;
 pub mod scene;
+pub mod selection_guard;
+pub mod shape_keys;
 pub mod temp_override;
+pub mod vertex_group;
 
-pub use bpy_id::BpyID;
+pub use animation::{
+    Action, AnimationData, Driver, DriverTarget, DriverVariable, FCurve, FModifier, Keyframe,
+};
+pub use attributes::Attribute;
+pub use bones::{Bone, BoneSpec, EditBone, PoseBone};
+pub use bpy_id::{BpyID, DataBlock};
 pub use bpy_struct::BpyStruct;
 pub use collections::{
-    Collection, CollectionImpl, Materials, NodeLinks, NodeTreeInputs, NodeTreeOutputs, NodeTrees,
-    Nodes, ObjectCollection, ObjectModifiers,
+    ActionFCurves, ArmatureBones, ArmatureEditBones, Attributes, Collection, CollectionChildren,
+    CollectionImpl, CollectionObjects, DriverVariables, FCurveKeyframePoints, FCurveModifiers,
+    Images, KeyBlocks, Materials, NodeIO, NodeInputs, NodeLinks, NodeOutputs, NodeTreeInputs,
+    NodeTreeOutputs, NodeTrees, Nodes, ObjectCollection, ObjectConstraints, ObjectModifiers,
+    PoseBones, SceneCollections, VertexGroups,
 };
-pub use materials::{IdMaterials, Material};
+pub use constraints::{Constraint, ConstraintImpl};
+pub use depsgraph::Depsgraph;
+pub use edit_mode_guard::EditModeGuard;
+pub use image::{Image, ImageOptions, ImageOptionsBuilder};
+pub use materials::{IdMaterials, Material, PbrInput, PbrMaterial, PbrMaterialBuilder};
 pub use modifiers::{Modifier, ModifierImpl};
-pub use nodes::{Node, NodeLink, NodeSocket, NodeSocketInterface, NodeTree};
+pub use nodes::{
+    Node, NodeGraph, NodeHandles, NodeLink, NodeRef, NodeSocket, NodeSocketInterface,
+    NodeSocketValue, NodeSpec, NodeTree, SocketRef,
+};
 pub use objects::{
-    Armature, Camera, Curve, CurveImpl, Curves, Empty, GreasePencil, Lattice, Light, LightProbe,
-    Mesh, MetaBall, Object, PointCloud, Speaker, Surface, TextCurve, Volume,
+    Armature, Camera, Curve, CurveImpl, Curves, Empty, EvaluatedMesh, GreasePencil, Lattice, Light,
+    LightProbe, Mesh, MetaBall, Object, PointCloud, Speaker, Surface, TextCurve, TriangleMesh,
+    Volume,
+};
+pub use physics::{RigidBodyConstraint, RigidBodyObject, RigidBodyWorld};
+pub use scene::{
+    EnvironmentTexture, EnvironmentTextureBuilder, Scene, SceneCollection, Screen, SkyTexture,
+    SkyTextureBuilder, SpaceView3D, ViewLayer, Window, World,
 };
-pub use scene::{Scene, Screen, SpaceView3D, ViewLayer, Window, World};
+pub use selection_guard::SelectionGuard;
+pub use shape_keys::{Key, ShapeKey};
 pub use temp_override::TempOverride;
+pub use vertex_group::VertexGroup;
 
 // pub type Addon<'py> = &'py pyo3::PyAny;
 // pub type AddonPreferences<'py> = &'py pyo3::PyAny;
@@ -1,12 +1,14 @@
 pub mod node;
+pub mod node_graph;
 pub mod node_link;
 pub mod node_socket;
 pub mod node_socket_interface;
 pub mod node_tree;
 
 pub use node::Node;
+pub use node_graph::{NodeGraph, NodeHandles, NodeRef, NodeSpec, SocketRef};
 pub use node_link::NodeLink;
-pub use node_socket::NodeSocket;
+pub use node_socket::{NodeSocket, NodeSocketValue};
 pub use node_socket_interface::NodeSocketInterface;
 pub use node_tree::NodeTree;
 
@@ -1,6 +1,13 @@
-use crate::types::BpyID;
+use crate::{
+    result::Result,
+    types::{
+        collections::{NodeInputs, NodeOutputs},
+        BpyID, CollectionImpl, NodeSocket, NodeTree,
+    },
+};
 use derive_more::{Deref, DerefMut, Display};
-use pyo3::PyObject;
+use pyo3::{PyAny, PyObject, Python};
+use pyo3_macros_more::bind_python;
 
 /// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Node.html>
 #[repr(transparent)]
@@ -9,7 +16,56 @@ pub struct Node(PyObject);
 
 impl BpyID for Node {}
 
-impl Node {}
+impl Node {
+    /// Returns the input socket with the given `name`.
+    pub fn input(&self, py: Python, name: &str) -> Result<NodeSocket> {
+        let inputs = self.inputs(py)?;
+        Ok(inputs.get(py, name)?)
+    }
+
+    /// Returns the output socket with the given `name`.
+    pub fn output(&self, py: Python, name: &str) -> Result<NodeSocket> {
+        let outputs = self.outputs(py)?;
+        Ok(outputs.get(py, name)?)
+    }
+
+    bind_python! { self.bl_description => pub fn bl_description(&self, py: Python) -> Result<String> }
+    bind_python! { self.bl_icon => pub fn bl_icon(&self, py: Python) -> Result<String> }
+    bind_python! { self.bl_idname => pub fn bl_idname(&self, py: Python) -> Result<String> }
+    bind_python! { self.bl_label => pub fn bl_label(&self, py: Python) -> Result<String> }
+    bind_python! { self.color => pub fn color(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.color = pub fn set_color(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.dimensions => pub fn dimensions(&self, py: Python) -> Result<[f32; 2]> }
+    bind_python! { self.height => pub fn height(&self, py: Python) -> Result<f32> }
+    bind_python! { self.height = pub fn set_height(&mut self, py: Python, value: f32) }
+    bind_python! { self.hide => pub fn hide(&self, py: Python) -> Result<bool> }
+    bind_python! { self.hide = pub fn set_hide(&mut self, py: Python, value: bool) }
+    bind_python! { self.id_data => pub fn node_tree(&self, py: Python) -> Result<NodeTree> }
+    bind_python! { self.inputs => pub fn inputs(&self, py: Python) -> Result<NodeInputs> }
+    bind_python! { self.internal_links => pub fn internal_links<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.label => pub fn label(&self, py: Python) -> Result<String> }
+    bind_python! { self.label = pub fn set_label(&mut self, py: Python, value: &str) }
+    bind_python! { self.location => pub fn location(&self, py: Python) -> Result<[f32; 2]> }
+    bind_python! { self.location = pub fn set_location(&mut self, py: Python, value: [f32; 2]) }
+    bind_python! { self.mute => pub fn mute(&self, py: Python) -> Result<bool> }
+    bind_python! { self.mute = pub fn set_mute(&mut self, py: Python, value: bool) }
+    bind_python! { self.name => pub fn name(&self, py: Python) -> Result<String> }
+    bind_python! { self.name = pub fn set_name(&mut self, py: Python, value: &str) }
+    bind_python! { self.outputs => pub fn outputs(&self, py: Python) -> Result<NodeOutputs> }
+    bind_python! { self.parent => pub fn parent(&self, py: Python) -> Result<Option<Node>> }
+    bind_python! { self.parent = pub fn set_parent(&mut self, py: Python, value: Option<&Node>) }
+    bind_python! { self.select => pub fn select(&self, py: Python) -> Result<bool> }
+    bind_python! { self.select = pub fn set_select(&mut self, py: Python, value: bool) }
+    bind_python! { self.show_options => pub fn show_options(&self, py: Python) -> Result<bool> }
+    bind_python! { self.show_options = pub fn set_show_options(&mut self, py: Python, value: bool) }
+    bind_python! { self.show_preview => pub fn show_preview(&self, py: Python) -> Result<bool> }
+    bind_python! { self.show_preview = pub fn set_show_preview(&mut self, py: Python, value: bool) }
+    bind_python! { self.type => pub fn node_type(&self, py: Python) -> Result<String> }
+    bind_python! { self.use_custom_color => pub fn use_custom_color(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_custom_color = pub fn set_use_custom_color(&mut self, py: Python, value: bool) }
+    bind_python! { self.width => pub fn width(&self, py: Python) -> Result<f32> }
+    bind_python! { self.width = pub fn set_width(&mut self, py: Python, value: f32) }
+}
 
 impl From<pyo3::PyObject> for Node {
     fn from(value: pyo3::PyObject) -> Self {
@@ -0,0 +1,570 @@
+//! Declarative construction of node trees.
+//!
+//! A [`NodeGraph`] collects nodes, input values and links without touching Blender. Calling
+//! [`NodeGraph::build`] creates everything inside an existing [`NodeTree`] (shader, geometry or
+//! compositing), validates all socket names and types, and lays out the nodes from left to right.
+//!
+//! ```no_run
+//! use blr::{bpy, types::{NodeGraph, NodeSpec}};
+//!
+//! # pyo3::Python::with_gil(|py| -> blr::BlResult<()> {
+//! let tree = bpy::data::node_groups(py)?.new_named(py, "Noise", "ShaderNodeTree")?;
+//! let mut graph = NodeGraph::new();
+//! let noise = graph.add(NodeSpec::new("ShaderNodeTexNoise").input("Scale", 8.0_f32));
+//! let bsdf = graph.add(NodeSpec::new("ShaderNodeBsdfPrincipled").input("Roughness", 0.4_f32));
+//! let output = graph.add(NodeSpec::new("ShaderNodeOutputMaterial"));
+//! graph
+//!     .link(noise.output("Color"), bsdf.input("Base Color"))
+//!     .link(bsdf.output("BSDF"), output.input("Surface"));
+//! let nodes = graph.build(py, &tree)?;
+//! nodes[bsdf].input(py, "Metallic")?.set_default_value(py, 1.0_f32)?;
+//! # Ok(())
+//! # })
+//! # .unwrap();
+//! ```
+
+use crate::{
+    enums::Dtype,
+    error::BlError,
+    result::Result,
+    types::{
+        collections::NodeIO,
+        nodes::{Node, NodeSocket, NodeSocketValue, NodeTree},
+        CollectionImpl,
+    },
+};
+use pyo3::{exceptions::PyKeyError, intern, Python, ToPyObject};
+use std::{
+    fmt,
+    ops::Index,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// Horizontal distance between the columns of the automatic layout.
+const LAYOUT_SPACING_X: f32 = 300.0;
+/// Vertical distance between the nodes in a column of the automatic layout.
+const LAYOUT_SPACING_Y: f32 = 250.0;
+
+/// Source of unique identifiers of graphs, so that handles cannot be mixed up between them.
+static NEXT_GRAPH_ID: AtomicUsize = AtomicUsize::new(0);
+
+type Setter = Box<dyn FnOnce(Python, &mut Node) -> Result<()>>;
+
+/// Handle of a node declared in a [`NodeGraph`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NodeRef {
+    graph: usize,
+    index: usize,
+}
+
+impl NodeRef {
+    /// Returns a handle of the input socket `name` (or identifier) of the node.
+    #[must_use]
+    pub fn input(self, name: &str) -> SocketRef {
+        SocketRef {
+            node: self,
+            name: name.to_string(),
+            is_output: false,
+        }
+    }
+
+    /// Returns a handle of the output socket `name` (or identifier) of the node.
+    #[must_use]
+    pub fn output(self, name: &str) -> SocketRef {
+        SocketRef {
+            node: self,
+            name: name.to_string(),
+            is_output: true,
+        }
+    }
+}
+
+/// Handle of a socket of a node declared in a [`NodeGraph`].
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct SocketRef {
+    pub node: NodeRef,
+    pub name: String,
+    /// Whether the socket is an output (see [`NodeRef::output`]) or an input of the node.
+    pub is_output: bool,
+}
+
+impl SocketRef {
+    const fn direction(&self) -> &'static str {
+        if self.is_output {
+            "output"
+        } else {
+            "input"
+        }
+    }
+}
+
+/// Declaration of a single node, see [`NodeGraph::add`].
+pub struct NodeSpec {
+    bl_idname: String,
+    name: Option<String>,
+    label: Option<String>,
+    location: Option<[f32; 2]>,
+    parent: Option<NodeRef>,
+    inputs: Vec<(String, Setter)>,
+    properties: Vec<(String, Setter)>,
+}
+
+impl NodeSpec {
+    /// Declares a node of the given type, e.g., `ShaderNodeBsdfPrincipled`,
+    /// `GeometryNodeMeshGrid` or `CompositorNodeBlur`.
+    #[must_use]
+    pub fn new(bl_idname: &str) -> Self {
+        Self {
+            bl_idname: bl_idname.to_string(),
+            name: None,
+            label: None,
+            location: None,
+            parent: None,
+            inputs: Vec::new(),
+            properties: Vec::new(),
+        }
+    }
+
+    #[must_use]
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    #[must_use]
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+
+    /// Places the node at a fixed `location` instead of the automatic layout.
+    #[must_use]
+    pub fn location(mut self, location: [f32; 2]) -> Self {
+        self.location = Some(location);
+        self
+    }
+
+    /// Places the node inside a frame node that was declared before.
+    #[must_use]
+    pub fn parent(mut self, frame: NodeRef) -> Self {
+        self.parent = Some(frame);
+        self
+    }
+
+    /// Sets the default value of input socket `name` (or identifier), whose data type is
+    /// validated when the graph is built.
+    #[must_use]
+    pub fn input<T: NodeSocketValue + 'static>(mut self, name: &str, value: T) -> Self {
+        self.push_input(name, value);
+        self
+    }
+
+    /// Sets property `name` of the node, e.g., `operation` of a Math node.
+    #[must_use]
+    pub fn property<T: ToPyObject + 'static>(mut self, name: &str, value: T) -> Self {
+        let property = name.to_string();
+        self.properties.push((
+            name.to_string(),
+            Box::new(move |py, node| {
+                node.as_ref(py)
+                    .setattr(property.as_str(), value.to_object(py))?;
+                Ok(())
+            }),
+        ));
+        self
+    }
+}
+
+impl NodeSpec {
+    fn push_input<T: NodeSocketValue + 'static>(&mut self, name: &str, value: T) {
+        self.inputs
+            .push((name.to_string(), input_setter(name, value)));
+    }
+}
+
+impl fmt::Debug for NodeSpec {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeSpec")
+            .field("bl_idname", &self.bl_idname)
+            .field("name", &self.name)
+            .field("label", &self.label)
+            .field("location", &self.location)
+            .field("parent", &self.parent)
+            .field(
+                "inputs",
+                &self.inputs.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .field(
+                "properties",
+                &self
+                    .properties
+                    .iter()
+                    .map(|(name, _)| name)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+/// Declarative description of nodes and their links, see the [module](self) documentation.
+pub struct NodeGraph {
+    id: usize,
+    nodes: Vec<NodeSpec>,
+    inputs: Vec<(SocketRef, Setter)>,
+    links: Vec<(SocketRef, SocketRef)>,
+    clear: bool,
+}
+
+impl NodeGraph {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            id: NEXT_GRAPH_ID.fetch_add(1, Ordering::Relaxed),
+            nodes: Vec::new(),
+            inputs: Vec::new(),
+            links: Vec::new(),
+            clear: false,
+        }
+    }
+
+    /// Replaces all existing nodes of the tree once the graph is built successfully.
+    pub fn clear_existing(&mut self, clear: bool) -> &mut Self {
+        self.clear = clear;
+        self
+    }
+
+    /// Declares a node and returns its handle.
+    pub fn add(&mut self, node: NodeSpec) -> NodeRef {
+        self.nodes.push(node);
+        NodeRef {
+            graph: self.id,
+            index: self.nodes.len() - 1,
+        }
+    }
+
+    /// Sets the default value of input socket `to` of a declared node, see [`NodeSpec::input`].
+    /// Like links, the socket is validated when the graph is built.
+    pub fn set_input<T: NodeSocketValue + 'static>(
+        &mut self,
+        to: SocketRef,
+        value: T,
+    ) -> &mut Self {
+        let setter = input_setter(&to.name, value);
+        self.inputs.push((to, setter));
+        self
+    }
+
+    /// Declares a link from output socket `from` to input socket `to`.
+    /// Like input values, the directions of the sockets are validated when the graph is built.
+    pub fn link(&mut self, from: SocketRef, to: SocketRef) -> &mut Self {
+        self.links.push((from, to));
+        self
+    }
+
+    /// Creates all declared nodes and links inside `tree`.
+    ///
+    /// The tree is only modified if the whole graph is valid, i.e. all nodes created so far are
+    /// removed again on error and the existing nodes are kept (see [`NodeGraph::clear_existing`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a node handle belongs to a different graph, a socket does not exist or
+    /// is used in the wrong direction (e.g., an input linked as the source of a link), an input
+    /// value does not match the data type of its socket, or a link connects sockets of
+    /// incompatible data types.
+    /// Furthermore, exceptions from the Python API of Blender are propagated (e.g., for node types
+    /// that are not supported by the tree).
+    pub fn build(self, py: Python, tree: &NodeTree) -> Result<NodeHandles> {
+        self.check_handles()?;
+        self.check_directions()?;
+        let locations = self.layout();
+        let id = self.id;
+
+        let tree_nodes = tree.nodes(py)?;
+        // Existing nodes are renamed to free their names for the new nodes and only removed once
+        // the new nodes are complete.
+        let mut existing = Vec::new();
+        if self.clear {
+            for mut node in tree_nodes.values(py)? {
+                let name = node.name(py)?;
+                node.set_name(py, &format!("{name}.replaced"))?;
+                existing.push((node, name));
+            }
+        }
+
+        let mut nodes = Vec::with_capacity(self.nodes.len());
+        match self.create(py, tree, locations, &mut nodes) {
+            Ok(()) => {
+                for (node, _) in &existing {
+                    tree_nodes.remove(py, node)?;
+                }
+                Ok(NodeHandles { graph: id, nodes })
+            }
+            Err(err) => {
+                for node in &nodes {
+                    tree_nodes.remove(py, node)?;
+                }
+                for (mut node, name) in existing {
+                    node.set_name(py, &name)?;
+                }
+                Err(err)
+            }
+        }
+    }
+
+    /// Checks that all handles belong to this graph and that parents are declared before their
+    /// children, so that invalid graphs are rejected before Blender is touched.
+    fn check_handles(&self) -> Result<()> {
+        let parents = self.nodes.iter().enumerate().filter_map(|(index, spec)| {
+            spec.parent.map(|parent| {
+                (parent.index < index).then_some(parent).ok_or_else(|| {
+                    BlError::ValueError(format!(
+                        "Parent of node '{}' must be declared before it",
+                        spec.bl_idname
+                    ))
+                })
+            })
+        });
+        let sockets = self
+            .links
+            .iter()
+            .flat_map(|(from, to)| [from, to])
+            .chain(self.inputs.iter().map(|(to, _)| to))
+            .map(|socket| Ok(socket.node));
+        for node in parents.chain(sockets) {
+            let node = node?;
+            if node.graph != self.id || node.index >= self.nodes.len() {
+                return Err(BlError::ValueError(format!(
+                    "Node handle {} does not belong to this graph",
+                    node.index
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that links lead from an output to an input socket and that input values are only
+    /// set on input sockets.
+    fn check_directions(&self) -> Result<()> {
+        let sockets = self
+            .links
+            .iter()
+            .flat_map(|(from, to)| [(from, true), (to, false)])
+            .chain(self.inputs.iter().map(|(to, _)| (to, false)));
+        for (socket, is_output) in sockets {
+            if socket.is_output != is_output {
+                return Err(BlError::ValueError(format!(
+                    "Socket '{name}' of node '{bl_idname}' is used as an {expected} but declared \
+                     as an {actual}",
+                    name = socket.name,
+                    bl_idname = self.nodes[socket.node.index].bl_idname,
+                    expected = if is_output { "output" } else { "input" },
+                    actual = socket.direction(),
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Creates the nodes, input values and links, collecting the created nodes in `nodes`.
+    fn create(
+        self,
+        py: Python,
+        tree: &NodeTree,
+        locations: Vec<[f32; 2]>,
+        nodes: &mut Vec<Node>,
+    ) -> Result<()> {
+        let mut tree_nodes = tree.nodes(py)?;
+        for (spec, location) in self.nodes.into_iter().zip(locations) {
+            let mut node = tree_nodes.new(py, &spec.bl_idname)?;
+            nodes.push(node.clone());
+            if let Some(name) = &spec.name {
+                node.set_name(py, name)?;
+            }
+            if let Some(label) = &spec.label {
+                node.set_label(py, label)?;
+            }
+            if let Some(parent) = spec.parent {
+                node.set_parent(py, Some(&nodes[parent.index]))?;
+            }
+            node.set_location(py, location)?;
+            for (_, set_property) in spec.properties {
+                set_property(py, &mut node)?;
+            }
+            for (_, set_input) in spec.inputs {
+                set_input(py, &mut node)?;
+            }
+        }
+        for (to, set_input) in self.inputs {
+            set_input(py, &mut nodes[to.node.index])?;
+        }
+
+        let mut sockets = Vec::with_capacity(self.links.len());
+        for (from, to) in &self.links {
+            let from_node = &nodes[from.node.index];
+            let to_node = &nodes[to.node.index];
+            let outputs = from_node.outputs(py)?;
+            let inputs = to_node.inputs(py)?;
+            let from_socket = find_socket(py, from_node, &outputs, &from.name, from.direction())?;
+            let to_socket = find_socket(py, to_node, &inputs, &to.name, to.direction())?;
+            check_link(py, &from_socket, &to_socket)?;
+            sockets.push((from_socket, to_socket));
+        }
+        let links = tree.links(py)?;
+        for (from_socket, to_socket) in &sockets {
+            links.new(py, from_socket, to_socket, true)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the location of every node, arranged in columns by the longest chain of links
+    /// leading to it (unless a location is declared explicitly).
+    fn layout(&self) -> Vec<[f32; 2]> {
+        let mut depths = vec![0_usize; self.nodes.len()];
+        // Relaxation over all links (bounded by the number of nodes to terminate on cycles).
+        for _ in 0..self.nodes.len() {
+            let mut changed = false;
+            for (from, to) in &self.links {
+                if depths[to.node.index] < depths[from.node.index] + 1 {
+                    depths[to.node.index] = depths[from.node.index] + 1;
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
+
+        let max_depth = depths.iter().copied().max().unwrap_or(0);
+        let mut rows = vec![0_usize; max_depth + 1];
+        self.nodes
+            .iter()
+            .zip(depths)
+            .map(|(spec, depth)| {
+                spec.location.unwrap_or_else(|| {
+                    let row = rows[depth];
+                    rows[depth] += 1;
+                    [
+                        (depth as f32 - max_depth as f32) * LAYOUT_SPACING_X,
+                        -(row as f32) * LAYOUT_SPACING_Y,
+                    ]
+                })
+            })
+            .collect()
+    }
+}
+
+/// Nodes created by [`NodeGraph::build`], indexable by their [`NodeRef`].
+#[derive(Clone, Debug)]
+pub struct NodeHandles {
+    graph: usize,
+    nodes: Vec<Node>,
+}
+
+impl NodeHandles {
+    /// Returns the node of handle `node`, or `None` if the handle belongs to a different graph.
+    #[must_use]
+    pub fn get(&self, node: NodeRef) -> Option<&Node> {
+        if node.graph != self.graph {
+            return None;
+        }
+        self.nodes.get(node.index)
+    }
+
+    #[must_use]
+    pub fn into_vec(self) -> Vec<Node> {
+        self.nodes
+    }
+}
+
+impl Index<NodeRef> for NodeHandles {
+    type Output = Node;
+
+    /// # Panics
+    ///
+    /// Panics if the handle belongs to a different graph.
+    fn index(&self, node: NodeRef) -> &Node {
+        self.get(node).unwrap_or_else(|| {
+            panic!(
+                "Node handle {} does not belong to the graph of these nodes",
+                node.index
+            )
+        })
+    }
+}
+
+impl Default for NodeGraph {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for NodeGraph {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NodeGraph")
+            .field("id", &self.id)
+            .field("nodes", &self.nodes)
+            .field(
+                "inputs",
+                &self.inputs.iter().map(|(to, _)| to).collect::<Vec<_>>(),
+            )
+            .field("links", &self.links)
+            .field("clear", &self.clear)
+            .finish()
+    }
+}
+
+/// Returns a setter of the default value of input socket `name` (or identifier).
+fn input_setter<T: NodeSocketValue + 'static>(name: &str, value: T) -> Setter {
+    let socket_name = name.to_string();
+    Box::new(move |py, node| {
+        let inputs = node.inputs(py)?;
+        find_socket(py, node, &inputs, &socket_name, "input")?.set_default_value(py, value)
+    })
+}
+
+/// Finds a socket by its name or, alternatively, by its identifier.
+fn find_socket(
+    py: Python,
+    node: &Node,
+    sockets: &NodeIO,
+    name: &str,
+    direction: &str,
+) -> Result<NodeSocket> {
+    match sockets.get(py, name) {
+        Ok(socket) => return Ok(socket),
+        Err(err) if !err.is_instance_of::<PyKeyError>(py) => return Err(err.into()),
+        Err(_) => {}
+    }
+    match sockets.get_identifier(py, name) {
+        Err(BlError::ValueError(_)) => Err(BlError::ValueError(format!(
+            "Node '{node_name}' has no {direction} socket '{name}' (available: '{available}')",
+            node_name = node.name(py)?,
+            available = sockets.keys(py)?.join("', '"),
+        ))),
+        result => result,
+    }
+}
+
+/// Checks that closures (shaders and geometry) are only linked to sockets of the same type.
+fn check_link(py: Python, from: &NodeSocket, to: &NodeSocket) -> Result<()> {
+    // Compared as strings to also support data types that are not covered by `Dtype`.
+    let from_type: String = from.getattr(py, intern!(py, "type"))?.extract(py)?;
+    let to_type: String = to.getattr(py, intern!(py, "type"))?.extract(py)?;
+    let is_closure = |dtype: &str| {
+        [Dtype::Shader, Dtype::Geometry]
+            .iter()
+            .any(|closure| closure.to_string() == dtype)
+    };
+    if from_type != to_type && (is_closure(&from_type) || is_closure(&to_type)) {
+        return Err(BlError::TypeError(format!(
+            "Cannot link {from_type} socket '{from_name}' of node '{from_node}' to {to_type} \
+             socket '{to_name}' of node '{to_node}'",
+            from_name = from.name(py)?,
+            from_node = from.node(py)?.name(py)?,
+            to_name = to.name(py)?,
+            to_node = to.node(py)?.name(py)?,
+        )));
+    }
+    Ok(())
+}
@@ -1,10 +1,11 @@
 use crate::{
     enums::Dtype,
+    error::BlError,
     result::Result,
-    types::{BpyID, Node},
+    types::{BpyID, Image, Material, Node, Object},
 };
 use derive_more::{Deref, DerefMut, Display};
-use pyo3::{intern, PyAny, PyObject, Python, ToPyObject};
+use pyo3::{intern, FromPyObject, PyAny, PyObject, Python, ToPyObject};
 use pyo3_macros_more::bind_python;
 
 /// Wrapper for <https://docs.blender.org/api/latest/bpy.types.NodeSocket.html>
@@ -14,7 +15,98 @@ pub struct NodeSocket(PyObject);
 
 impl BpyID for NodeSocket {}
 
+/// Values of [`NodeSocket::default_value`], each stored by sockets of specific data types.
+pub trait NodeSocketValue: for<'py> FromPyObject<'py> + ToPyObject {
+    /// Data types of the sockets that store values of this type.
+    const DTYPES: &'static [Dtype];
+}
+
+impl NodeSocketValue for f32 {
+    const DTYPES: &'static [Dtype] = &[Dtype::Value];
+}
+
+impl NodeSocketValue for i32 {
+    const DTYPES: &'static [Dtype] = &[Dtype::Int];
+}
+
+impl NodeSocketValue for bool {
+    const DTYPES: &'static [Dtype] = &[Dtype::Boolean];
+}
+
+impl NodeSocketValue for [f32; 3] {
+    const DTYPES: &'static [Dtype] = &[Dtype::Vector];
+}
+
+impl NodeSocketValue for [f32; 4] {
+    const DTYPES: &'static [Dtype] = &[Dtype::Rgba];
+}
+
+impl NodeSocketValue for String {
+    const DTYPES: &'static [Dtype] = &[Dtype::String];
+}
+
+impl NodeSocketValue for Option<Object> {
+    const DTYPES: &'static [Dtype] = &[Dtype::Object];
+}
+
+impl NodeSocketValue for Option<Material> {
+    const DTYPES: &'static [Dtype] = &[Dtype::Material];
+}
+
+impl NodeSocketValue for Option<Image> {
+    const DTYPES: &'static [Dtype] = &[Dtype::Image];
+}
+
+/// Data-blocks without a dedicated wrapper (e.g., collections and textures).
+impl NodeSocketValue for Option<PyObject> {
+    const DTYPES: &'static [Dtype] = &[
+        Dtype::Collection,
+        Dtype::Image,
+        Dtype::Material,
+        Dtype::Object,
+        Dtype::Texture,
+    ];
+}
+
 impl NodeSocket {
+    /// Returns the value of the socket that is used if it is not linked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data type of the socket does not store values of type `T`.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn default_value<T: NodeSocketValue>(&self, py: Python) -> Result<T> {
+        self.check_value_type::<T>(py)?;
+        Ok(self
+            .getattr(py, intern!(py, "default_value"))?
+            .extract(py)?)
+    }
+
+    /// Sets the value of the socket that is used if it is not linked.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the data type of the socket does not store values of type `T`.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_default_value<T: NodeSocketValue>(&mut self, py: Python, value: T) -> Result<()> {
+        self.check_value_type::<T>(py)?;
+        self.setattr(py, intern!(py, "default_value"), value.to_object(py))?;
+        Ok(())
+    }
+
+    fn check_value_type<T: NodeSocketValue>(&self, py: Python) -> Result<()> {
+        let data_type = self.data_type(py)?;
+        if T::DTYPES.contains(&data_type) {
+            Ok(())
+        } else {
+            Err(BlError::TypeError(format!(
+                "Socket '{name}' of type {data_type} does not store values of type {value_type}",
+                name = self.name(py)?,
+                value_type = std::any::type_name::<T>(),
+            )))
+        }
+    }
+
     pub fn data_type(&self, py: Python) -> Result<Dtype> {
         Ok(self.getattr(py, intern!(py, "type"))?.extract(py)?)
     }
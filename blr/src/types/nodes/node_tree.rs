@@ -1,14 +1,14 @@
 use crate::{
-    enums::NodeTreeType,
+    enums::{DataBlockKind, NodeTreeType},
     objects::GreasePencil,
     result::Result,
     types::{
         collections::{NodeLinks, NodeTreeInputs, NodeTreeOutputs, Nodes},
-        BpyID,
+        AnimationData, BpyID, DataBlock,
     },
 };
 use derive_more::{Deref, DerefMut, Display};
-use pyo3::{intern, PyAny, PyObject, Python};
+use pyo3::{intern, PyObject, Python};
 use pyo3_macros_more::bind_python;
 
 /// Wrapper for <https://docs.blender.org/api/latest/bpy.types.NodeTree.html>
@@ -18,6 +18,10 @@ pub struct NodeTree(PyObject);
 
 impl BpyID for NodeTree {}
 
+impl DataBlock for NodeTree {
+    const KIND: DataBlockKind = DataBlockKind::NodeTree;
+}
+
 impl NodeTree {
     pub fn node_tree_type(&self, py: Python) -> Result<NodeTreeType> {
         Ok(self.getattr(py, intern!(py, "type"))?.extract(py)?)
@@ -27,7 +31,7 @@ impl NodeTree {
     bind_python! { self.active_input = pub fn set_active_input(&mut self, py: Python, value: usize) }
     bind_python! { self.active_output => pub fn active_output(&self, py: Python) -> Result<usize> }
     bind_python! { self.active_output = pub fn set_active_output(&mut self, py: Python, value: usize) }
-    bind_python! { self.animation_data => pub fn animation_data<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.animation_data => pub fn animation_data(&self, py: Python) -> Result<Option<AnimationData>> }
     bind_python! { self.bl_description => pub fn bl_description(&self, py: Python) -> Result<String> }
     bind_python! { self.bl_description = pub fn set_bl_description(&mut self, py: Python, value: &str) }
     bind_python! { self.bl_icon => pub fn bl_icon(&self, py: Python) -> Result<String> }
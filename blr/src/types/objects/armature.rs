@@ -1,7 +1,16 @@
-use crate::types::BpyID;
+use crate::{
+    enums::DataBlockKind,
+    error::BlError,
+    result::Result,
+    types::{
+        collections::{ArmatureBones, ArmatureEditBones},
+        BoneSpec, BpyID, CollectionImpl, DataBlock, EditBone,
+    },
+};
 use derive_more::{Deref, DerefMut, Display};
-use pyo3::PyObject;
+use pyo3::{PyObject, Python};
 use pyo3_macros_more::bind_python;
+use std::collections::HashMap;
 
 /// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Armature.html>
 #[repr(transparent)]
@@ -10,8 +19,67 @@ pub struct Armature(PyObject);
 
 impl BpyID for Armature {}
 
+impl DataBlock for Armature {
+    const KIND: DataBlockKind = DataBlockKind::Armature;
+}
+
 impl Armature {
-    bind_python! {}
+    /// Adds an edit bone for each of the given `bones`, whose parents are either declared before
+    /// them or already exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the armature is not in edit mode (see
+    /// [`EditModeGuard`](crate::types::EditModeGuard)) or a parent bone does not exist.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn add_bones(&self, py: Python, bones: &[BoneSpec]) -> Result<Vec<EditBone>> {
+        if !self.is_editmode(py)? {
+            return Err(BlError::ValueError(format!(
+                "Armature '{}' must be in edit mode to add bones",
+                self.name(py)?
+            )));
+        }
+        let edit_bones = self.edit_bones(py)?;
+        let mut added: HashMap<String, EditBone> = HashMap::new();
+        let mut result = Vec::with_capacity(bones.len());
+        for spec in bones {
+            let parent = match &spec.parent {
+                Some(parent) => Some(match added.get(parent) {
+                    Some(bone) => bone.clone(),
+                    None => edit_bones.get(py, parent).map_err(|_| {
+                        BlError::ValueError(format!(
+                            "Parent '{parent}' of bone '{}' does not exist",
+                            spec.name
+                        ))
+                    })?,
+                }),
+                None => None,
+            };
+
+            let mut bone = edit_bones.new(py, &spec.name)?;
+            bone.set_head(py, spec.head)?;
+            bone.set_tail(py, spec.tail)?;
+            bone.set_roll(py, spec.roll)?;
+            bone.set_use_deform(py, spec.use_deform)?;
+            bone.set_parent(py, parent.as_ref())?;
+            bone.set_use_connect(py, spec.use_connect)?;
+            added.insert(spec.name.clone(), bone.clone());
+            result.push(bone);
+        }
+        Ok(result)
+    }
+
+    bind_python! { self.bones => pub fn bones(&self, py: Python) -> Result<ArmatureBones> }
+    bind_python! { self.display_type => pub fn display_type(&self, py: Python) -> Result<String> }
+    bind_python! { self.display_type = pub fn set_display_type(&mut self, py: Python, value: &str) }
+    bind_python! { self.edit_bones => pub fn edit_bones(&self, py: Python) -> Result<ArmatureEditBones> }
+    bind_python! { self.is_editmode => pub fn is_editmode(&self, py: Python) -> Result<bool> }
+    bind_python! { self.pose_position => pub fn pose_position(&self, py: Python) -> Result<String> }
+    bind_python! { self.pose_position = pub fn set_pose_position(&mut self, py: Python, value: &str) }
+    bind_python! { self.show_axes => pub fn show_axes(&self, py: Python) -> Result<bool> }
+    bind_python! { self.show_axes = pub fn set_show_axes(&mut self, py: Python, value: bool) }
+    bind_python! { self.show_names => pub fn show_names(&self, py: Python) -> Result<bool> }
+    bind_python! { self.show_names = pub fn set_show_names(&mut self, py: Python, value: bool) }
 }
 
 impl From<pyo3::PyObject> for Armature {
@@ -1,6 +1,13 @@
-use crate::types::BpyID;
+use crate::{
+    bpy,
+    enums::{CameraSensorFit, CameraType, DataBlockKind},
+    error::BlError,
+    result::Result,
+    types::{BpyID, DataBlock, Object, Scene},
+};
 use derive_more::{Deref, DerefMut, Display};
-use pyo3::PyObject;
+use nalgebra::{Matrix3, Matrix4};
+use pyo3::{intern, PyObject, Python};
 use pyo3_macros_more::bind_python;
 
 /// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Camera.html>
@@ -10,8 +17,229 @@ pub struct Camera(PyObject);
 
 impl BpyID for Camera {}
 
+impl DataBlock for Camera {
+    const KIND: DataBlockKind = DataBlockKind::Camera;
+}
+
 impl Camera {
-    bind_python! {}
+    bind_python! { self.angle => pub fn angle(&self, py: Python) -> Result<f32> }
+    bind_python! { self.angle = pub fn set_angle(&mut self, py: Python, value: f32) }
+    bind_python! { self.clip_end => pub fn clip_end(&self, py: Python) -> Result<f32> }
+    bind_python! { self.clip_end = pub fn set_clip_end(&mut self, py: Python, value: f32) }
+    bind_python! { self.clip_start => pub fn clip_start(&self, py: Python) -> Result<f32> }
+    bind_python! { self.clip_start = pub fn set_clip_start(&mut self, py: Python, value: f32) }
+    bind_python! { self.dof.aperture_blades => pub fn dof_aperture_blades(&self, py: Python) -> Result<u32> }
+    bind_python! { self.dof.aperture_blades = pub fn set_dof_aperture_blades(&mut self, py: Python, value: u32) }
+    bind_python! { self.dof.aperture_fstop => pub fn dof_aperture_fstop(&self, py: Python) -> Result<f32> }
+    bind_python! { self.dof.aperture_fstop = pub fn set_dof_aperture_fstop(&mut self, py: Python, value: f32) }
+    bind_python! { self.dof.aperture_ratio => pub fn dof_aperture_ratio(&self, py: Python) -> Result<f32> }
+    bind_python! { self.dof.aperture_ratio = pub fn set_dof_aperture_ratio(&mut self, py: Python, value: f32) }
+    bind_python! { self.dof.aperture_rotation => pub fn dof_aperture_rotation(&self, py: Python) -> Result<f32> }
+    bind_python! { self.dof.aperture_rotation = pub fn set_dof_aperture_rotation(&mut self, py: Python, value: f32) }
+    bind_python! { self.dof.focus_distance => pub fn dof_focus_distance(&self, py: Python) -> Result<f32> }
+    bind_python! { self.dof.focus_distance = pub fn set_dof_focus_distance(&mut self, py: Python, value: f32) }
+    bind_python! { self.dof.focus_object => pub fn dof_focus_object(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.dof.focus_object = pub fn set_dof_focus_object(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.dof.use_dof => pub fn use_dof(&self, py: Python) -> Result<bool> }
+    bind_python! { self.dof.use_dof = pub fn set_use_dof(&mut self, py: Python, value: bool) }
+    bind_python! { self.lens => pub fn lens(&self, py: Python) -> Result<f32> }
+    bind_python! { self.lens = pub fn set_lens(&mut self, py: Python, value: f32) }
+    bind_python! { self.lens_unit => pub fn lens_unit(&self, py: Python) -> Result<String> }
+    bind_python! { self.lens_unit = pub fn set_lens_unit(&mut self, py: Python, value: &str) }
+    bind_python! { self.ortho_scale => pub fn ortho_scale(&self, py: Python) -> Result<f32> }
+    bind_python! { self.ortho_scale = pub fn set_ortho_scale(&mut self, py: Python, value: f32) }
+    bind_python! { self.sensor_fit => pub fn sensor_fit(&self, py: Python) -> Result<CameraSensorFit> }
+    bind_python! { self.sensor_fit = pub fn set_sensor_fit(&mut self, py: Python, value: CameraSensorFit) }
+    bind_python! { self.sensor_height => pub fn sensor_height(&self, py: Python) -> Result<f32> }
+    bind_python! { self.sensor_height = pub fn set_sensor_height(&mut self, py: Python, value: f32) }
+    bind_python! { self.sensor_width => pub fn sensor_width(&self, py: Python) -> Result<f32> }
+    bind_python! { self.sensor_width = pub fn set_sensor_width(&mut self, py: Python, value: f32) }
+    bind_python! { self.shift_x => pub fn shift_x(&self, py: Python) -> Result<f32> }
+    bind_python! { self.shift_x = pub fn set_shift_x(&mut self, py: Python, value: f32) }
+    bind_python! { self.shift_y => pub fn shift_y(&self, py: Python) -> Result<f32> }
+    bind_python! { self.shift_y = pub fn set_shift_y(&mut self, py: Python, value: f32) }
+    bind_python! { self.type => pub fn camera_type(&self, py: Python) -> Result<CameraType> }
+    bind_python! { self.type = pub fn set_camera_type(&mut self, py: Python, value: CameraType) }
+}
+
+/// Intrinsics and extrinsics of camera objects.
+///
+/// Both follow the computer vision convention, i.e. the camera looks along its `+Z` axis with
+/// `+X` pointing right and `+Y` pointing down in the image, and pixel coordinates have their origin
+/// in the top-left corner of the rendered image.
+impl Object {
+    /// Returns the 3x3 intrinsic matrix `K` of the camera for the render resolution of `scene`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object is not a perspective camera.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn camera_intrinsic_matrix(&self, py: Python, scene: &Scene) -> Result<Matrix3<f64>> {
+        Self::check_perspective_camera(py, self)?;
+        let (width, height, pixel_aspect_x, pixel_aspect_y) = render_resolution(py, scene)?;
+
+        let depsgraph = bpy::context::evaluated_depsgraph_get(py)?;
+        let projection = self.calc_matrix_camera(
+            py,
+            &depsgraph,
+            width as i32,
+            height as i32,
+            pixel_aspect_x as f32,
+            pixel_aspect_y as f32,
+        )?;
+        let projection = projection.map(|row| row.map(f64::from));
+
+        Ok(Matrix3::new(
+            0.5 * width * projection[0][0],
+            0.0,
+            0.5 * width * (1.0 - projection[0][2]),
+            0.0,
+            0.5 * height * projection[1][1],
+            0.5 * height * (1.0 + projection[1][2]),
+            0.0,
+            0.0,
+            1.0,
+        ))
+    }
+
+    /// Adjusts the camera and the pixel aspect ratio of `scene` to match the intrinsic matrix `k`
+    /// for the render resolution of `scene`. The skew of `k` is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object is not a perspective camera or if `k` is not valid.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_camera_intrinsic_matrix(
+        &mut self,
+        py: Python,
+        scene: &mut Scene,
+        k: &Matrix3<f64>,
+    ) -> Result<()> {
+        let mut camera = Self::check_perspective_camera(py, self)?;
+        let (fx, fy, cx, cy) = (k[(0, 0)], k[(1, 1)], k[(0, 2)], k[(1, 2)]);
+        if fx <= 0.0 || fy <= 0.0 {
+            return Err(BlError::ValueError(format!(
+                "Focal lengths of the intrinsic matrix must be positive (fx: {fx}, fy: {fy})"
+            )));
+        }
+
+        let render = scene.render(py)?;
+        let (width, height, _, _) = render_resolution(py, scene)?;
+        let pixel_aspect_ratio = fx / fy;
+        let (pixel_aspect_x, pixel_aspect_y) = if pixel_aspect_ratio >= 1.0 {
+            (1.0, pixel_aspect_ratio)
+        } else {
+            (1.0 / pixel_aspect_ratio, 1.0)
+        };
+        render.setattr(intern!(py, "pixel_aspect_x"), pixel_aspect_x)?;
+        render.setattr(intern!(py, "pixel_aspect_y"), pixel_aspect_y)?;
+
+        camera.set_sensor_fit(py, CameraSensorFit::Horizontal)?;
+        let sensor_width = f64::from(camera.sensor_width(py)?);
+        camera.set_lens(py, (fx * sensor_width / width) as f32)?;
+        camera.set_shift_x(py, ((0.5 * width - cx) / width) as f32)?;
+        camera.set_shift_y(
+            py,
+            ((cy - 0.5 * height) * pixel_aspect_ratio / width) as f32,
+        )?;
+        Ok(())
+    }
+
+    /// Returns the 4x4 extrinsic matrix of the camera, i.e. the transformation from world
+    /// coordinates to camera coordinates.
+    pub fn camera_extrinsic_matrix(&self, py: Python) -> Result<Matrix4<f64>> {
+        let matrix_world: [[f32; 4]; 4] = self.matrix_world(py)?.extract()?;
+        let world_from_camera =
+            Matrix4::from_fn(|row, col| f64::from(matrix_world[row][col])) * blender_from_cv();
+        world_from_camera.try_inverse().ok_or_else(|| {
+            BlError::ValueError("World matrix of the camera is not invertible".to_string())
+        })
+    }
+
+    /// Places the camera according to the 4x4 `extrinsic` matrix, i.e. the transformation from
+    /// world coordinates to camera coordinates.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `extrinsic` is not invertible.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_camera_extrinsic_matrix(
+        &mut self,
+        py: Python,
+        extrinsic: &Matrix4<f64>,
+    ) -> Result<()> {
+        let world_from_camera = extrinsic.try_inverse().ok_or_else(|| {
+            BlError::ValueError("Extrinsic matrix of the camera is not invertible".to_string())
+        })?;
+        let matrix_world = world_from_camera * blender_from_cv();
+        let rows: [[f64; 4]; 4] =
+            std::array::from_fn(|row| std::array::from_fn(|col| matrix_world[(row, col)]));
+        self.setattr(py, intern!(py, "matrix_world"), rows)?;
+        Ok(())
+    }
+
+    /// Configures the camera from the intrinsic matrix `k` (see
+    /// [`Object::set_camera_intrinsic_matrix`]) and places it at the given `world_from_camera`
+    /// pose in the computer vision convention.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object is not a perspective camera or if the inputs are not valid.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_camera_from_intrinsics(
+        &mut self,
+        py: Python,
+        scene: &mut Scene,
+        k: &Matrix3<f64>,
+        world_from_camera: &Matrix4<f64>,
+    ) -> Result<()> {
+        self.set_camera_intrinsic_matrix(py, scene, k)?;
+        let extrinsic = world_from_camera.try_inverse().ok_or_else(|| {
+            BlError::ValueError("Pose of the camera is not invertible".to_string())
+        })?;
+        self.set_camera_extrinsic_matrix(py, &extrinsic)
+    }
+
+    fn check_perspective_camera(py: Python, object: &Self) -> Result<Camera> {
+        let camera: Camera = object.try_into()?;
+        match camera.camera_type(py)? {
+            CameraType::Perspective => Ok(camera),
+            camera_type => Err(BlError::ValueError(format!(
+                "Camera '{name}' of type '{camera_type}' has no intrinsic matrix (expected: '{}')",
+                CameraType::Perspective,
+                name = object.name(py)?,
+            ))),
+        }
+    }
+}
+
+/// Returns the effective render resolution and pixel aspect ratio of `scene`.
+fn render_resolution(py: Python, scene: &Scene) -> Result<(f64, f64, f64, f64)> {
+    let render = scene.render(py)?;
+    let percentage = render
+        .getattr(intern!(py, "resolution_percentage"))?
+        .extract::<f64>()?
+        / 100.0;
+    Ok((
+        (render
+            .getattr(intern!(py, "resolution_x"))?
+            .extract::<f64>()?
+            * percentage)
+            .floor(),
+        (render
+            .getattr(intern!(py, "resolution_y"))?
+            .extract::<f64>()?
+            * percentage)
+            .floor(),
+        render.getattr(intern!(py, "pixel_aspect_x"))?.extract()?,
+        render.getattr(intern!(py, "pixel_aspect_y"))?.extract()?,
+    ))
+}
+
+/// Rotation from the computer vision convention of camera axes to the one of Blender, where the
+/// camera looks along its `-Z` axis with `+Y` pointing up.
+fn blender_from_cv() -> Matrix4<f64> {
+    Matrix4::from_diagonal(&nalgebra::Vector4::new(1.0, -1.0, -1.0, 1.0))
 }
 
 impl From<pyo3::PyObject> for Camera {
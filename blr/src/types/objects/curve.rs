@@ -1,4 +1,7 @@
-use crate::types::{BpyID, Collection, Object};
+use crate::{
+    enums::DataBlockKind,
+    types::{AnimationData, BpyID, Collection, DataBlock, Key, Object},
+};
 use derive_more::{Deref, DerefMut, Display};
 use pyo3::{PyAny, PyObject};
 use pyo3_macros_more::bind_python;
@@ -9,12 +12,16 @@ use pyo3_macros_more::bind_python;
 pub struct Curve(PyObject);
 
 impl BpyID for Curve {}
+
+impl DataBlock for Curve {
+    const KIND: DataBlockKind = DataBlockKind::Curve;
+}
 impl CurveImpl for Curve {}
 
 pub trait CurveImpl:
     std::ops::Deref<Target = PyObject> + std::ops::DerefMut<Target = PyObject>
 {
-    bind_python! { self.animation_data => fn animation_data<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.animation_data => fn animation_data(&self, py: Python) -> Result<Option<AnimationData>> }
     bind_python! { self.bevel_depth => fn bevel_depth(&self, py: Python) -> Result<f32> }
     bind_python! { self.bevel_depth = fn set_bevel_depth(&mut self, py: Python, value: f32) }
     bind_python! { self.bevel_factor_end => fn bevel_factor_end(&self, py: Python) -> Result<f32> }
@@ -55,7 +62,7 @@ pub trait CurveImpl:
     bind_python! { self.resolution_u = fn set_resolution_u(&mut self, py: Python, value: u16) }
     bind_python! { self.resolution_v => fn resolution_v(&self, py: Python) -> Result<u16> }
     bind_python! { self.resolution_v = fn set_resolution_v(&mut self, py: Python, value: u16) }
-    bind_python! { self.shape_keys => fn shape_keys<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.shape_keys => fn shape_keys(&self, py: Python) -> Result<Option<Key>> }
     bind_python! { self.splines => fn splines(&self, py: Python) -> Result<Collection> }
     bind_python! { self.taper_object => fn taper_object(&self, py: Python) -> Result<Object> }
     bind_python! { self.taper_object = fn set_taper_object(&mut self, py: Python, value: Object) }
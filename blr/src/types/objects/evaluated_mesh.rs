@@ -0,0 +1,129 @@
+use crate::{
+    error::BlError,
+    result::Result,
+    types::{BpyID, Depsgraph, Mesh, Object},
+};
+use pyo3::Python;
+
+/// Mesh of an evaluated object, i.e. with all modifiers applied, that is obtained via
+/// <https://docs.blender.org/api/latest/bpy.types.Object.html#bpy.types.Object.to_mesh>.
+///
+/// The temporary mesh is owned by the evaluated object and it is released via
+/// <https://docs.blender.org/api/latest/bpy.types.Object.html#bpy.types.Object.to_mesh_clear>
+/// once the guard is dropped.
+#[derive(Debug)]
+pub struct EvaluatedMesh {
+    object: Object,
+    mesh: Mesh,
+}
+
+impl EvaluatedMesh {
+    /// Obtains the mesh of `object` as evaluated by `depsgraph`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object has no geometry (e.g., an empty or a camera).
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn new(py: Python, object: &Object, depsgraph: &Depsgraph) -> Result<Self> {
+        let object = depsgraph.evaluated(py, object)?;
+        let Some(mesh) = object.to_mesh(py)? else {
+            return Err(BlError::ValueError(format!(
+                "Object '{}' has no geometry that can be converted to a mesh",
+                object.name(py)?
+            )));
+        };
+        Ok(Self { object, mesh })
+    }
+
+    /// Returns the evaluated object that owns the mesh.
+    #[must_use]
+    pub fn object(&self) -> &Object {
+        &self.object
+    }
+
+    /// Returns the triangulated geometry of the mesh in world space.
+    pub fn triangle_mesh(&self, py: Python) -> Result<TriangleMesh> {
+        let matrix_world: [[f32; 4]; 4] = self.object.matrix_world(py)?.extract()?;
+        let mut triangle_mesh = TriangleMesh {
+            positions: self.mesh.vertex_positions(py)?,
+            normals: self.mesh.vertex_normal_vectors(py)?,
+            indices: self.mesh.loop_triangle_indices(py)?,
+        };
+        triangle_mesh.transform(&matrix_world);
+        Ok(triangle_mesh)
+    }
+}
+
+impl std::ops::Deref for EvaluatedMesh {
+    type Target = Mesh;
+
+    fn deref(&self) -> &Self::Target {
+        &self.mesh
+    }
+}
+
+impl Drop for EvaluatedMesh {
+    fn drop(&mut self) {
+        Python::with_gil(|py| {
+            if let Err(err) = self.object.to_mesh_clear(py) {
+                err.print(py);
+            }
+        });
+    }
+}
+
+/// Triangulated geometry with per-vertex positions and normals.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TriangleMesh {
+    pub positions: Vec<[f32; 3]>,
+    pub normals: Vec<[f32; 3]>,
+    /// Vertex indices of each triangle with counter-clockwise winding.
+    pub indices: Vec<[u32; 3]>,
+}
+
+impl TriangleMesh {
+    /// Transforms the geometry by the given row-major affine `matrix`.
+    ///
+    /// Normals are transformed by the inverse transpose of the linear part of `matrix` and
+    /// re-normalized, which keeps them perpendicular under non-uniform scaling. The winding of
+    /// triangles is flipped if `matrix` mirrors the geometry.
+    pub fn transform(&mut self, matrix: &[[f32; 4]; 4]) {
+        let linear = [
+            [matrix[0][0], matrix[0][1], matrix[0][2]],
+            [matrix[1][0], matrix[1][1], matrix[1][2]],
+            [matrix[2][0], matrix[2][1], matrix[2][2]],
+        ];
+        let translation = [matrix[0][3], matrix[1][3], matrix[2][3]];
+
+        // The cofactor matrix equals the inverse transpose scaled by the determinant
+        let cofactor: [[f32; 3]; 3] = std::array::from_fn(|row| {
+            std::array::from_fn(|col| {
+                let (r0, r1) = ((row + 1) % 3, (row + 2) % 3);
+                let (c0, c1) = ((col + 1) % 3, (col + 2) % 3);
+                linear[r0][c0] * linear[r1][c1] - linear[r0][c1] * linear[r1][c0]
+            })
+        });
+        let determinant: f32 = (0..3).map(|i| linear[0][i] * cofactor[0][i]).sum();
+
+        self.positions.iter_mut().for_each(|position| {
+            let transformed = mul_mat3_vec3(&linear, position);
+            *position = std::array::from_fn(|i| transformed[i] + translation[i]);
+        });
+        self.normals.iter_mut().for_each(|normal| {
+            let transformed = mul_mat3_vec3(&cofactor, normal);
+            let norm = transformed.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm > 0.0 {
+                *normal = transformed.map(|x| x * determinant.signum() / norm);
+            }
+        });
+        if determinant < 0.0 {
+            self.indices
+                .iter_mut()
+                .for_each(|triangle| triangle.swap(1, 2));
+        }
+    }
+}
+
+fn mul_mat3_vec3(matrix: &[[f32; 3]; 3], vector: &[f32; 3]) -> [f32; 3] {
+    std::array::from_fn(|row| (0..3).map(|col| matrix[row][col] * vector[col]).sum())
+}
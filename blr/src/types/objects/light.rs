@@ -1,6 +1,11 @@
-use crate::types::BpyID;
+use crate::{
+    bpy,
+    enums::{DataBlockKind, LightAreaShape, LightType},
+    result::Result,
+    types::{BpyID, CollectionImpl, DataBlock, Object},
+};
 use derive_more::{Deref, DerefMut, Display};
-use pyo3::PyObject;
+use pyo3::{PyObject, Python};
 use pyo3_macros_more::bind_python;
 
 /// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Light.html>
@@ -10,8 +15,101 @@ pub struct Light(PyObject);
 
 impl BpyID for Light {}
 
+impl DataBlock for Light {
+    const KIND: DataBlockKind = DataBlockKind::Light;
+}
+
+impl Light {
+    pub fn try_from_name(name: &str) -> Result<Self> {
+        Python::with_gil(|py| Ok(bpy::data::lights(py)?.get(py, name)?.into()))
+    }
+
+    pub fn try_from_active(py: Python) -> Result<Self> {
+        Object::from_active(py)?.try_into()
+    }
+
+    bind_python! { self.color => pub fn color(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.color = pub fn set_color(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.cutoff_distance => pub fn cutoff_distance(&self, py: Python) -> Result<f32> }
+    bind_python! { self.cutoff_distance = pub fn set_cutoff_distance(&mut self, py: Python, value: f32) }
+    bind_python! { self.diffuse_factor => pub fn diffuse_factor(&self, py: Python) -> Result<f32> }
+    bind_python! { self.diffuse_factor = pub fn set_diffuse_factor(&mut self, py: Python, value: f32) }
+    bind_python! { self.energy => pub fn energy(&self, py: Python) -> Result<f32> }
+    bind_python! { self.energy = pub fn set_energy(&mut self, py: Python, value: f32) }
+    bind_python! { self.shadow_soft_size => pub fn shadow_soft_size(&self, py: Python) -> Result<f32> }
+    bind_python! { self.shadow_soft_size = pub fn set_shadow_soft_size(&mut self, py: Python, value: f32) }
+    bind_python! { self.specular_factor => pub fn specular_factor(&self, py: Python) -> Result<f32> }
+    bind_python! { self.specular_factor = pub fn set_specular_factor(&mut self, py: Python, value: f32) }
+    bind_python! { self.type => pub fn light_type(&self, py: Python) -> Result<LightType> }
+    bind_python! { self.type = pub fn set_light_type(&mut self, py: Python, value: LightType) }
+    bind_python! { self.use_custom_distance => pub fn use_custom_distance(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_custom_distance = pub fn set_use_custom_distance(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_shadow => pub fn use_shadow(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_shadow = pub fn set_use_shadow(&mut self, py: Python, value: bool) }
+    bind_python! { self.volume_factor => pub fn volume_factor(&self, py: Python) -> Result<f32> }
+    bind_python! { self.volume_factor = pub fn set_volume_factor(&mut self, py: Python, value: f32) }
+}
+
+/// Settings that are only used by EEVEE.
+impl Light {
+    bind_python! { self.contact_shadow_bias => pub fn contact_shadow_bias(&self, py: Python) -> Result<f32> }
+    bind_python! { self.contact_shadow_bias = pub fn set_contact_shadow_bias(&mut self, py: Python, value: f32) }
+    bind_python! { self.contact_shadow_distance => pub fn contact_shadow_distance(&self, py: Python) -> Result<f32> }
+    bind_python! { self.contact_shadow_distance = pub fn set_contact_shadow_distance(&mut self, py: Python, value: f32) }
+    bind_python! { self.contact_shadow_thickness => pub fn contact_shadow_thickness(&self, py: Python) -> Result<f32> }
+    bind_python! { self.contact_shadow_thickness = pub fn set_contact_shadow_thickness(&mut self, py: Python, value: f32) }
+    bind_python! { self.shadow_buffer_bias => pub fn shadow_buffer_bias(&self, py: Python) -> Result<f32> }
+    bind_python! { self.shadow_buffer_bias = pub fn set_shadow_buffer_bias(&mut self, py: Python, value: f32) }
+    bind_python! { self.shadow_cascade_count => pub fn shadow_cascade_count(&self, py: Python) -> Result<u32> }
+    bind_python! { self.shadow_cascade_count = pub fn set_shadow_cascade_count(&mut self, py: Python, value: u32) }
+    bind_python! { self.shadow_cascade_exponent => pub fn shadow_cascade_exponent(&self, py: Python) -> Result<f32> }
+    bind_python! { self.shadow_cascade_exponent = pub fn set_shadow_cascade_exponent(&mut self, py: Python, value: f32) }
+    bind_python! { self.shadow_cascade_fade => pub fn shadow_cascade_fade(&self, py: Python) -> Result<f32> }
+    bind_python! { self.shadow_cascade_fade = pub fn set_shadow_cascade_fade(&mut self, py: Python, value: f32) }
+    bind_python! { self.shadow_cascade_max_distance => pub fn shadow_cascade_max_distance(&self, py: Python) -> Result<f32> }
+    bind_python! { self.shadow_cascade_max_distance = pub fn set_shadow_cascade_max_distance(&mut self, py: Python, value: f32) }
+    bind_python! { self.use_contact_shadow => pub fn use_contact_shadow(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_contact_shadow = pub fn set_use_contact_shadow(&mut self, py: Python, value: bool) }
+}
+
+/// Settings that are only used by Cycles (stored in `Light.cycles`).
+impl Light {
+    bind_python! { self.cycles.cast_shadow => pub fn cycles_cast_shadow(&self, py: Python) -> Result<bool> }
+    bind_python! { self.cycles.cast_shadow = pub fn set_cycles_cast_shadow(&mut self, py: Python, value: bool) }
+    bind_python! { self.cycles.is_caustics_light => pub fn cycles_is_caustics_light(&self, py: Python) -> Result<bool> }
+    bind_python! { self.cycles.is_caustics_light = pub fn set_cycles_is_caustics_light(&mut self, py: Python, value: bool) }
+    bind_python! { self.cycles.is_portal => pub fn cycles_is_portal(&self, py: Python) -> Result<bool> }
+    bind_python! { self.cycles.is_portal = pub fn set_cycles_is_portal(&mut self, py: Python, value: bool) }
+    bind_python! { self.cycles.max_bounces => pub fn cycles_max_bounces(&self, py: Python) -> Result<u32> }
+    bind_python! { self.cycles.max_bounces = pub fn set_cycles_max_bounces(&mut self, py: Python, value: u32) }
+    bind_python! { self.cycles.use_multiple_importance_sampling => pub fn cycles_use_multiple_importance_sampling(&self, py: Python) -> Result<bool> }
+    bind_python! { self.cycles.use_multiple_importance_sampling = pub fn set_cycles_use_multiple_importance_sampling(&mut self, py: Python, value: bool) }
+}
+
+/// Settings of <https://docs.blender.org/api/latest/bpy.types.SpotLight.html>.
+impl Light {
+    bind_python! { self.show_cone => pub fn show_cone(&self, py: Python) -> Result<bool> }
+    bind_python! { self.show_cone = pub fn set_show_cone(&mut self, py: Python, value: bool) }
+    bind_python! { self.spot_blend => pub fn spot_blend(&self, py: Python) -> Result<f32> }
+    bind_python! { self.spot_blend = pub fn set_spot_blend(&mut self, py: Python, value: f32) }
+    bind_python! { self.spot_size => pub fn spot_size(&self, py: Python) -> Result<f32> }
+    bind_python! { self.spot_size = pub fn set_spot_size(&mut self, py: Python, value: f32) }
+}
+
+/// Settings of <https://docs.blender.org/api/latest/bpy.types.AreaLight.html>.
+impl Light {
+    bind_python! { self.shape => pub fn area_shape(&self, py: Python) -> Result<LightAreaShape> }
+    bind_python! { self.shape = pub fn set_area_shape(&mut self, py: Python, value: LightAreaShape) }
+    bind_python! { self.size => pub fn area_size(&self, py: Python) -> Result<f32> }
+    bind_python! { self.size = pub fn set_area_size(&mut self, py: Python, value: f32) }
+    bind_python! { self.size_y => pub fn area_size_y(&self, py: Python) -> Result<f32> }
+    bind_python! { self.size_y = pub fn set_area_size_y(&mut self, py: Python, value: f32) }
+}
+
+/// Settings of <https://docs.blender.org/api/latest/bpy.types.SunLight.html>.
 impl Light {
-    bind_python! {}
+    bind_python! { self.angle => pub fn sun_angle(&self, py: Python) -> Result<f32> }
+    bind_python! { self.angle = pub fn set_sun_angle(&mut self, py: Python, value: f32) }
 }
 
 impl From<pyo3::PyObject> for Light {
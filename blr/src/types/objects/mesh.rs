@@ -1,12 +1,18 @@
 use crate::{
     bpy,
-    enums::{AxisXYZ, MeshSelectMode, ObjectMode, ObjectType, TransformOrientation},
+    enums::{
+        AttributeDomain, AxisXYZ, DataBlockKind, MeshSelectMode, ObjectMode, ObjectType,
+        TransformOrientation,
+    },
     error::BlError,
     result::Result,
-    types::{BpyID, Collection, CollectionImpl, IdMaterials, Object},
+    types::{
+        collections::ForeachValue, AnimationData, Attributes, BpyID, Collection, CollectionImpl,
+        DataBlock, IdMaterials, Key, Object,
+    },
 };
 use derive_more::{Deref, DerefMut, Display};
-use pyo3::{PyAny, PyObject, Python, ToPyObject};
+use pyo3::{intern, PyAny, PyObject, Python};
 use pyo3_macros_more::bind_python;
 
 /// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Mesh.html>
@@ -16,6 +22,10 @@ pub struct Mesh(PyObject);
 
 impl BpyID for Mesh {}
 
+impl DataBlock for Mesh {
+    const KIND: DataBlockKind = DataBlockKind::Mesh;
+}
+
 impl Mesh {
     pub fn try_from_name(name: &str) -> Result<Self> {
         Python::with_gil(|py| Ok(bpy::data::meshes(py)?.get(py, name)?.into()))
@@ -171,28 +181,124 @@ impl Mesh {
         )?)
     }
 
-    pub fn get_output_attribute_data(&self, py: Python, key: &str) -> Result<PyObject> {
-        let parent_object = self.find_parent_object(py)?.unwrap();
-
-        let evaluated_object: Object = parent_object
-            .evaluated_get(
-                py,
-                bpy::context::evaluated_depsgraph_get(py)
-                    .unwrap()
-                    .call0()
-                    .unwrap(),
-            )?
+    /// Returns the values of attribute `key` after evaluating the modifiers of the object that
+    /// uses the mesh, e.g. an attribute that is output by a geometry nodes modifier.
+    ///
+    /// See [`Attribute::data`](crate::types::Attribute::data) for the supported value types.
+    pub fn get_output_attribute_data<T: ForeachValue>(
+        &self,
+        py: Python,
+        key: &str,
+    ) -> Result<Vec<T>> {
+        let Some(parent_object) = self.find_parent_object(py)? else {
+            return Err(BlError::ValueError(format!(
+                "Mesh '{mesh}' is not used by any object",
+                mesh = self.name(py)?
+            )));
+        };
+
+        let depsgraph = bpy::context::evaluated_depsgraph_get(py)?;
+        let evaluated_mesh: Mesh = depsgraph.evaluated(py, &parent_object)?.try_into()?;
+
+        evaluated_mesh.attributes(py)?.get_data(py, key)
+    }
+
+    /// Returns the positions of all vertices.
+    pub fn vertex_positions(&self, py: Python) -> Result<Vec<[f32; 3]>> {
+        self.vertices(py)?.foreach_get(py, "co")
+    }
+
+    /// Overwrites the positions of all vertices. The number of `positions` must match the number
+    /// of vertices of the mesh.
+    pub fn set_vertex_positions(&mut self, py: Python, positions: &[[f32; 3]]) -> Result<()> {
+        let vertices = self.vertices(py)?;
+        let n_vertices = vertices.len(py)?;
+        if positions.len() != n_vertices {
+            return Err(BlError::ValueError(format!(
+                "Number of positions does not match the number of vertices (expected: {n_vertices}, actual: {})",
+                positions.len()
+            )));
+        }
+        vertices.foreach_set(py, "co", positions)?;
+        Ok(self.update(py, false, false)?)
+    }
+
+    /// Returns the vertex indices of all polygons.
+    pub fn polygon_vertex_indices(&self, py: Python) -> Result<Vec<Vec<u32>>> {
+        let polygons = self.polygons(py)?;
+        let loop_starts: Vec<i32> = polygons.foreach_get(py, "loop_start")?;
+        let loop_totals: Vec<i32> = polygons.foreach_get(py, "loop_total")?;
+        let loop_vertices: Vec<i32> = self.loops(py)?.foreach_get(py, "vertex_index")?;
+
+        Ok(loop_starts
+            .into_iter()
+            .zip(loop_totals)
+            .map(|(start, total)| {
+                loop_vertices[start as usize..(start + total) as usize]
+                    .iter()
+                    .map(|&index| index as u32)
+                    .collect()
+            })
+            .collect())
+    }
+
+    /// Returns the vertex indices of all triangles of the tessellated mesh.
+    pub fn loop_triangle_indices(&self, py: Python) -> Result<Vec<[u32; 3]>> {
+        self.calc_loop_triangles(py)?;
+        Ok(self
+            .loop_triangles(py)?
+            .foreach_get::<[i32; 3]>(py, "vertices")?
+            .into_iter()
+            .map(|triangle| triangle.map(|index| index as u32))
+            .collect())
+    }
+
+    /// Returns the normals of all vertices.
+    pub fn vertex_normal_vectors(&self, py: Python) -> Result<Vec<[f32; 3]>> {
+        self.vertex_normals(py)?.foreach_get(py, "vector")
+    }
+
+    /// Returns the UV coordinates of all loops (face corners) for UV map `name`.
+    pub fn uv_layer_data(&self, py: Python, name: &str) -> Result<Vec<[f32; 2]>> {
+        let uv_layers = self.uv_layers(py)?;
+        let data: Collection = uv_layers
+            .get(py, name)?
+            .getattr(intern!(py, "data"))?
             .extract()?;
-        let evaluated_mesh: Mesh = evaluated_object.try_into()?;
+        data.foreach_get(py, "uv")
+    }
 
-        let attributes = evaluated_mesh.attributes(py)?;
-        let attribute_data: Collection = attributes.get(py, key)?.getattr("data")?.extract()?;
-        let vector_data = attribute_data.values(py)?[0].getattr("vector")?;
-        Ok(vector_data.to_object(py))
+    /// Returns the data of attribute `name` that is stored on the given `domain`.
+    ///
+    /// See [`Attribute::data`](crate::types::Attribute::data) for the supported value types.
+    pub fn attribute_data<T: ForeachValue>(
+        &self,
+        py: Python,
+        name: &str,
+        domain: AttributeDomain,
+    ) -> Result<Vec<T>> {
+        self.attributes(py)?
+            .get_on_domain(py, name, domain)?
+            .data(py)
+    }
+
+    /// Overwrites the data of attribute `name` that is stored on the given `domain`.
+    ///
+    /// See [`Attribute::data`](crate::types::Attribute::data) for the supported value types.
+    pub fn set_attribute_data<T: ForeachValue>(
+        &mut self,
+        py: Python,
+        name: &str,
+        domain: AttributeDomain,
+        values: &[T],
+    ) -> Result<()> {
+        self.attributes(py)?
+            .get_on_domain(py, name, domain)?
+            .set_data(py, values)
     }
 
-    bind_python! { self.animation_data => pub fn animation_data<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
-    bind_python! { self.attributes => pub fn attributes(&self, py: Python) -> Result<Collection> }
+    bind_python! { self.animation_data => pub fn animation_data(&self, py: Python) -> Result<Option<AnimationData>> }
+    bind_python! { self.attributes => pub fn attributes(&self, py: Python) -> Result<Attributes> }
     bind_python! { self.auto_smooth_angle => pub fn auto_smooth_angle(&self, py: Python) -> Result<f32> }
     bind_python! { self.auto_smooth_angle = pub fn set_auto_smooth_angle(&mut self, py: Python, value: f32) }
     bind_python! { self.auto_texspace => pub fn auto_texspace(&self, py: Python) -> Result<bool> }
@@ -225,7 +331,7 @@ impl Mesh {
     bind_python! { self.remesh_voxel_size => pub fn remesh_voxel_size(&self, py: Python) -> Result<f32> }
     bind_python! { self.remesh_voxel_size = pub fn set_remesh_voxel_size(&mut self, py: Python, value: f32) }
     bind_python! { self.sculpt_vertex_colors => pub fn sculpt_vertex_colors(&self, py: Python) -> Result<Collection> }
-    bind_python! { self.shape_keys => pub fn shape_keys<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.shape_keys => pub fn shape_keys(&self, py: Python) -> Result<Option<Key>> }
     bind_python! { self.skin_vertices => pub fn skin_vertices<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
     bind_python! { self.texco_mesh => pub fn texco_mesh(&self, py: Python) -> Result<Self> }
     bind_python! { self.texco_mesh = pub fn set_texco_mesh(&mut self, py: Python, value: &Self) }
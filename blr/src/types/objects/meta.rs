@@ -1,4 +1,7 @@
-use crate::types::BpyID;
+use crate::{
+    enums::DataBlockKind,
+    types::{BpyID, DataBlock},
+};
 use derive_more::{Deref, DerefMut, Display};
 use pyo3::PyObject;
 use pyo3_macros_more::bind_python;
@@ -10,6 +13,10 @@ pub struct MetaBall(PyObject);
 
 impl BpyID for MetaBall {}
 
+impl DataBlock for MetaBall {
+    const KIND: DataBlockKind = DataBlockKind::MetaBall;
+}
+
 impl MetaBall {
     bind_python! {}
 }
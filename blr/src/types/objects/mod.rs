@@ -5,6 +5,7 @@ pub mod camera;
 pub mod curve;
 pub mod curves;
 pub mod empty;
+pub mod evaluated_mesh;
 pub mod grease_pencil;
 pub mod lattice;
 pub mod light;
@@ -24,6 +25,7 @@ pub use camera::Camera;
 pub use curve::{Curve, CurveImpl};
 pub use curves::Curves;
 pub use empty::Empty;
+pub use evaluated_mesh::{EvaluatedMesh, TriangleMesh};
 pub use grease_pencil::GreasePencil;
 pub use lattice::Lattice;
 pub use light::Light;
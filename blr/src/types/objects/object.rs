@@ -1,21 +1,25 @@
 use crate::{
     bpy,
     enums::{
-        Alignment, ContextMode, LightType, ObjectMode, ObjectType, OriginCenter, OriginType,
-        RenderVariant,
+        Alignment, ContextMode, DataBlockKind, LightType, ObjectMode, ObjectType, OriginCenter,
+        OriginType, RenderVariant, RigidBodyConstraintType, RigidBodyType,
     },
+    error::BlError,
     result::Result,
     scene::{Scene, SpaceView3D, ViewLayer},
     types::{
-        collections::ObjectModifiers, BpyID, Collection, CollectionImpl, Curve, Empty, Material,
-        Mesh,
+        collections::{ObjectConstraints, ObjectModifiers, PoseBones, VertexGroups},
+        AnimationData, Armature, BoneSpec, BpyID, CollectionImpl, Curve, DataBlock, Depsgraph,
+        EditModeGuard, Empty, EvaluatedMesh, Light, Material, Mesh, PoseBone, RigidBodyConstraint,
+        RigidBodyObject, SceneCollection, SelectionGuard, ShapeKey, TempOverride, TriangleMesh,
     },
 };
 use derive_more::{Deref, DerefMut, Display};
 // TODO: Remove nalgebra dependency
 use nalgebra::Quaternion;
-use pyo3::{intern, PyAny, PyObject, Python};
+use pyo3::{exceptions::PyKeyError, intern, types::PyDict, PyAny, PyObject, Python, ToPyObject};
 use pyo3_macros_more::bind_python;
+use std::collections::HashMap;
 
 /// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Object.html>
 #[repr(transparent)]
@@ -24,6 +28,10 @@ pub struct Object(PyObject);
 
 impl BpyID for Object {}
 
+impl DataBlock for Object {
+    const KIND: DataBlockKind = DataBlockKind::Object;
+}
+
 impl Object {
     pub fn new_camera(py: Python, location: [f32; 3], rotation: [f32; 3]) -> Result<Self> {
         Self::force_object_mode(py)?;
@@ -142,6 +150,27 @@ impl Object {
         Ok(objects.remove(py, &self, true, true, true)?)
     }
 
+    /// Links the object to `collection` and unlinks it from all other collections.
+    pub fn move_to_collection(&self, py: Python, collection: &SceneCollection) -> Result<()> {
+        let mut others = Vec::new();
+        let mut is_linked = false;
+        for user in self.users_collection(py)? {
+            if user.as_ref(py).eq(collection.as_ref(py))? {
+                is_linked = true;
+            } else {
+                others.push(user);
+            }
+        }
+        // Linked before unlinking, so that the object is never orphaned if linking fails.
+        if !is_linked {
+            collection.objects(py)?.link(py, self)?;
+        }
+        for other in others {
+            other.objects(py)?.unlink(py, self)?;
+        }
+        Ok(())
+    }
+
     pub fn object_type(&self, py: Python) -> Result<ObjectType> {
         Ok(self.getattr(py, intern!(py, "type"))?.extract(py)?)
     }
@@ -198,6 +227,31 @@ impl Object {
         Ok(bpy::ops::object::origin_set(py, r#type, center)?)
     }
 
+    /// Returns the light data of the object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object is not a light.
+    pub fn light_data(&self) -> Result<Light> {
+        self.try_into()
+    }
+
+    /// Returns the mesh of the object with all modifiers applied, as evaluated by `depsgraph`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object has no geometry (e.g., an empty or a camera).
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn evaluated_mesh(&self, py: Python, depsgraph: &Depsgraph) -> Result<EvaluatedMesh> {
+        EvaluatedMesh::new(py, self, depsgraph)
+    }
+
+    /// Returns the triangulated world-space geometry of the object with all modifiers applied.
+    pub fn evaluated_geometry(&self, py: Python) -> Result<TriangleMesh> {
+        let depsgraph = bpy::context::evaluated_depsgraph_get(py)?;
+        self.evaluated_mesh(py, &depsgraph)?.triangle_mesh(py)
+    }
+
     pub(crate) fn force_object_mode(py: Python) -> Result<()> {
         let obj = Self::from_active(py)?;
         if !obj.is_none(py) {
@@ -210,18 +264,18 @@ impl Object {
     bind_python! { self.active_material = pub fn set_active_material(&mut self, py: Python, value: &Material) }
     bind_python! { self.active_material_index => pub fn active_material_index(&self, py: Python) -> Result<usize> }
     bind_python! { self.active_material_index = pub fn set_active_material_index(&mut self, py: Python, value: usize) }
-    bind_python! { self.active_shape_key => pub fn active_shape_key<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.active_shape_key => pub fn active_shape_key(&self, py: Python) -> Result<Option<ShapeKey>> }
     bind_python! { self.active_shape_key_index => pub fn active_shape_key_index(&self, py: Python) -> Result<i16> }
     bind_python! { self.active_shape_key_index = pub fn set_active_shape_key_index(&mut self, py: Python, value: i16) }
     bind_python! { self.add_rest_position_attribute => pub fn add_rest_position_attribute(&self, py: Python) -> Result<bool> }
     bind_python! { self.add_rest_position_attribute = pub fn set_add_rest_position_attribute(&mut self, py: Python, value: bool) }
-    bind_python! { self.animation_data => pub fn animation_data<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.animation_data => pub fn animation_data(&self, py: Python) -> Result<Option<AnimationData>> }
     bind_python! { self.animation_visualization => pub fn animation_visualization<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
     bind_python! { self.bound_box => pub fn bound_box<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
     bind_python! { self.collision => pub fn collision<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
     bind_python! { self.color => pub fn color(&self, py: Python) -> Result<[f32; 4]> }
     bind_python! { self.color = pub fn set_color(&mut self, py: Python, value: [f32; 4]) }
-    bind_python! { self.constraints => pub fn constraints<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.constraints => pub fn constraints(&self, py: Python) -> Result<ObjectConstraints> }
     bind_python! { self.cycles => pub fn cycles<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
     bind_python! { self.data => pub fn data<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
     bind_python! { self.data = pub fn set_data(&mut self, py: Python, value: &PyAny) }
@@ -260,8 +314,8 @@ impl Object {
     bind_python! { self.hide_viewport => pub fn hide_viewport(&self, py: Python) -> Result<bool> }
     bind_python! { self.hide_viewport = pub fn set_hide_viewport(&mut self, py: Python, value: bool) }
     bind_python! { self.image_user => pub fn image_user<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
-    bind_python! { self.instance_collection => pub fn instance_collection<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
-    bind_python! { self.instance_collection = pub fn set_instance_collection(&mut self, py: Python, value: &PyAny) }
+    bind_python! { self.instance_collection => pub fn instance_collection(&self, py: Python) -> Result<Option<SceneCollection>> }
+    bind_python! { self.instance_collection = pub fn set_instance_collection(&mut self, py: Python, value: Option<&SceneCollection>) }
     bind_python! { self.instance_faces_scale => pub fn instance_faces_scale(&self, py: Python) -> Result<f32> }
     bind_python! { self.instance_faces_scale = pub fn set_instance_faces_scale(&mut self, py: Python, value: f32) }
     bind_python! { self.instance_type => pub fn instance_type(&self, py: Python) -> Result<String> }
@@ -312,8 +366,8 @@ impl Object {
     bind_python! { self.pass_index => pub fn pass_index(&self, py: Python) -> Result<i16> }
     bind_python! { self.pass_index = pub fn set_pass_index(&mut self, py: Python, value: i16) }
     bind_python! { self.pose => pub fn pose<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
-    bind_python! { self.rigid_body => pub fn rigid_body<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
-    bind_python! { self.rigid_body_constraint => pub fn rigid_body_constraint<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.rigid_body => pub fn rigid_body(&self, py: Python) -> Result<Option<RigidBodyObject>> }
+    bind_python! { self.rigid_body_constraint => pub fn rigid_body_constraint(&self, py: Python) -> Result<Option<RigidBodyConstraint>> }
     bind_python! { self.rotation_axis_angle => pub fn rotation_axis_angle(&self, py: Python) -> Result<[f32; 4]> }
     bind_python! { self.rotation_axis_angle = pub fn set_rotation_axis_angle(&mut self, py: Python, value: [f32; 4]) }
     bind_python! { self.rotation_euler => pub fn rotation_euler(&self, py: Python) -> Result<[f32; 3]> }
@@ -378,7 +432,7 @@ impl Object {
     bind_python! { self.use_shape_key_edit_mode = pub fn set_use_shape_key_edit_mode(&mut self, py: Python, value: bool) }
     bind_python! { self.use_simulation_cache => pub fn use_simulation_cache(&self, py: Python) -> Result<bool> }
     bind_python! { self.use_simulation_cache = pub fn set_use_simulation_cache(&mut self, py: Python, value: bool) }
-    bind_python! { self.vertex_groups => pub fn vertex_groups<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.vertex_groups => pub fn vertex_groups(&self, py: Python) -> Result<VertexGroups> }
     bind_python! { self.visible_camera => pub fn visible_camera(&self, py: Python) -> Result<bool> }
     bind_python! { self.visible_camera = pub fn set_visible_camera(&mut self, py: Python, value: bool) }
     bind_python! { self.visible_diffuse => pub fn visible_diffuse(&self, py: Python) -> Result<bool> }
@@ -393,8 +447,8 @@ impl Object {
     bind_python! { self.visible_volume_scatter = pub fn set_visible_volume_scatter(&mut self, py: Python, value: bool) }
     bind_python! { self.children => pub fn children(&self, py: Python) -> Result<Vec<Self>> }
     bind_python! { self.children_recursive => pub fn children_recursive(&self, py: Python) -> Result<Vec<Self>> }
-    bind_python! { self.users_collection => pub fn users_collection(&self, py: Python) -> Result<Vec<Collection>> }
-    bind_python! { self.users_scene => pub fn users_scene<'py>(&'py self, py: Python<'py>) -> Result<Vec<Scene<'py>>> }
+    bind_python! { self.users_collection => pub fn users_collection(&self, py: Python) -> Result<Vec<SceneCollection>> }
+    bind_python! { self.users_scene => pub fn users_scene(&self, py: Python) -> Result<Vec<Scene>> }
     bind_python! { self.select_get() => pub fn select_get(&self, py: Python, view_layer: Option<ViewLayer>) -> Result<bool> }
     bind_python! { self.select_set() => pub fn select_set(&self, py: Python, state: bool, view_layer: Option<ViewLayer>) }
     bind_python! { self.hide_get() => pub fn hide_get(&self, py: Python, view_layer: Option<ViewLayer>) -> Result<bool> }
@@ -406,29 +460,234 @@ impl Object {
     bind_python! { self.local_view_set() => pub fn local_view_set(&self, py: Python, viewport: SpaceView3D, state: bool) }
     bind_python! { self.visible_in_viewport_get() => pub fn visible_in_viewport_get(&self, py: Python, viewport: SpaceView3D) -> Result<bool> }
     // bind_python! { self.convert_space() => pub fn convert_space(&self, py: Python, pose_bone=None, matrix=((0.0, 0.0, 0.0, 0.0), (0.0, 0.0, 0.0, 0.0), (0.0, 0.0, 0.0, 0.0), (0.0, 0.0, 0.0, 0.0)), from_space='WORLD', to_space='WORLD') -> Result<&PyAny> }
-    // bind_python! { self.calc_matrix_camera() => pub fn calc_matrix_camera(&self, py: Python, x=1, y=1, scale_x=1.0, scale_y=1.0) -> Result<&PyAny> }
+    bind_python! { self.calc_matrix_camera() => pub fn calc_matrix_camera(&self, py: Python, depsgraph: &Depsgraph, x: i32, y: i32, scale_x: f32, scale_y: f32) -> Result<[[f32; 4]; 4]> }
     // bind_python! { self.camera_fit_coords() => pub fn camera_fit_coords(&self, py: Python, coordinates) -> Result<&PyAny> }
     // bind_python! { self.crazyspace_eval() => pub fn crazyspace_eval(&self, py: Python, scene) -> Result<&PyAny> }
     // bind_python! { self.crazyspace_displacement_to_deformed() => pub fn crazyspace_displacement_to_deformed(&self, py: Python, vertex_index=0, displacement=(0.0, 0.0, 0.0)) -> Result<&PyAny> }
     // bind_python! { self.crazyspace_displacement_to_original() => pub fn crazyspace_displacement_to_original(&self, py: Python, vertex_index=0, displacement=(0.0, 0.0, 0.0)) -> Result<&PyAny> }
     // bind_python! { self.crazyspace_eval_clear() => pub fn crazyspace_eval_clear(&self, py: Python) -> Result<&PyAny> }
-    bind_python! { self.to_mesh() => pub fn to_mesh(&self, py: Python) -> Result<Mesh> }
+    bind_python! { self.to_mesh() => pub fn to_mesh(&self, py: Python) -> Result<Option<Mesh>> }
     bind_python! { self.to_mesh_clear() => pub fn to_mesh_clear(&self, py: Python) }
     bind_python! { self.to_curve() => pub fn to_curve(&self, py: Python, apply_modifiers: bool) -> Result<Curve> }
     bind_python! { self.to_curve_clear() => pub fn to_curve_clear(&self, py: Python) }
     bind_python! { self.find_armature() => pub fn find_armature(&self, py: Python) -> Result<Self> }
-    // bind_python! { self.shape_key_add() => pub fn shape_key_add(&self, py: Python, name='Key', from_mix: bool) -> Result<&PyAny> }
-    // bind_python! { self.shape_key_remove() => pub fn shape_key_remove(&self, py: Python, key) }
-    // bind_python! { self.shape_key_clear() => pub fn shape_key_clear(&self, py: Python) }
+    bind_python! { self.shape_key_add() => pub fn shape_key_add(&self, py: Python, name: &str, from_mix: bool) -> Result<ShapeKey> }
+    bind_python! { self.shape_key_remove() => pub fn shape_key_remove(&self, py: Python, key: &ShapeKey) }
+    bind_python! { self.shape_key_clear() => pub fn shape_key_clear(&self, py: Python) }
     bind_python! { self.ray_cast() => pub fn ray_cast(&self, py: Python, origin: [f32; 3], direction: [f32; 3], distance: f32) -> Result<(bool, [f32; 3], [f32; 3], i32)> }
     bind_python! { self.closest_point_on_mesh() => pub fn closest_point_on_mesh(&self, py: Python, origin: [f32; 3], distance: f32) -> Result<(bool, [f32; 3], [f32; 3], i32)> }
-    bind_python! { self.is_modified() => pub fn is_modified(&self, py: Python, scene: Scene, settings: RenderVariant) -> Result<bool> }
-    bind_python! { self.is_deform_modified() => pub fn is_deform_modified(&self, py: Python, scene: Scene, settings: RenderVariant) -> Result<bool> }
+    bind_python! { self.is_modified() => pub fn is_modified(&self, py: Python, scene: &Scene, settings: RenderVariant) -> Result<bool> }
+    bind_python! { self.is_deform_modified() => pub fn is_deform_modified(&self, py: Python, scene: &Scene, settings: RenderVariant) -> Result<bool> }
     bind_python! { self.update_from_editmode() => pub fn update_from_editmode(&self, py: Python) -> Result<bool> }
     bind_python! { self.cache_release() => pub fn cache_release(&self, py: Python) }
     bind_python! { self.generate_gpencil_strokes() => pub fn generate_gpencil_strokes(&self, py: Python, grease_pencil_object: &Self, use_collections: bool, scale_thickness: f32, sample: f32) -> Result<bool> }
 }
 
+/// Armature objects, e.g., to pose robot arms.
+impl Object {
+    /// Creates an armature object with the given `bones` (instead of the default bone), whose
+    /// joint axes are applied to the pose bones (see [`Object::apply_joint_axes`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the bones cannot be added (see [`Armature::add_bones`]), in which case
+    /// the armature object is removed again.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn new_armature(
+        py: Python,
+        location: [f32; 3],
+        rotation: [f32; 3],
+        bones: &[BoneSpec],
+    ) -> Result<Self> {
+        Self::force_object_mode(py)?;
+        bpy::ops::object::armature_add(
+            py,
+            1.0,
+            false,
+            Alignment::World,
+            location,
+            rotation,
+            [1.0, 1.0, 1.0],
+        )?;
+        let object: Self = bpy::context::active_object(py)?;
+        let armature = object.armature_data()?;
+        if let Err(err) = object.build_armature(py, &armature, bones) {
+            bpy::data::objects(py)?.remove(py, &object, true, true, true)?;
+            bpy::data::armatures(py)?
+                .as_ref(py)
+                .call_method1(intern!(py, "remove"), (armature.as_ref(py),))?;
+            return Err(err);
+        }
+        Ok(object)
+    }
+
+    fn build_armature(&self, py: Python, armature: &Armature, bones: &[BoneSpec]) -> Result<()> {
+        let edit_mode = EditModeGuard::new(py, self)?;
+        armature.edit_bones(py)?.clear(py)?;
+        let result = armature.add_bones(py, bones);
+        drop(edit_mode);
+        result?;
+        self.apply_joint_axes(py, bones)
+    }
+
+    /// Locks the rotation axes of the pose bones of the given `bones` that declare a joint axis
+    /// (see [`BoneSpec::joint_axis`]), e.g., after adding them via [`Armature::add_bones`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the armature is in edit mode or a bone does not exist.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn apply_joint_axes(&self, py: Python, bones: &[BoneSpec]) -> Result<()> {
+        let pose_bones = self.pose_bones(py)?;
+        for spec in bones {
+            if let Some(axis) = spec.joint_axis {
+                self.pose_bone(py, &pose_bones, &spec.name)?
+                    .set_joint_axis(py, axis)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the armature data of the object.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object is not an armature.
+    pub fn armature_data(&self) -> Result<Armature> {
+        self.try_into()
+    }
+
+    /// Sets the joint angles (in radians) of the pose bones with the given names, see
+    /// [`PoseBone::set_joint_angle`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a bone does not exist or cannot be used as a joint.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_joint_angles(&self, py: Python, angles: &HashMap<String, f32>) -> Result<()> {
+        let pose_bones = self.pose_bones(py)?;
+        let mut names: Vec<&String> = angles.keys().collect();
+        names.sort();
+        // All joints are resolved first, so that no angle is set if any of them is invalid.
+        let joints = names
+            .into_iter()
+            .map(|name| {
+                let bone = self.pose_bone(py, &pose_bones, name)?;
+                let axis = bone.joint_axis(py)?;
+                Ok((bone, axis, angles[name]))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        for (mut bone, axis, angle) in joints {
+            bone.set_angle_about_axis(py, axis, angle)?;
+        }
+        Ok(())
+    }
+
+    fn pose_bone(&self, py: Python, pose_bones: &PoseBones, name: &str) -> Result<PoseBone> {
+        match pose_bones.get(py, name) {
+            Ok(bone) => Ok(bone),
+            Err(err) if err.is_instance_of::<PyKeyError>(py) => Err(BlError::ValueError(format!(
+                "Armature '{}' has no pose bone '{name}'",
+                self.name(py)?
+            ))),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Parents `meshes` to this armature object with automatic weights, i.e., each mesh gets an
+    /// Armature modifier and a vertex group per deforming bone.
+    pub fn parent_with_automatic_weights(&self, py: Python, meshes: &[Object]) -> Result<()> {
+        Self::force_object_mode(py)?;
+        let selection = SelectionGuard::new(py)?;
+        let objects: Vec<Object> = std::iter::once(self.clone())
+            .chain(meshes.iter().cloned())
+            .collect();
+        selection.select_only(py, &objects)?;
+        Ok(bpy::ops::object::parent_set(
+            py,
+            "ARMATURE_AUTO",
+            false,
+            false,
+        )?)
+    }
+
+    bind_python! { self.pose.bones => pub fn pose_bones(&self, py: Python) -> Result<PoseBones> }
+}
+
+/// Vertex groups, e.g., to assign skinning weights.
+impl Object {
+    /// Normalizes the weights of all vertex groups such that the weights of each vertex sum up
+    /// to one. The weights of the active group are kept if `lock_active` is set.
+    pub fn normalize_vertex_groups(&self, py: Python, lock_active: bool) -> Result<()> {
+        let _temp_override = self.temp_override(py)?;
+        Ok(bpy::ops::object::vertex_group_normalize_all(
+            py,
+            "ALL",
+            lock_active,
+        )?)
+    }
+
+    /// Removes the smallest weights of each vertex such that it is assigned to at most `limit`
+    /// vertex groups.
+    pub fn limit_vertex_groups_total(&self, py: Python, limit: u32) -> Result<()> {
+        let _temp_override = self.temp_override(py)?;
+        Ok(bpy::ops::object::vertex_group_limit_total(
+            py, "ALL", limit,
+        )?)
+    }
+
+    fn temp_override<'py>(&self, py: Python<'py>) -> Result<TempOverride<'py>> {
+        let overrides = PyDict::new(py);
+        overrides.set_item(intern!(py, "object"), self.to_object(py))?;
+        overrides.set_item(intern!(py, "active_object"), self.to_object(py))?;
+        TempOverride::new(py, overrides)
+    }
+}
+
+/// Rigid bodies, e.g., to drop objects onto surfaces.
+impl Object {
+    /// Adds the object to the rigid-body world of the scene (which is created if necessary).
+    pub fn rigid_body_add(&self, py: Python, r#type: RigidBodyType) -> Result<RigidBodyObject> {
+        let _temp_override = self.temp_override(py)?;
+        bpy::ops::rigidbody::object_add(py, r#type)?;
+        self.rigid_body(py)?.ok_or_else(|| {
+            BlError::ValueError(format!(
+                "Object '{}' cannot be added to the rigid-body world",
+                self.name(py).unwrap_or_default()
+            ))
+        })
+    }
+
+    /// Removes the object from the rigid-body world.
+    pub fn rigid_body_remove(&self, py: Python) -> Result<()> {
+        let _temp_override = self.temp_override(py)?;
+        Ok(bpy::ops::rigidbody::object_remove(py)?)
+    }
+
+    /// Adds a rigid-body constraint to the object, whose constrained objects are set with
+    /// [`RigidBodyConstraint::set_object1`] and [`RigidBodyConstraint::set_object2`].
+    pub fn rigid_body_constraint_add(
+        &self,
+        py: Python,
+        r#type: RigidBodyConstraintType,
+    ) -> Result<RigidBodyConstraint> {
+        let _temp_override = self.temp_override(py)?;
+        bpy::ops::rigidbody::constraint_add(py, r#type)?;
+        self.rigid_body_constraint(py)?.ok_or_else(|| {
+            BlError::ValueError(format!(
+                "Object '{}' cannot be added as rigid-body constraint",
+                self.name(py).unwrap_or_default()
+            ))
+        })
+    }
+
+    /// Removes the rigid-body constraint of the object.
+    pub fn rigid_body_constraint_remove(&self, py: Python) -> Result<()> {
+        let _temp_override = self.temp_override(py)?;
+        Ok(bpy::ops::rigidbody::constraint_remove(py)?)
+    }
+}
+
 impl From<pyo3::PyObject> for Object {
     fn from(value: pyo3::PyObject) -> Self {
         Self(value)
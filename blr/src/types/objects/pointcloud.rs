@@ -1,4 +1,7 @@
-use crate::types::BpyID;
+use crate::{
+    enums::DataBlockKind,
+    types::{BpyID, DataBlock},
+};
 use derive_more::{Deref, DerefMut, Display};
 use pyo3::PyObject;
 use pyo3_macros_more::bind_python;
@@ -10,6 +13,10 @@ pub struct PointCloud(PyObject);
 
 impl BpyID for PointCloud {}
 
+impl DataBlock for PointCloud {
+    const KIND: DataBlockKind = DataBlockKind::PointCloud;
+}
+
 impl PointCloud {
     bind_python! {}
 }
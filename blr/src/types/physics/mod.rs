@@ -0,0 +1,7 @@
+pub mod rigid_body_constraint;
+pub mod rigid_body_object;
+pub mod rigid_body_world;
+
+pub use rigid_body_constraint::RigidBodyConstraint;
+pub use rigid_body_object::RigidBodyObject;
+pub use rigid_body_world::RigidBodyWorld;
@@ -0,0 +1,73 @@
+use crate::{
+    enums::RigidBodyConstraintType,
+    types::{BpyStruct, Object},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.RigidBodyConstraint.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct RigidBodyConstraint(PyObject);
+
+impl BpyStruct for RigidBodyConstraint {}
+
+impl RigidBodyConstraint {
+    bind_python! { self.breaking_threshold => pub fn breaking_threshold(&self, py: Python) -> Result<f32> }
+    bind_python! { self.breaking_threshold = pub fn set_breaking_threshold(&mut self, py: Python, value: f32) }
+    bind_python! { self.disable_collisions => pub fn disable_collisions(&self, py: Python) -> Result<bool> }
+    bind_python! { self.disable_collisions = pub fn set_disable_collisions(&mut self, py: Python, value: bool) }
+    bind_python! { self.enabled => pub fn enabled(&self, py: Python) -> Result<bool> }
+    bind_python! { self.enabled = pub fn set_enabled(&mut self, py: Python, value: bool) }
+    bind_python! { self.limit_ang_z_lower => pub fn limit_ang_z_lower(&self, py: Python) -> Result<f32> }
+    bind_python! { self.limit_ang_z_lower = pub fn set_limit_ang_z_lower(&mut self, py: Python, value: f32) }
+    bind_python! { self.limit_ang_z_upper => pub fn limit_ang_z_upper(&self, py: Python) -> Result<f32> }
+    bind_python! { self.limit_ang_z_upper = pub fn set_limit_ang_z_upper(&mut self, py: Python, value: f32) }
+    bind_python! { self.limit_lin_x_lower => pub fn limit_lin_x_lower(&self, py: Python) -> Result<f32> }
+    bind_python! { self.limit_lin_x_lower = pub fn set_limit_lin_x_lower(&mut self, py: Python, value: f32) }
+    bind_python! { self.limit_lin_x_upper => pub fn limit_lin_x_upper(&self, py: Python) -> Result<f32> }
+    bind_python! { self.limit_lin_x_upper = pub fn set_limit_lin_x_upper(&mut self, py: Python, value: f32) }
+    bind_python! { self.motor_ang_max_impulse => pub fn motor_ang_max_impulse(&self, py: Python) -> Result<f32> }
+    bind_python! { self.motor_ang_max_impulse = pub fn set_motor_ang_max_impulse(&mut self, py: Python, value: f32) }
+    bind_python! { self.motor_ang_target_velocity => pub fn motor_ang_target_velocity(&self, py: Python) -> Result<f32> }
+    bind_python! { self.motor_ang_target_velocity = pub fn set_motor_ang_target_velocity(&mut self, py: Python, value: f32) }
+    bind_python! { self.object1 => pub fn object1(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.object1 = pub fn set_object1(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.object2 => pub fn object2(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.object2 = pub fn set_object2(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.type => pub fn constraint_type(&self, py: Python) -> Result<RigidBodyConstraintType> }
+    bind_python! { self.type = pub fn set_constraint_type(&mut self, py: Python, value: RigidBodyConstraintType) }
+    bind_python! { self.use_breaking => pub fn use_breaking(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_breaking = pub fn set_use_breaking(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_limit_ang_z => pub fn use_limit_ang_z(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_limit_ang_z = pub fn set_use_limit_ang_z(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_limit_lin_x => pub fn use_limit_lin_x(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_limit_lin_x = pub fn set_use_limit_lin_x(&mut self, py: Python, value: bool) }
+    bind_python! { self.use_motor_ang => pub fn use_motor_ang(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_motor_ang = pub fn set_use_motor_ang(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for RigidBodyConstraint {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for RigidBodyConstraint {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for RigidBodyConstraint {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for RigidBodyConstraint {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,63 @@
+use crate::{
+    enums::{RigidBodyCollisionShape, RigidBodyType},
+    types::BpyStruct,
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.RigidBodyObject.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct RigidBodyObject(PyObject);
+
+impl BpyStruct for RigidBodyObject {}
+
+impl RigidBodyObject {
+    bind_python! { self.angular_damping => pub fn angular_damping(&self, py: Python) -> Result<f32> }
+    bind_python! { self.angular_damping = pub fn set_angular_damping(&mut self, py: Python, value: f32) }
+    bind_python! { self.collision_margin => pub fn collision_margin(&self, py: Python) -> Result<f32> }
+    bind_python! { self.collision_margin = pub fn set_collision_margin(&mut self, py: Python, value: f32) }
+    bind_python! { self.collision_shape => pub fn collision_shape(&self, py: Python) -> Result<RigidBodyCollisionShape> }
+    bind_python! { self.collision_shape = pub fn set_collision_shape(&mut self, py: Python, value: RigidBodyCollisionShape) }
+    bind_python! { self.enabled => pub fn enabled(&self, py: Python) -> Result<bool> }
+    bind_python! { self.enabled = pub fn set_enabled(&mut self, py: Python, value: bool) }
+    bind_python! { self.friction => pub fn friction(&self, py: Python) -> Result<f32> }
+    bind_python! { self.friction = pub fn set_friction(&mut self, py: Python, value: f32) }
+    bind_python! { self.kinematic => pub fn kinematic(&self, py: Python) -> Result<bool> }
+    bind_python! { self.kinematic = pub fn set_kinematic(&mut self, py: Python, value: bool) }
+    bind_python! { self.linear_damping => pub fn linear_damping(&self, py: Python) -> Result<f32> }
+    bind_python! { self.linear_damping = pub fn set_linear_damping(&mut self, py: Python, value: f32) }
+    bind_python! { self.mass => pub fn mass(&self, py: Python) -> Result<f32> }
+    bind_python! { self.mass = pub fn set_mass(&mut self, py: Python, value: f32) }
+    bind_python! { self.restitution => pub fn restitution(&self, py: Python) -> Result<f32> }
+    bind_python! { self.restitution = pub fn set_restitution(&mut self, py: Python, value: f32) }
+    bind_python! { self.type => pub fn rigid_body_type(&self, py: Python) -> Result<RigidBodyType> }
+    bind_python! { self.type = pub fn set_rigid_body_type(&mut self, py: Python, value: RigidBodyType) }
+    bind_python! { self.use_margin => pub fn use_margin(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_margin = pub fn set_use_margin(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for RigidBodyObject {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for RigidBodyObject {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for RigidBodyObject {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for RigidBodyObject {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,58 @@
+use crate::types::{BpyStruct, SceneCollection};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.RigidBodyWorld.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct RigidBodyWorld(PyObject);
+
+impl BpyStruct for RigidBodyWorld {}
+
+impl RigidBodyWorld {
+    bind_python! { self.collection => pub fn collection(&self, py: Python) -> Result<Option<SceneCollection>> }
+    bind_python! { self.collection = pub fn set_collection(&mut self, py: Python, value: Option<&SceneCollection>) }
+    bind_python! { self.constraints => pub fn constraints(&self, py: Python) -> Result<Option<SceneCollection>> }
+    bind_python! { self.constraints = pub fn set_constraints(&mut self, py: Python, value: Option<&SceneCollection>) }
+    bind_python! { self.enabled => pub fn enabled(&self, py: Python) -> Result<bool> }
+    bind_python! { self.enabled = pub fn set_enabled(&mut self, py: Python, value: bool) }
+    bind_python! { self.point_cache.frame_end => pub fn frame_end(&self, py: Python) -> Result<i32> }
+    bind_python! { self.point_cache.frame_end = pub fn set_frame_end(&mut self, py: Python, value: i32) }
+    bind_python! { self.point_cache.frame_start => pub fn frame_start(&self, py: Python) -> Result<i32> }
+    bind_python! { self.point_cache.frame_start = pub fn set_frame_start(&mut self, py: Python, value: i32) }
+    bind_python! { self.id_data.gravity => pub fn gravity(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.id_data.gravity = pub fn set_gravity(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.solver_iterations => pub fn solver_iterations(&self, py: Python) -> Result<u32> }
+    bind_python! { self.solver_iterations = pub fn set_solver_iterations(&mut self, py: Python, value: u32) }
+    bind_python! { self.substeps_per_frame => pub fn substeps_per_frame(&self, py: Python) -> Result<u32> }
+    bind_python! { self.substeps_per_frame = pub fn set_substeps_per_frame(&mut self, py: Python, value: u32) }
+    bind_python! { self.time_scale => pub fn time_scale(&self, py: Python) -> Result<f32> }
+    bind_python! { self.time_scale = pub fn set_time_scale(&mut self, py: Python, value: f32) }
+    bind_python! { self.use_split_impulse => pub fn use_split_impulse(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_split_impulse = pub fn set_use_split_impulse(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for RigidBodyWorld {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for RigidBodyWorld {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for RigidBodyWorld {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for RigidBodyWorld {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -1,9 +1,16 @@
+#[allow(clippy::module_inception)]
+pub mod scene;
+pub mod scene_collection;
 pub mod view_layer;
+pub mod world;
 
+pub use scene::Scene;
+pub use scene_collection::SceneCollection;
 pub use view_layer::ViewLayer;
+pub use world::{
+    EnvironmentTexture, EnvironmentTextureBuilder, SkyTexture, SkyTextureBuilder, World,
+};
 
-pub type Scene<'py> = &'py pyo3::PyAny;
 pub type Screen<'py> = &'py pyo3::PyAny;
 pub type SpaceView3D<'py> = &'py pyo3::PyAny;
 pub type Window<'py> = &'py pyo3::PyAny;
-pub type World<'py> = &'py pyo3::PyAny;
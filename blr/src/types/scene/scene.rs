@@ -0,0 +1,231 @@
+use crate::{
+    bpy,
+    enums::DataBlockKind,
+    error::BlError,
+    result::Result,
+    types::{
+        BpyID, Collection, DataBlock, Object, RigidBodyWorld, SceneCollection, SelectionGuard,
+        TempOverride, World,
+    },
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, types::PyDict, PyAny, PyObject, Python, ToPyObject};
+use pyo3_macros_more::bind_python;
+use std::collections::HashMap;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Scene.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Scene(PyObject);
+
+impl BpyID for Scene {}
+
+impl DataBlock for Scene {
+    const KIND: DataBlockKind = DataBlockKind::Scene;
+}
+
+impl Scene {
+    bind_python! { self.frame_start => pub fn frame_start(&self, py: Python) -> Result<i32> }
+    bind_python! { self.frame_start = pub fn set_frame_start(&mut self, py: Python, value: i32) }
+    bind_python! { self.frame_end => pub fn frame_end(&self, py: Python) -> Result<i32> }
+    bind_python! { self.frame_end = pub fn set_frame_end(&mut self, py: Python, value: i32) }
+    bind_python! { self.frame_step => pub fn frame_step(&self, py: Python) -> Result<i32> }
+    bind_python! { self.frame_step = pub fn set_frame_step(&mut self, py: Python, value: i32) }
+    bind_python! { self.frame_current => pub fn frame_current(&self, py: Python) -> Result<i32> }
+    bind_python! { self.frame_current = pub fn set_frame_current(&mut self, py: Python, value: i32) }
+    bind_python! { self.frame_current_final => pub fn frame_current_final(&self, py: Python) -> Result<f32> }
+    bind_python! { self.frame_float => pub fn frame_float(&self, py: Python) -> Result<f32> }
+    bind_python! { self.frame_float = pub fn set_frame_float(&mut self, py: Python, value: f32) }
+    bind_python! { self.render.fps => pub fn fps(&self, py: Python) -> Result<i32> }
+    bind_python! { self.render.fps = pub fn set_fps(&mut self, py: Python, value: i32) }
+    bind_python! { self.render.fps_base => pub fn fps_base(&self, py: Python) -> Result<f32> }
+    bind_python! { self.render.fps_base = pub fn set_fps_base(&mut self, py: Python, value: f32) }
+    bind_python! { self.camera => pub fn camera(&self, py: Python) -> Result<Option<Object>> }
+    bind_python! { self.camera = pub fn set_camera(&mut self, py: Python, value: Option<&Object>) }
+    bind_python! { self.world => pub fn world(&self, py: Python) -> Result<Option<World>> }
+    bind_python! { self.world = pub fn set_world(&mut self, py: Python, value: Option<&World>) }
+    bind_python! { self.collection => pub fn collection(&self, py: Python) -> Result<SceneCollection> }
+    bind_python! { self.objects => pub fn objects(&self, py: Python) -> Result<Collection> }
+    bind_python! { self.view_layers => pub fn view_layers(&self, py: Python) -> Result<Collection> }
+    bind_python! { self.render => pub fn render<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.unit_settings => pub fn unit_settings<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.unit_settings.system => pub fn unit_system(&self, py: Python) -> Result<String> }
+    bind_python! { self.unit_settings.system = pub fn set_unit_system(&mut self, py: Python, value: &str) }
+    bind_python! { self.unit_settings.length_unit => pub fn unit_length(&self, py: Python) -> Result<String> }
+    bind_python! { self.unit_settings.length_unit = pub fn set_unit_length(&mut self, py: Python, value: &str) }
+    bind_python! { self.unit_settings.scale_length => pub fn unit_scale_length(&self, py: Python) -> Result<f32> }
+    bind_python! { self.unit_settings.scale_length = pub fn set_unit_scale_length(&mut self, py: Python, value: f32) }
+    bind_python! { self.cursor => pub fn cursor<'py>(&'py self, py: Python<'py>) -> Result<&'py PyAny> }
+    bind_python! { self.cursor.location => pub fn cursor_location(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.cursor.location = pub fn set_cursor_location(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.cursor.rotation_euler => pub fn cursor_rotation_euler(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.cursor.rotation_euler = pub fn set_cursor_rotation_euler(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.use_gravity => pub fn use_gravity(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_gravity = pub fn set_use_gravity(&mut self, py: Python, value: bool) }
+    bind_python! { self.gravity => pub fn gravity(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.gravity = pub fn set_gravity(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.frame_set() => pub fn frame_set(&self, py: Python, frame: i32, subframe: f32) }
+    bind_python! { self.rigidbody_world => pub fn rigidbody_world(&self, py: Python) -> Result<Option<RigidBodyWorld>> }
+}
+
+/// Rigid-body physics, e.g., to drop objects onto surfaces.
+impl Scene {
+    /// Returns the rigid-body world of the scene, which is created if it does not exist yet.
+    pub fn rigid_body_world_ensure(&self, py: Python) -> Result<RigidBodyWorld> {
+        if let Some(world) = self.rigidbody_world(py)? {
+            return Ok(world);
+        }
+        let overrides = PyDict::new(py);
+        overrides.set_item(intern!(py, "scene"), self.to_object(py))?;
+        let temp_override = TempOverride::new(py, overrides)?;
+        bpy::ops::rigidbody::world_add(py)?;
+        drop(temp_override);
+        self.rigidbody_world(py)?.ok_or_else(|| {
+            BlError::ValueError(format!(
+                "Scene '{}' cannot have a rigid-body world",
+                self.name(py).unwrap_or_default()
+            ))
+        })
+    }
+
+    /// Returns all objects that are simulated as rigid bodies.
+    pub fn rigid_body_objects(&self, py: Python) -> Result<Vec<Object>> {
+        match self.rigidbody_world(py)? {
+            Some(world) => match world.collection(py)? {
+                Some(collection) => Ok(collection.all_objects(py)?),
+                None => Ok(Vec::new()),
+            },
+            None => Ok(Vec::new()),
+        }
+    }
+
+    /// Steps the simulation through `frames` and returns the world matrix of each rigid-body
+    /// object (by name) at each frame. The current frame is restored afterwards.
+    ///
+    /// Blender only simulates a frame if its predecessor is cached, so all frames in between are
+    /// stepped through as well, starting at [`RigidBodyWorld::frame_start`] (or at the previous
+    /// frame of `frames` if it is not later). Thus, ascending `frames` are the most efficient.
+    pub fn simulate_rigid_bodies(
+        &self,
+        py: Python,
+        frames: impl IntoIterator<Item = i32>,
+    ) -> Result<HashMap<String, Vec<[[f32; 4]; 4]>>> {
+        let objects = self.rigid_body_objects(py)?;
+        let original_frame = self.frame_current(py)?;
+        let frame_start = self.rigid_body_frame_start(py)?;
+
+        let mut poses: HashMap<String, Vec<[[f32; 4]; 4]>> = HashMap::new();
+        let mut previous = None;
+        let result = frames.into_iter().try_for_each(|frame| -> Result<()> {
+            let from = match previous {
+                Some(previous) if previous <= frame => previous + 1,
+                _ => frame_start,
+            };
+            self.step_rigid_bodies(py, from, frame)?;
+            previous = Some(frame);
+            for object in &objects {
+                poses
+                    .entry(object.name(py)?)
+                    .or_default()
+                    .push(object.matrix_world(py)?.extract()?);
+            }
+            Ok(())
+        });
+
+        self.frame_set(py, original_frame, 0.0)?;
+        result.map(|()| poses)
+    }
+
+    /// Bakes the simulation of all rigid-body objects into keyframes of their transforms (every
+    /// `step` frames) and removes them from the rigid-body world.
+    pub fn bake_rigid_bodies_to_keyframes(
+        &self,
+        py: Python,
+        frame_start: i32,
+        frame_end: i32,
+        step: i32,
+    ) -> Result<()> {
+        let objects = self.rigid_body_objects(py)?;
+        if objects.is_empty() {
+            return Ok(());
+        }
+        let selection = SelectionGuard::new(py)?;
+        selection.select_only(py, &objects)?;
+        Ok(bpy::ops::rigidbody::bake_to_keyframes(
+            py,
+            frame_start,
+            frame_end,
+            step,
+        )?)
+    }
+
+    /// Applies the simulated transforms of all rigid-body objects at `frame` (e.g., the resting
+    /// poses of dropped objects) and removes them from the rigid-body world, so they keep their
+    /// poses independently of the current frame. The current frame is restored afterwards.
+    ///
+    /// The simulation is stepped through all frames from [`RigidBodyWorld::frame_start`] up to
+    /// `frame`, since Blender only simulates a frame if its predecessor is cached.
+    pub fn apply_rigid_body_transforms(&self, py: Python, frame: i32) -> Result<()> {
+        let objects = self.rigid_body_objects(py)?;
+        if objects.is_empty() {
+            return Ok(());
+        }
+        let original_frame = self.frame_current(py)?;
+        let frame_start = self.rigid_body_frame_start(py)?;
+        if let Err(err) = self.step_rigid_bodies(py, frame_start, frame) {
+            self.frame_set(py, original_frame, 0.0)?;
+            return Err(err);
+        }
+
+        let selection = SelectionGuard::new(py)?;
+        let result = selection
+            .select_only(py, &objects)
+            .and_then(|()| Ok(bpy::ops::object::visual_transform_apply(py)?))
+            .and_then(|()| Ok(bpy::ops::rigidbody::objects_remove(py)?));
+        drop(selection);
+
+        self.frame_set(py, original_frame, 0.0)?;
+        result
+    }
+
+    /// Returns the first simulated frame of the rigid-body world (or of the scene without one).
+    fn rigid_body_frame_start(&self, py: Python) -> Result<i32> {
+        match self.rigidbody_world(py)? {
+            Some(world) => Ok(world.frame_start(py)?),
+            None => Ok(self.frame_start(py)?),
+        }
+    }
+
+    /// Sets the frames from `from` up to `to` one after another, so that Blender simulates (and
+    /// caches) each frame of the rigid-body world based on its predecessor.
+    fn step_rigid_bodies(&self, py: Python, from: i32, to: i32) -> Result<()> {
+        for frame in from..to {
+            self.frame_set(py, frame, 0.0)?;
+        }
+        Ok(self.frame_set(py, to, 0.0)?)
+    }
+}
+
+impl From<pyo3::PyObject> for Scene {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Scene {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Scene {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Scene {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{
+    bpy,
+    enums::DataBlockKind,
+    result::Result,
+    types::{
+        collections::{CollectionChildren, CollectionObjects},
+        BpyID, DataBlock, Object,
+    },
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Collection.html>, i.e., a
+/// collection of the scene hierarchy (not to be confused with
+/// [`Collection`](crate::types::Collection), which wraps `bpy_prop_collection`).
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct SceneCollection(PyObject);
+
+impl BpyID for SceneCollection {}
+
+impl DataBlock for SceneCollection {
+    const KIND: DataBlockKind = DataBlockKind::Collection;
+}
+
+impl SceneCollection {
+    /// Creates a new collection that is not linked to any scene yet.
+    pub fn new(py: Python, name: &str) -> Result<Self> {
+        Ok(bpy::data::collections(py)?.new(py, name)?)
+    }
+
+    /// Returns the collection of the current scene, which is the root of the scene hierarchy.
+    pub fn from_scene(py: Python) -> Result<Self> {
+        Ok(bpy::context::scene(py)?.collection(py)?)
+    }
+
+    /// Creates a new collection and links it as a child of this collection.
+    pub fn new_child(&self, py: Python, name: &str) -> Result<Self> {
+        let child = Self::new(py, name)?;
+        self.children(py)?.link(py, &child)?;
+        Ok(child)
+    }
+
+    bind_python! { self.all_objects => pub fn all_objects(&self, py: Python) -> Result<Vec<Object>> }
+    bind_python! { self.children => pub fn children(&self, py: Python) -> Result<CollectionChildren> }
+    bind_python! { self.children_recursive => pub fn children_recursive(&self, py: Python) -> Result<Vec<SceneCollection>> }
+    bind_python! { self.color_tag => pub fn color_tag(&self, py: Python) -> Result<String> }
+    bind_python! { self.color_tag = pub fn set_color_tag(&mut self, py: Python, value: &str) }
+    bind_python! { self.hide_render => pub fn hide_render(&self, py: Python) -> Result<bool> }
+    bind_python! { self.hide_render = pub fn set_hide_render(&mut self, py: Python, value: bool) }
+    bind_python! { self.hide_select => pub fn hide_select(&self, py: Python) -> Result<bool> }
+    bind_python! { self.hide_select = pub fn set_hide_select(&mut self, py: Python, value: bool) }
+    bind_python! { self.hide_viewport => pub fn hide_viewport(&self, py: Python) -> Result<bool> }
+    bind_python! { self.hide_viewport = pub fn set_hide_viewport(&mut self, py: Python, value: bool) }
+    bind_python! { self.instance_offset => pub fn instance_offset(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.instance_offset = pub fn set_instance_offset(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.objects => pub fn objects(&self, py: Python) -> Result<CollectionObjects> }
+}
+
+impl From<pyo3::PyObject> for SceneCollection {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for SceneCollection {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for SceneCollection {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for SceneCollection {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
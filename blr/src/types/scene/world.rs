@@ -0,0 +1,250 @@
+use crate::{
+    bpy,
+    enums::DataBlockKind,
+    error::BlError,
+    result::Result,
+    types::{BpyID, DataBlock, Image, Node, NodeTree, Nodes, Scene},
+};
+use builder_derive_more::IntoBuilder;
+use derive_builder::Builder;
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, PyObject, Python, ToPyObject};
+use pyo3_macros_more::bind_python;
+use std::path::PathBuf;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.World.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct World(PyObject);
+
+impl BpyID for World {}
+
+impl DataBlock for World {
+    const KIND: DataBlockKind = DataBlockKind::World;
+}
+
+impl World {
+    /// Creates a new world data-block with the given `name`.
+    pub fn new(py: Python, name: &str) -> Result<Self> {
+        Ok(bpy::data::worlds(py)?
+            .call_method1(py, intern!(py, "new"), (name,))?
+            .into())
+    }
+
+    /// Assigns the world to `scene`, which is then lit by it.
+    pub fn assign_to(&self, py: Python, scene: &mut Scene) -> Result<()> {
+        Ok(scene.set_world(py, Some(self))?)
+    }
+
+    /// Replaces the node tree of the world with an Environment Texture (e.g., an HDRI).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the image cannot be loaded.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_environment_texture(
+        &mut self,
+        py: Python,
+        environment: &EnvironmentTexture,
+    ) -> Result<()> {
+        let image = Image::load(py, &environment.filepath)?;
+        let tree = self.reset_node_tree(py)?;
+        let mut nodes = tree.nodes(py)?;
+
+        let coordinates = new_node(py, &mut nodes, "ShaderNodeTexCoord", [-900.0, 300.0])?;
+        let mapping = new_node(py, &mut nodes, "ShaderNodeMapping", [-700.0, 300.0])?;
+        mapping
+            .input(py, "Rotation")?
+            .set_default_value(py, environment.rotation)?;
+        let texture = new_node(py, &mut nodes, "ShaderNodeTexEnvironment", [-450.0, 300.0])?;
+        texture.setattr(py, intern!(py, "image"), image.to_object(py))?;
+
+        let links = tree.links(py)?;
+        links.new(
+            py,
+            &coordinates.output(py, "Generated")?,
+            &mapping.input(py, "Vector")?,
+            true,
+        )?;
+        links.new(
+            py,
+            &mapping.output(py, "Vector")?,
+            &texture.input(py, "Vector")?,
+            true,
+        )?;
+        connect_background(py, &tree, &texture, environment.strength)
+    }
+
+    /// Replaces the node tree of the world with a Sky Texture.
+    ///
+    /// # Errors
+    ///
+    /// Exceptions from the Python API of Blender are propagated.
+    pub fn set_sky_texture(&mut self, py: Python, sky: &SkyTexture) -> Result<()> {
+        let tree = self.reset_node_tree(py)?;
+        let mut nodes = tree.nodes(py)?;
+
+        let texture = new_node(py, &mut nodes, "ShaderNodeTexSky", [-450.0, 300.0])?;
+        let texture_ref = texture.as_ref(py);
+        texture_ref.setattr(intern!(py, "sky_type"), "NISHITA")?;
+        texture_ref.setattr(intern!(py, "sun_disc"), sky.sun_disc)?;
+        texture_ref.setattr(intern!(py, "sun_size"), sky.sun_size)?;
+        texture_ref.setattr(intern!(py, "sun_intensity"), sky.sun_intensity)?;
+        texture_ref.setattr(intern!(py, "sun_elevation"), sky.sun_elevation)?;
+        texture_ref.setattr(intern!(py, "sun_rotation"), sky.sun_rotation)?;
+        texture_ref.setattr(intern!(py, "altitude"), sky.altitude)?;
+        texture_ref.setattr(intern!(py, "air_density"), sky.air_density)?;
+        texture_ref.setattr(intern!(py, "dust_density"), sky.dust_density)?;
+        texture_ref.setattr(intern!(py, "ozone_density"), sky.ozone_density)?;
+        connect_background(py, &tree, &texture, sky.strength)
+    }
+
+    /// Enables nodes and removes all existing nodes from the node tree of the world.
+    fn reset_node_tree(&mut self, py: Python) -> Result<NodeTree> {
+        self.set_use_nodes(py, true)?;
+        let Some(tree) = self.node_tree(py)? else {
+            return Err(BlError::ValueError(format!(
+                "World '{}' has no node tree",
+                self.name(py)?
+            )));
+        };
+        tree.nodes(py)?.clear(py)?;
+        Ok(tree)
+    }
+
+    bind_python! { self.color => pub fn color(&self, py: Python) -> Result<[f32; 3]> }
+    bind_python! { self.color = pub fn set_color(&mut self, py: Python, value: [f32; 3]) }
+    bind_python! { self.node_tree => pub fn node_tree(&self, py: Python) -> Result<Option<NodeTree>> }
+    bind_python! { self.use_nodes => pub fn use_nodes(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_nodes = pub fn set_use_nodes(&mut self, py: Python, value: bool) }
+}
+
+/// Environment lighting from an image, see
+/// <https://docs.blender.org/api/latest/bpy.types.ShaderNodeTexEnvironment.html>.
+#[derive(Debug, Clone, Builder, IntoBuilder)]
+#[builder(default, build_fn(private, name = "fallible_build", error = "BlError"))]
+pub struct EnvironmentTexture {
+    /// Path of the (HDR) image, which is reused if it is already loaded.
+    #[builder(setter(into))]
+    pub filepath: PathBuf,
+    /// Euler rotation of the environment in radians.
+    pub rotation: [f32; 3],
+    pub strength: f32,
+}
+
+impl EnvironmentTextureBuilder {
+    #[must_use]
+    pub fn build(&self) -> EnvironmentTexture {
+        self.fallible_build().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl Default for EnvironmentTexture {
+    fn default() -> Self {
+        Self {
+            filepath: PathBuf::new(),
+            rotation: [0.0, 0.0, 0.0],
+            strength: 1.0,
+        }
+    }
+}
+
+/// Procedural environment lighting from the Nishita sky model, see
+/// <https://docs.blender.org/api/latest/bpy.types.ShaderNodeTexSky.html>.
+#[derive(Debug, Clone, Builder, IntoBuilder)]
+#[builder(default, build_fn(private, name = "fallible_build", error = "BlError"))]
+pub struct SkyTexture {
+    /// Angle between the sun and the horizon in radians.
+    pub sun_elevation: f32,
+    /// Rotation of the sun around the zenith in radians.
+    pub sun_rotation: f32,
+    /// Angular diameter of the sun disc in radians.
+    pub sun_size: f32,
+    pub sun_intensity: f32,
+    pub sun_disc: bool,
+    /// Height from the sea level in meters.
+    pub altitude: f32,
+    pub air_density: f32,
+    pub dust_density: f32,
+    pub ozone_density: f32,
+    pub strength: f32,
+}
+
+impl SkyTextureBuilder {
+    #[must_use]
+    pub fn build(&self) -> SkyTexture {
+        self.fallible_build().unwrap_or_else(|_| unreachable!())
+    }
+}
+
+impl Default for SkyTexture {
+    fn default() -> Self {
+        Self {
+            sun_elevation: 15.0_f32.to_radians(),
+            sun_rotation: 0.0,
+            sun_size: 0.545_f32.to_radians(),
+            sun_intensity: 1.0,
+            sun_disc: true,
+            altitude: 0.0,
+            air_density: 1.0,
+            dust_density: 1.0,
+            ozone_density: 1.0,
+            strength: 1.0,
+        }
+    }
+}
+
+/// Connects the color output of `texture` to the world output via a Background shader.
+fn connect_background(py: Python, tree: &NodeTree, texture: &Node, strength: f32) -> Result<()> {
+    let mut nodes = tree.nodes(py)?;
+    let background = new_node(py, &mut nodes, "ShaderNodeBackground", [-150.0, 300.0])?;
+    background
+        .input(py, "Strength")?
+        .set_default_value(py, strength)?;
+    let world_output = new_node(py, &mut nodes, "ShaderNodeOutputWorld", [100.0, 300.0])?;
+
+    let links = tree.links(py)?;
+    links.new(
+        py,
+        &texture.output(py, "Color")?,
+        &background.input(py, "Color")?,
+        true,
+    )?;
+    links.new(
+        py,
+        &background.output(py, "Background")?,
+        &world_output.input(py, "Surface")?,
+        true,
+    )?;
+    Ok(())
+}
+
+fn new_node(py: Python, nodes: &mut Nodes, r#type: &str, location: [f32; 2]) -> Result<Node> {
+    let node = nodes.new(py, r#type)?;
+    node.setattr(py, intern!(py, "location"), location)?;
+    Ok(node)
+}
+
+impl From<pyo3::PyObject> for World {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for World {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for World {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for World {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,52 @@
+use crate::{bpy, result::Result, types::Object};
+use pyo3::{intern, Python, ToPyObject};
+
+/// Guard that captures the selected and active objects of the current view layer and restores
+/// them once it is dropped.
+#[derive(Debug)]
+pub struct SelectionGuard {
+    selected: Vec<Object>,
+    active: Option<Object>,
+}
+
+impl SelectionGuard {
+    pub fn new(py: Python) -> Result<Self> {
+        Ok(Self {
+            selected: bpy::context::selected_objects(py)?,
+            active: bpy::context::view_layer(py)?
+                .objects(py)?
+                .getattr(py, intern!(py, "active"))?
+                .extract(py)?,
+        })
+    }
+
+    /// Selects only the given `objects`, the first of which also becomes the active object.
+    pub fn select_only(&self, py: Python, objects: &[Object]) -> Result<()> {
+        Self::select_exclusively(py, objects, objects.first())
+    }
+
+    fn select_exclusively(py: Python, objects: &[Object], active: Option<&Object>) -> Result<()> {
+        for object in bpy::context::selected_objects(py)? {
+            object.select_set(py, false, None)?;
+        }
+        for object in objects {
+            object.select_set(py, true, None)?;
+        }
+        bpy::context::view_layer(py)?.objects(py)?.setattr(
+            py,
+            intern!(py, "active"),
+            active.map_or_else(|| py.None(), |object| object.to_object(py)),
+        )?;
+        Ok(())
+    }
+}
+
+impl Drop for SelectionGuard {
+    fn drop(&mut self) {
+        Python::with_gil(|py| {
+            if let Err(err) = Self::select_exclusively(py, &self.selected, self.active.as_ref()) {
+                pyo3::PyErr::from(err).print(py);
+            }
+        });
+    }
+}
@@ -0,0 +1,45 @@
+use crate::types::{collections::KeyBlocks, AnimationData, BpyID, ShapeKey};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::PyObject;
+use pyo3_macros_more::bind_python;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.Key.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct Key(PyObject);
+
+impl BpyID for Key {}
+
+impl Key {
+    bind_python! { self.animation_data => pub fn animation_data(&self, py: Python) -> Result<Option<AnimationData>> }
+    bind_python! { self.eval_time => pub fn eval_time(&self, py: Python) -> Result<f32> }
+    bind_python! { self.eval_time = pub fn set_eval_time(&mut self, py: Python, value: f32) }
+    bind_python! { self.key_blocks => pub fn key_blocks(&self, py: Python) -> Result<KeyBlocks> }
+    bind_python! { self.reference_key => pub fn reference_key(&self, py: Python) -> Result<ShapeKey> }
+    bind_python! { self.use_relative => pub fn use_relative(&self, py: Python) -> Result<bool> }
+    bind_python! { self.use_relative = pub fn set_use_relative(&mut self, py: Python, value: bool) }
+}
+
+impl From<pyo3::PyObject> for Key {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for Key {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for Key {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for Key {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,5 @@
+pub mod key;
+pub mod shape_key;
+
+pub use key::Key;
+pub use shape_key::ShapeKey;
@@ -0,0 +1,116 @@
+use crate::{
+    enums::{KeyframeInterpolation, ShapeKeyInterpolation},
+    error::BlError,
+    result::Result,
+    types::{BpyID, BpyStruct, Collection, CollectionImpl, FCurve, Key},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{PyObject, Python};
+use pyo3_macros_more::bind_python;
+use std::collections::HashSet;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.ShapeKey.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct ShapeKey(PyObject);
+
+impl BpyStruct for ShapeKey {}
+
+impl ShapeKey {
+    /// Returns the positions of all points of the shape key, which correspond to the vertices of
+    /// the mesh.
+    pub fn coordinates(&self, py: Python) -> Result<Vec<[f32; 3]>> {
+        self.data(py)?.foreach_get(py, "co")
+    }
+
+    /// Overwrites the positions of all points of the shape key. The number of `coordinates` must
+    /// match the number of points (i.e., vertices of the mesh).
+    pub fn set_coordinates(&mut self, py: Python, coordinates: &[[f32; 3]]) -> Result<()> {
+        let data = self.data(py)?;
+        let n_points = data.len(py)?;
+        if coordinates.len() != n_points {
+            return Err(BlError::ValueError(format!(
+                "Number of coordinates does not match the size of shape key '{name}' (expected: {n_points}, actual: {})",
+                coordinates.len(),
+                name = self.name(py)?,
+            )));
+        }
+        data.foreach_set(py, "co", coordinates)
+    }
+
+    /// Sets the value of the shape key and inserts a keyframe of it at `frame`.
+    pub fn insert_value_keyframe(&mut self, py: Python, frame: f32, value: f32) -> Result<()> {
+        self.set_value(py, value)?;
+        self.keyframe_insert(py, "value", -1, frame, "", HashSet::new())?;
+        Ok(())
+    }
+
+    /// Inserts keyframes of the value of the shape key in bulk, with one value per frame.
+    ///
+    /// Returns the F-curve of the value, which is created in the action of the shape key
+    /// data-block if necessary (see [`BpyID::insert_keyframes`]).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of `frames` and `values` differs.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn insert_value_keyframes(
+        &self,
+        py: Python,
+        frames: &[f32],
+        values: &[f32],
+        interpolation: KeyframeInterpolation,
+    ) -> Result<FCurve> {
+        let data_path = self.path_from_id(py, "value")?;
+        let values: Vec<[f32; 1]> = values.iter().map(|&value| [value]).collect();
+        let mut fcurves =
+            self.id_data(py)?
+                .insert_keyframes(py, &data_path, frames, &values, interpolation)?;
+        Ok(fcurves.remove(0))
+    }
+
+    bind_python! { self.data => pub fn data(&self, py: Python) -> Result<Collection> }
+    bind_python! { self.id_data => pub fn id_data(&self, py: Python) -> Result<Key> }
+    bind_python! { self.interpolation => pub fn interpolation(&self, py: Python) -> Result<ShapeKeyInterpolation> }
+    bind_python! { self.interpolation = pub fn set_interpolation(&mut self, py: Python, value: ShapeKeyInterpolation) }
+    bind_python! { self.lock_shape => pub fn lock_shape(&self, py: Python) -> Result<bool> }
+    bind_python! { self.lock_shape = pub fn set_lock_shape(&mut self, py: Python, value: bool) }
+    bind_python! { self.mute => pub fn mute(&self, py: Python) -> Result<bool> }
+    bind_python! { self.mute = pub fn set_mute(&mut self, py: Python, value: bool) }
+    bind_python! { self.name => pub fn name(&self, py: Python) -> Result<String> }
+    bind_python! { self.name = pub fn set_name(&mut self, py: Python, value: &str) }
+    bind_python! { self.relative_key => pub fn relative_key(&self, py: Python) -> Result<ShapeKey> }
+    bind_python! { self.relative_key = pub fn set_relative_key(&mut self, py: Python, value: &ShapeKey) }
+    bind_python! { self.slider_max => pub fn slider_max(&self, py: Python) -> Result<f32> }
+    bind_python! { self.slider_max = pub fn set_slider_max(&mut self, py: Python, value: f32) }
+    bind_python! { self.slider_min => pub fn slider_min(&self, py: Python) -> Result<f32> }
+    bind_python! { self.slider_min = pub fn set_slider_min(&mut self, py: Python, value: f32) }
+    bind_python! { self.value => pub fn value(&self, py: Python) -> Result<f32> }
+    bind_python! { self.value = pub fn set_value(&mut self, py: Python, value: f32) }
+    bind_python! { self.vertex_group => pub fn vertex_group(&self, py: Python) -> Result<String> }
+    bind_python! { self.vertex_group = pub fn set_vertex_group(&mut self, py: Python, value: &str) }
+}
+
+impl From<pyo3::PyObject> for ShapeKey {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for ShapeKey {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for ShapeKey {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for ShapeKey {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
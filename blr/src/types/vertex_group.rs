@@ -0,0 +1,131 @@
+use crate::{
+    enums::VertexGroupAssignMode,
+    error::BlError,
+    result::Result,
+    types::{BpyStruct, CollectionImpl, Mesh, Object},
+};
+use derive_more::{Deref, DerefMut, Display};
+use pyo3::{intern, types::PyModule, PyObject, Python};
+use pyo3_macros_more::bind_python;
+
+/// Python helpers that access the weights of a vertex group in a single call. Blender does not
+/// provide bulk access to vertex groups (`foreach_get`/`foreach_set`), so the loops over the
+/// vertices run in Python instead of calling into Python once per vertex.
+const BULK_WEIGHTS_CODE: &str = r#"
+def weights(group):
+    index = group.index
+    return [
+        (vertex_index, element.weight)
+        for vertex_index, vertex in enumerate(group.id_data.data.vertices)
+        for element in vertex.groups
+        if element.group == index
+    ]
+
+
+def set_weights(group, weights):
+    indices_by_weight = {}
+    for vertex_index, weight in enumerate(weights):
+        indices_by_weight.setdefault(weight, []).append(vertex_index)
+    group.remove(indices_by_weight.pop(0.0, []))
+    for weight, indices in indices_by_weight.items():
+        group.add(indices, weight, "REPLACE")
+"#;
+
+/// Wrapper for <https://docs.blender.org/api/latest/bpy.types.VertexGroup.html>
+#[repr(transparent)]
+#[derive(Clone, Debug, Deref, DerefMut, Display)]
+pub struct VertexGroup(PyObject);
+
+impl BpyStruct for VertexGroup {}
+
+impl VertexGroup {
+    /// Returns the indices and weights of all vertices that are assigned to the group.
+    ///
+    /// All weights are read in a single call into Python.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the object of the group is not a mesh.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn weights(&self, py: Python) -> Result<Vec<(usize, f32)>> {
+        // Fails if the object of the group is not a mesh
+        self.mesh(py)?;
+        Ok(bulk_weights(py)?
+            .call_method1(intern!(py, "weights"), (self.as_ref(py),))?
+            .extract()?)
+    }
+
+    /// Overwrites the weights of all vertices with one weight per vertex. Vertices with a weight
+    /// of zero are removed from the group.
+    ///
+    /// All weights are passed to Python in a single call, which assigns the vertices of each
+    /// distinct weight at once via [`VertexGroup::add`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of `weights` does not match the number of vertices or the
+    /// object of the group is not a mesh.
+    /// Furthermore, exceptions from the Python API of Blender are propagated.
+    pub fn set_weights(&self, py: Python, weights: &[f32]) -> Result<()> {
+        let n_vertices = self.mesh(py)?.vertices(py)?.len(py)?;
+        if weights.len() != n_vertices {
+            return Err(BlError::ValueError(format!(
+                "Number of weights does not match the number of vertices of vertex group '{name}' (expected: {n_vertices}, actual: {})",
+                weights.len(),
+                name = self.name(py)?,
+            )));
+        }
+        bulk_weights(py)?.call_method1(
+            intern!(py, "set_weights"),
+            (self.as_ref(py), weights.to_vec()),
+        )?;
+        Ok(())
+    }
+
+    fn mesh(&self, py: Python) -> Result<Mesh> {
+        self.id_data(py)?.try_into()
+    }
+
+    bind_python! { self.id_data => pub fn id_data(&self, py: Python) -> Result<Object> }
+    bind_python! { self.index => pub fn index(&self, py: Python) -> Result<usize> }
+    bind_python! { self.lock_weight => pub fn lock_weight(&self, py: Python) -> Result<bool> }
+    bind_python! { self.lock_weight = pub fn set_lock_weight(&mut self, py: Python, value: bool) }
+    bind_python! { self.name => pub fn name(&self, py: Python) -> Result<String> }
+    bind_python! { self.name = pub fn set_name(&mut self, py: Python, value: &str) }
+    bind_python! { self.add() => pub fn add(&self, py: Python, index: &[usize], weight: f32, r#type: VertexGroupAssignMode) }
+    bind_python! { self.remove() => pub fn remove(&self, py: Python, index: &[usize]) }
+    bind_python! { self.weight() => pub fn weight(&self, py: Python, index: usize) -> Result<f32> }
+}
+
+fn bulk_weights<'py>(py: Python<'py>) -> Result<&'py PyModule> {
+    Ok(PyModule::from_code(
+        py,
+        BULK_WEIGHTS_CODE,
+        "blr_vertex_group.py",
+        "blr_vertex_group",
+    )?)
+}
+
+impl From<pyo3::PyObject> for VertexGroup {
+    fn from(value: pyo3::PyObject) -> Self {
+        Self(value)
+    }
+}
+
+impl From<&pyo3::PyAny> for VertexGroup {
+    fn from(value: &pyo3::PyAny) -> Self {
+        Self(value.into())
+    }
+}
+
+impl pyo3::FromPyObject<'_> for VertexGroup {
+    fn extract(value: &pyo3::PyAny) -> pyo3::PyResult<Self> {
+        Ok(Self(value.into()))
+    }
+}
+
+impl pyo3::ToPyObject for VertexGroup {
+    fn to_object(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        self.as_ref(py).to_object(py)
+    }
+}
@@ -0,0 +1,3052 @@
+"""
+Pure-Python stand-in for the Python module of Blender (`bpy`).
+
+Only a small subset of the API is mimicked, which is sufficient for testing code built on top
+of `blr` without Blender being installed. Operators that are not explicitly implemented are
+accepted as no-op stubs and recorded in `bpy.ops.calls`.
+"""
+
+import json
+import math
+import os
+import types as _types
+
+# --------------------------------------------------------------------------------------------------
+# bpy.types
+# --------------------------------------------------------------------------------------------------
+
+
+class bpy_struct:
+    """Base of all structs. Unknown attributes can be freely assigned, like custom properties."""
+
+    def __init__(self, **kwargs):
+        for key, value in kwargs.items():
+            setattr(self, key, value)
+
+    def __repr__(self):
+        name = getattr(self, "name", None)
+        if name is None:
+            return f"<bpy_struct, {type(self).__name__}>"
+        return f"<bpy_struct, {type(self).__name__}(\"{name}\")>"
+
+    def as_pointer(self):
+        return id(self)
+
+    def path_resolve(self, path, coerce=True):
+        value = self
+        for attr in path.split("."):
+            value = getattr(value, attr)
+        return value
+
+    def foreach_get(self, attr, seq):
+        raise TypeError("foreach_get is only supported on collections")
+
+    def foreach_set(self, attr, seq):
+        raise TypeError("foreach_set is only supported on collections")
+
+
+def _fill_sequence(seq, values):
+    """Writes `values` into `seq` in place, coercing them to the item type of typed buffers."""
+    cast = {"f": float, "d": float, "i": int}.get(getattr(seq, "format", None), lambda v: v)
+    for index, value in enumerate(values):
+        seq[index] = cast(value)
+
+
+class bpy_prop_collection:
+    """Ordered collection of structs that can be indexed by name or position."""
+
+    def __init__(self, items=None):
+        self._items = list(items or [])
+
+    def __len__(self):
+        return len(self._items)
+
+    def __iter__(self):
+        return iter(list(self._items))
+
+    def __contains__(self, key):
+        if isinstance(key, str):
+            return self.find(key) != -1
+        return key in self._items
+
+    def __getitem__(self, key):
+        if isinstance(key, str):
+            index = self.find(key)
+            if index == -1:
+                raise KeyError(f'bpy_prop_collection[key]: key "{key}" not found')
+            return self._items[index]
+        return self._items[key]
+
+    def __repr__(self):
+        return f"<bpy_collection[{len(self)}], {type(self).__name__}>"
+
+    def find(self, key):
+        for index, item in enumerate(self._items):
+            if getattr(item, "name", None) == key:
+                return index
+        return -1
+
+    def get(self, key, default=None):
+        index = self.find(key)
+        return default if index == -1 else self._items[index]
+
+    def keys(self):
+        return [item.name for item in self._items]
+
+    def values(self):
+        return list(self._items)
+
+    def items(self):
+        return [(item.name, item) for item in self._items]
+
+    def foreach_get(self, attr, seq):
+        flat = []
+        for item in self._items:
+            value = getattr(item, attr)
+            enum_items = getattr(item, "_FOREACH_ENUMS", {}).get(attr)
+            if enum_items is not None:
+                value = enum_items.index(value)
+            if isinstance(value, (list, tuple)):
+                flat.extend(value)
+            else:
+                flat.append(value)
+        if len(seq) != len(flat):
+            raise RuntimeError(
+                f"internal error setting the array (expected {len(flat)}, got {len(seq)})"
+            )
+        _fill_sequence(seq, flat)
+
+    def foreach_set(self, attr, seq):
+        seq = list(seq)
+        if not self._items:
+            if seq:
+                raise RuntimeError("internal error setting the array")
+            return
+        sample = getattr(self._items[0], attr)
+        width = len(sample) if isinstance(sample, (list, tuple)) else 0
+        if len(seq) != len(self._items) * max(width, 1):
+            raise RuntimeError("internal error setting the array")
+        for index, item in enumerate(self._items):
+            if width:
+                setattr(item, attr, list(seq[index * width : (index + 1) * width]))
+            else:
+                enum_items = getattr(item, "_FOREACH_ENUMS", {}).get(attr)
+                value = seq[index] if enum_items is None else enum_items[int(seq[index])]
+                setattr(item, attr, value)
+
+    def _unique_name(self, name):
+        if self.find(name) == -1:
+            return name
+        suffix = 1
+        while self.find(f"{name}.{suffix:03}") != -1:
+            suffix += 1
+        return f"{name}.{suffix:03}"
+
+    def _append(self, item):
+        self._items.append(item)
+        return item
+
+    def _discard(self, item):
+        if item in self._items:
+            self._items.remove(item)
+
+
+class ID(bpy_struct):
+    def __init__(self, name, **kwargs):
+        self.name = name
+        self.users = 0
+        self.use_fake_user = False
+        self.use_extra_user = False
+        self.is_evaluated = False
+        self.is_embedded_data = False
+        self.is_library_indirect = False
+        self.is_missing = False
+        self.is_runtime_data = False
+        self.library = None
+        self.tag = False
+        self.animation_data = None
+        super().__init__(**kwargs)
+
+    @property
+    def name_full(self):
+        return self.name
+
+    @property
+    def id_type(self):
+        return {"NodeTree": "NODETREE"}.get(type(self).__name__, type(self).__name__.upper())
+
+    @property
+    def original(self):
+        return self
+
+    def evaluated_get(self, depsgraph):
+        return self
+
+    def copy(self):
+        new = type(self).__new__(type(self))
+        new.__dict__.update(self.__dict__)
+        new.name = _data_collection_of(self)._unique_name(self.name)
+        _data_collection_of(self)._append(new)
+        return new
+
+    def user_clear(self):
+        self.users = 0
+
+    def animation_data_create(self):
+        if self.animation_data is None:
+            self.animation_data = AnimData()
+        return self.animation_data
+
+    def animation_data_clear(self):
+        self.animation_data = None
+
+    def driver_add(self, path, index=-1):
+        value = self.path_resolve(path)
+        if isinstance(value, (list, tuple)) and index == -1:
+            return [self.driver_add(path, i) for i in range(len(value))]
+        index = max(index, 0)
+        drivers = self.animation_data_create().drivers
+        fcurve = drivers.find(path, index)
+        if fcurve is None:
+            element = value[index] if isinstance(value, (list, tuple)) else value
+            fcurve = drivers.new(path, index)
+            fcurve.driver.expression = f"{float(element):.3f}"
+        return fcurve
+
+    def driver_remove(self, path, index=-1):
+        if self.animation_data is None:
+            return False
+        drivers = self.animation_data.drivers
+        removed = [
+            fcurve
+            for fcurve in drivers
+            if fcurve.data_path == path and index in (-1, fcurve.array_index)
+        ]
+        for fcurve in removed:
+            drivers.remove(fcurve)
+        return bool(removed)
+
+
+class AnimData(bpy_struct):
+    def __init__(self):
+        super().__init__(
+            action=None,
+            action_blend_type="REPLACE",
+            action_extrapolation="HOLD",
+            action_influence=1.0,
+            drivers=AnimDataDrivers(),
+            nla_tracks=bpy_prop_collection(),
+            use_nla=True,
+        )
+
+
+# Animation --------------------------------------------------------------------------------------
+
+
+class Keyframe(bpy_struct):
+    # Enum properties are transferred by `foreach_get()`/`foreach_set()` as their integer values.
+    _FOREACH_ENUMS = {
+        # Ordered by the integer values of `eBezTriple_Interpolation` (not the UI order)
+        "interpolation": [
+            "CONSTANT", "LINEAR", "BEZIER", "BACK", "BOUNCE", "CIRC", "CUBIC", "ELASTIC", "EXPO",
+            "QUAD", "QUART", "QUINT", "SINE",
+        ],
+    }
+
+    def __init__(self, co=(0.0, 0.0)):
+        super().__init__(
+            co=list(co),
+            handle_left=list(co),
+            handle_right=list(co),
+            handle_left_type="AUTO_CLAMPED",
+            handle_right_type="AUTO_CLAMPED",
+            interpolation="BEZIER",
+            easing="AUTO",
+            amplitude=0.8,
+            back=1.70158,
+            period=4.1,
+            type="KEYFRAME",
+            select_control_point=False,
+        )
+
+
+class FCurveKeyframePoints(bpy_prop_collection):
+    def add(self, count):
+        for _ in range(count):
+            self._append(Keyframe())
+
+    def insert(self, frame, value, options=frozenset(), keyframe_type="KEYFRAME"):
+        for keyframe in self._items:
+            if keyframe.co[0] == frame:
+                keyframe.co = [frame, value]
+                return keyframe
+        keyframe = self._append(Keyframe((frame, value)))
+        keyframe.type = keyframe_type
+        self._items.sort(key=lambda keyframe: keyframe.co[0])
+        return keyframe
+
+    def remove(self, keyframe, fast=False):
+        self._discard(keyframe)
+
+    def clear(self):
+        self._items.clear()
+
+
+_FMODIFIER_PROPERTIES = {
+    "CYCLES": dict(mode_before="REPEAT", mode_after="REPEAT", cycles_before=0, cycles_after=0),
+    "NOISE": dict(blend_type="REPLACE", scale=1.0, strength=1.0, phase=1.0, offset=0.0, depth=0),
+    "STEPPED": dict(frame_step=2.0, frame_offset=0.0),
+}
+
+
+class FModifier(bpy_struct):
+    def __init__(self, type):
+        super().__init__(
+            type=type,
+            active=True,
+            mute=False,
+            is_valid=True,
+            influence=1.0,
+            use_influence=False,
+            use_restricted_range=False,
+            frame_start=0.0,
+            frame_end=0.0,
+            blend_in=0.0,
+            blend_out=0.0,
+            **_FMODIFIER_PROPERTIES.get(type, {}),
+        )
+
+
+class FCurveModifiers(bpy_prop_collection):
+    @property
+    def active(self):
+        return next((modifier for modifier in self._items if modifier.active), None)
+
+    def new(self, type):
+        for modifier in self._items:
+            modifier.active = False
+        return self._append(FModifier(type))
+
+    def remove(self, modifier):
+        self._discard(modifier)
+
+
+class FCurve(bpy_struct):
+    def __init__(self, data_path, index=0, group=None):
+        super().__init__(
+            data_path=data_path,
+            array_index=index,
+            group=group,
+            extrapolation="CONSTANT",
+            mute=False,
+            hide=False,
+            lock=False,
+            select=True,
+            is_valid=True,
+            driver=None,
+        )
+        self.keyframe_points = FCurveKeyframePoints()
+        self.modifiers = FCurveModifiers()
+
+    @property
+    def is_empty(self):
+        return len(self.keyframe_points) == 0 and len(self.modifiers) == 0
+
+    def range(self):
+        frames = [keyframe.co[0] for keyframe in self.keyframe_points]
+        return (min(frames), max(frames)) if frames else (0.0, 0.0)
+
+    def update(self):
+        self.keyframe_points._items.sort(key=lambda keyframe: keyframe.co[0])
+        for keyframe in self.keyframe_points:
+            keyframe.handle_left = list(keyframe.co)
+            keyframe.handle_right = list(keyframe.co)
+
+    def evaluate(self, frame):
+        """Only constant and linear interpolation are mocked (Bézier and the dynamic
+        interpolations are evaluated linearly), and only the Cycles modifier is applied."""
+        keyframes = sorted(self.keyframe_points, key=lambda keyframe: keyframe.co[0])
+        if not keyframes:
+            return 0.0
+        start, end = keyframes[0].co[0], keyframes[-1].co[0]
+        if any(modifier.type == "CYCLES" and not modifier.mute for modifier in self.modifiers):
+            if end > start:
+                frame = start + math.fmod(frame - start, end - start)
+                if frame < start:
+                    frame += end - start
+        if frame <= start:
+            return keyframes[0].co[1]
+        if frame >= end:
+            return keyframes[-1].co[1]
+        for left, right in zip(keyframes, keyframes[1:]):
+            if left.co[0] <= frame <= right.co[0]:
+                if left.interpolation == "CONSTANT" or frame == left.co[0]:
+                    return left.co[1] if frame < right.co[0] else right.co[1]
+                factor = (frame - left.co[0]) / (right.co[0] - left.co[0])
+                return left.co[1] + factor * (right.co[1] - left.co[1])
+        return keyframes[-1].co[1]
+
+
+class ActionFCurves(bpy_prop_collection):
+    def new(self, data_path, index=0, action_group=""):
+        if self.find(data_path, index) is not None:
+            raise RuntimeError(f"Error: F-Curve '{data_path}[{index}]' already exists in action")
+        return self._append(FCurve(data_path, index, action_group or None))
+
+    def find(self, data_path, index=0):
+        for fcurve in self._items:
+            if fcurve.data_path == data_path and fcurve.array_index == index:
+                return fcurve
+        return None
+
+    def remove(self, fcurve):
+        self._discard(fcurve)
+
+    def clear(self):
+        self._items.clear()
+
+
+class AnimDataDrivers(ActionFCurves):
+    def new(self, data_path, index=0):
+        fcurve = super().new(data_path, index)
+        fcurve.driver = Driver()
+        return fcurve
+
+
+class DriverTarget(bpy_struct):
+    def __init__(self):
+        super().__init__(
+            id=None,
+            id_type="OBJECT",
+            data_path="",
+            transform_type="LOC_X",
+            transform_space="WORLD_SPACE",
+            rotation_mode="AUTO",
+            bone_target="",
+        )
+
+
+_DRIVER_VARIABLE_TARGETS = {"SINGLE_PROP": 1, "TRANSFORMS": 1, "ROTATION_DIFF": 2, "LOC_DIFF": 2}
+
+
+class DriverVariable(bpy_struct):
+    def __init__(self, name):
+        super().__init__(name=name, is_name_valid=True)
+        self.targets = bpy_prop_collection([DriverTarget()])
+        self._type = "SINGLE_PROP"
+
+    @property
+    def type(self):
+        return self._type
+
+    @type.setter
+    def type(self, value):
+        self._type = value
+        count = _DRIVER_VARIABLE_TARGETS[value]
+        self.targets = bpy_prop_collection(
+            (list(self.targets) + [DriverTarget() for _ in range(count)])[:count]
+        )
+
+
+class DriverVariables(bpy_prop_collection):
+    def new(self):
+        name = "var"
+        suffix = 0
+        while self.find(name) != -1:
+            suffix += 1
+            name = f"var_{suffix:03}"
+        return self._append(DriverVariable(name))
+
+    def remove(self, variable):
+        self._discard(variable)
+
+
+class Driver(bpy_struct):
+    def __init__(self):
+        super().__init__(
+            type="SCRIPTED",
+            expression="",
+            use_self=False,
+            is_valid=True,
+            is_simple_expression=True,
+        )
+        self.variables = DriverVariables()
+
+
+class Action(ID):
+    def __init__(self, name):
+        super().__init__(name)
+        self.fcurves = ActionFCurves()
+        self.id_root = "OBJECT"
+        self.use_cyclic = False
+        self.use_frame_range = False
+        self.frame_start = 1.0
+        self.frame_end = 1.0
+
+    @property
+    def frame_range(self):
+        if self.use_frame_range:
+            return (self.frame_start, self.frame_end)
+        ranges = [fcurve.range() for fcurve in self.fcurves if len(fcurve.keyframe_points)]
+        if not ranges:
+            return (0.0, 0.0)
+        return (min(start for start, _ in ranges), max(end for _, end in ranges))
+
+
+# Mesh -------------------------------------------------------------------------------------------
+
+
+class MeshVertex(bpy_struct):
+    def __init__(self, index, co):
+        super().__init__(
+            index=index, co=list(co), normal=[0.0, 0.0, 0.0], select=False, hide=False
+        )
+        self.groups = bpy_prop_collection()
+
+
+class MeshEdge(bpy_struct):
+    def __init__(self, index, vertices):
+        super().__init__(index=index, vertices=list(vertices), select=False, hide=False)
+
+
+class MeshLoop(bpy_struct):
+    def __init__(self, index, vertex_index, edge_index=0):
+        super().__init__(
+            index=index,
+            vertex_index=vertex_index,
+            edge_index=edge_index,
+            normal=[0.0, 0.0, 0.0],
+        )
+
+
+class MeshPolygon(bpy_struct):
+    def __init__(self, index, vertices, loop_start):
+        super().__init__(
+            index=index,
+            normal=[0.0, 0.0, 0.0],
+            vertices=list(vertices),
+            loop_start=loop_start,
+            loop_total=len(vertices),
+            material_index=0,
+            use_smooth=False,
+            select=False,
+            hide=False,
+        )
+
+
+class MeshLoopTriangle(bpy_struct):
+    def __init__(self, index, vertices, loops, polygon_index):
+        super().__init__(
+            index=index,
+            vertices=list(vertices),
+            loops=list(loops),
+            polygon_index=polygon_index,
+        )
+
+
+_ATTRIBUTE_VALUE_FIELDS = {
+    "FLOAT": ("value", 0.0),
+    "INT": ("value", 0),
+    "INT8": ("value", 0),
+    "BOOLEAN": ("value", False),
+    "FLOAT_VECTOR": ("vector", [0.0, 0.0, 0.0]),
+    "FLOAT2": ("vector", [0.0, 0.0]),
+    "INT32_2D": ("value", [0, 0]),
+    "FLOAT_COLOR": ("color", [0.0, 0.0, 0.0, 0.0]),
+    "BYTE_COLOR": ("color", [0.0, 0.0, 0.0, 0.0]),
+    "QUATERNION": ("value", [1.0, 0.0, 0.0, 0.0]),
+    "STRING": ("value", ""),
+}
+
+
+class Attribute(bpy_struct):
+    def __init__(self, name, data_type, domain, size):
+        field, default = _ATTRIBUTE_VALUE_FIELDS[data_type]
+        super().__init__(
+            name=name,
+            data_type=data_type,
+            domain=domain,
+            is_internal=False,
+            is_required=False,
+        )
+        self.data = bpy_prop_collection(
+            bpy_struct(**{field: list(default) if isinstance(default, list) else default})
+            for _ in range(size)
+        )
+
+
+class AttributeGroup(bpy_prop_collection):
+    def __init__(self, mesh):
+        super().__init__()
+        self._mesh = mesh
+        self.active = None
+        self.active_index = -1
+
+    def new(self, name, type, domain):
+        if type not in _ATTRIBUTE_VALUE_FIELDS:
+            raise TypeError(f"Invalid attribute type '{type}'")
+        size = self._mesh._domain_size(domain)
+        attribute = self._append(Attribute(self._unique_name(name), type, domain, size))
+        self.active = attribute
+        self.active_index = len(self) - 1
+        return attribute
+
+    def remove(self, attribute):
+        if attribute not in self._items:
+            raise RuntimeError(f"Attribute '{attribute.name}' does not belong to mesh")
+        self._discard(attribute)
+        if self.active is attribute:
+            self.active = None
+            self.active_index = -1
+
+
+class MeshUVLoopLayer(bpy_struct):
+    def __init__(self, name, size):
+        super().__init__(name=name, active=False, active_render=False)
+        self.data = bpy_prop_collection(bpy_struct(uv=[0.0, 0.0]) for _ in range(size))
+
+
+class UVLoopLayers(bpy_prop_collection):
+    def __init__(self, mesh):
+        super().__init__()
+        self._mesh = mesh
+        self.active = None
+
+    def new(self, name="UVMap", do_init=True):
+        layer = self._append(MeshUVLoopLayer(self._unique_name(name), len(self._mesh.loops)))
+        if self.active is None:
+            self.active = layer
+        return layer
+
+    def remove(self, layer):
+        self._discard(layer)
+        if self.active is layer:
+            self.active = self._items[0] if self._items else None
+
+
+class IDMaterials(bpy_prop_collection):
+    def append(self, material):
+        self._append(material)
+        if material is not None:
+            material.users += 1
+
+    def pop(self, index=-1):
+        material = self._items.pop(index)
+        if material is not None:
+            material.users -= 1
+        return material
+
+    def clear(self):
+        while self._items:
+            self.pop()
+
+
+class Mesh(ID):
+    def __init__(self, name):
+        super().__init__(name)
+        self.vertices = bpy_prop_collection()
+        self.edges = bpy_prop_collection()
+        self.loops = bpy_prop_collection()
+        self.polygons = bpy_prop_collection()
+        self.loop_triangles = bpy_prop_collection()
+        self.uv_layers = UVLoopLayers(self)
+        self.attributes = AttributeGroup(self)
+        self.materials = IDMaterials()
+        self.shape_keys = None
+
+    def from_pydata(self, vertices, edges, faces, shade_flat=True):
+        self.vertices = bpy_prop_collection(
+            MeshVertex(index, co) for index, co in enumerate(vertices)
+        )
+        edge_keys = [tuple(edge) for edge in edges]
+        loops = []
+        polygons = []
+        for face in faces:
+            polygons.append(MeshPolygon(len(polygons), face, len(loops)))
+            for corner, vertex in enumerate(face):
+                key = tuple(sorted((vertex, face[(corner + 1) % len(face)])))
+                if key not in edge_keys and key[::-1] not in edge_keys:
+                    edge_keys.append(key)
+                loops.append(MeshLoop(len(loops), vertex))
+        self.edges = bpy_prop_collection(
+            MeshEdge(index, key) for index, key in enumerate(edge_keys)
+        )
+        self.loops = bpy_prop_collection(loops)
+        self.polygons = bpy_prop_collection(polygons)
+        self.loop_triangles = bpy_prop_collection()
+        self.calc_normals()
+
+    def validate(self, verbose=False, clean_customdata=True):
+        return False
+
+    def update(self, calc_edges=False, calc_edges_loose=False):
+        pass
+
+    def calc_loop_triangles(self):
+        triangles = []
+        for polygon in self.polygons:
+            loop_indices = list(range(polygon.loop_start, polygon.loop_start + polygon.loop_total))
+            for corner in range(1, polygon.loop_total - 1):
+                loops = [loop_indices[0], loop_indices[corner], loop_indices[corner + 1]]
+                vertices = [self.loops[loop].vertex_index for loop in loops]
+                triangles.append(
+                    MeshLoopTriangle(len(triangles), vertices, loops, polygon.index)
+                )
+        self.loop_triangles = bpy_prop_collection(triangles)
+
+    @property
+    def vertex_normals(self):
+        return bpy_prop_collection(bpy_struct(vector=vertex.normal) for vertex in self.vertices)
+
+    @property
+    def polygon_normals(self):
+        return bpy_prop_collection(
+            bpy_struct(vector=polygon.normal) for polygon in self.polygons
+        )
+
+    def calc_normals(self):
+        for polygon in self.polygons:
+            polygon.normal = _newell_normal([self.vertices[i].co for i in polygon.vertices])
+        normals = [[0.0, 0.0, 0.0] for _ in self.vertices]
+        for polygon in self.polygons:
+            for index in polygon.vertices:
+                normals[index] = [a + b for a, b in zip(normals[index], polygon.normal)]
+        for vertex, normal in zip(self.vertices, normals):
+            vertex.normal = _normalized(normal)
+
+    def _domain_size(self, domain):
+        return {
+            "POINT": len(self.vertices),
+            "EDGE": len(self.edges),
+            "FACE": len(self.polygons),
+            "CORNER": len(self.loops),
+        }.get(domain, 0)
+
+    def clear_geometry(self):
+        self.from_pydata([], [], [])
+
+
+def _newell_normal(points):
+    normal = [0.0, 0.0, 0.0]
+    for current, following in zip(points, points[1:] + points[:1]):
+        normal[0] += (current[1] - following[1]) * (current[2] + following[2])
+        normal[1] += (current[2] - following[2]) * (current[0] + following[0])
+        normal[2] += (current[0] - following[0]) * (current[1] + following[1])
+    return _normalized(normal)
+
+
+def _normalized(vector):
+    length = sum(component * component for component in vector) ** 0.5
+    if length == 0.0:
+        return [0.0, 0.0, 0.0]
+    return [component / length for component in vector]
+
+
+# Object -----------------------------------------------------------------------------------------
+
+
+# Vertex groups ----------------------------------------------------------------------------------
+
+
+class VertexGroupElement(bpy_struct):
+    def __init__(self, group, weight):
+        super().__init__(group=group, weight=weight)
+
+
+class VertexGroup(bpy_struct):
+    """Weights are stored in the vertices of the mesh, like in Blender."""
+
+    def __init__(self, obj, name, index):
+        super().__init__(name=name, index=index, lock_weight=False)
+        self.id_data = obj
+
+    def _element(self, vertex):
+        for element in vertex.groups:
+            if element.group == self.index:
+                return element
+        return None
+
+    def add(self, index, weight, type):
+        for vertex_index in index:
+            vertex = self.id_data.data.vertices[vertex_index]
+            element = self._element(vertex)
+            if element is None:
+                if type == "SUBTRACT":
+                    continue
+                element = vertex.groups._append(VertexGroupElement(self.index, 0.0))
+            if type == "REPLACE":
+                element.weight = weight
+            elif type == "ADD":
+                element.weight = min(element.weight + weight, 1.0)
+            else:
+                element.weight = max(element.weight - weight, 0.0)
+
+    def remove(self, index):
+        for vertex_index in index:
+            vertex = self.id_data.data.vertices[vertex_index]
+            vertex.groups._discard(self._element(vertex))
+
+    def weight(self, index):
+        element = self._element(self.id_data.data.vertices[index])
+        if element is None:
+            raise RuntimeError("Error: Vertex not in group")
+        return element.weight
+
+
+class VertexGroups(bpy_prop_collection):
+    def __init__(self, obj):
+        super().__init__()
+        self._object = obj
+        self.active_index = -1
+
+    @property
+    def active(self):
+        return self._items[self.active_index] if 0 <= self.active_index < len(self) else None
+
+    def new(self, name="Group"):
+        group = self._append(VertexGroup(self._object, self._unique_name(name), len(self)))
+        self.active_index = group.index
+        return group
+
+    def remove(self, group):
+        vertices = getattr(self._object.data, "vertices", [])
+        for vertex in vertices:
+            vertex.groups._discard(group._element(vertex))
+            for element in vertex.groups:
+                if element.group > group.index:
+                    element.group -= 1
+        self._discard(group)
+        for index, other in enumerate(self._items):
+            other.index = index
+        self.active_index = min(self.active_index, len(self) - 1)
+
+    def clear(self):
+        for group in list(self._items):
+            self.remove(group)
+
+
+# Shape keys -------------------------------------------------------------------------------------
+
+
+class ShapeKeyPoint(bpy_struct):
+    def __init__(self, co):
+        super().__init__(co=list(co))
+
+
+class ShapeKey(bpy_struct):
+    def __init__(self, key, name, coordinates, relative_key=None):
+        super().__init__(
+            name=name,
+            value=0.0,
+            slider_min=0.0,
+            slider_max=1.0,
+            vertex_group="",
+            interpolation="KEY_LINEAR",
+            mute=False,
+            lock_shape=False,
+        )
+        self.id_data = key
+        self.relative_key = relative_key if relative_key is not None else self
+        self.data = bpy_prop_collection(ShapeKeyPoint(co) for co in coordinates)
+
+    def path_from_id(self, property=""):
+        path = f'key_blocks["{self.name}"]'
+        return f"{path}.{property}" if property else path
+
+    def keyframe_insert(self, data_path, index=-1, frame=0.0, group="", options=frozenset()):
+        key = self.id_data
+        animation_data = key.animation_data_create()
+        if animation_data.action is None:
+            animation_data.action = _state.data.actions.new(f"{key.name}Action")
+        fcurves = animation_data.action.fcurves
+        path = self.path_from_id(data_path)
+        fcurve = fcurves.find(path, max(index, 0)) or fcurves.new(path, max(index, 0), group)
+        fcurve.keyframe_points.insert(frame, getattr(self, data_path))
+        return True
+
+
+class KeyBlocks(bpy_prop_collection):
+    pass
+
+
+class Key(ID):
+    def __init__(self, name, user=None):
+        super().__init__(name)
+        self.user = user
+        self.key_blocks = KeyBlocks()
+        self.use_relative = True
+        self.eval_time = 0.0
+
+    @property
+    def reference_key(self):
+        return self.key_blocks[0] if len(self.key_blocks) else None
+
+    def _mix(self):
+        """Coordinates of the relative shape keys blended by their values."""
+        coordinates = [list(point.co) for point in self.reference_key.data]
+        for shape_key in list(self.key_blocks)[1:]:
+            if shape_key.mute or shape_key.value == 0.0:
+                continue
+            pairs = zip(shape_key.data, shape_key.relative_key.data)
+            for mixed, (point, relative) in zip(coordinates, pairs):
+                for axis in range(3):
+                    mixed[axis] += shape_key.value * (point.co[axis] - relative.co[axis])
+        return coordinates
+
+
+class Modifier(bpy_struct):
+    def __init__(self, name, type):
+        super().__init__(
+            name=name,
+            type=type,
+            show_viewport=True,
+            show_render=True,
+            show_in_editmode=False,
+            show_on_cage=False,
+            show_expanded=True,
+            is_active=False,
+            is_override_data=False,
+            use_apply_on_spline=False,
+            execution_time=0.0,
+        )
+
+
+class ObjectModifiers(bpy_prop_collection):
+    def __init__(self):
+        super().__init__()
+        self.active = None
+
+    def new(self, name, type):
+        modifier = self._append(Modifier(self._unique_name(name), type))
+        self.active = modifier
+        return modifier
+
+    def remove(self, modifier):
+        if modifier not in self._items:
+            raise ReferenceError(f"Modifier '{modifier.name}' not found")
+        self._discard(modifier)
+        if self.active is modifier:
+            self.active = self._items[-1] if self._items else None
+
+    def clear(self):
+        self._items.clear()
+        self.active = None
+
+    def move(self, from_index, to_index):
+        self._items.insert(to_index, self._items.pop(from_index))
+
+
+_CONSTRAINT_DEFAULTS = {
+    "COPY_LOCATION": dict(
+        use_x=True,
+        use_y=True,
+        use_z=True,
+        invert_x=False,
+        invert_y=False,
+        invert_z=False,
+        use_offset=False,
+        head_tail=0.0,
+        use_bbone_shape=False,
+    ),
+    "DAMPED_TRACK": dict(track_axis="TRACK_Y", head_tail=0.0, use_bbone_shape=False),
+    "LIMIT_LOCATION": dict(
+        **{f"{bound}_{axis}": 0.0 for bound in ("min", "max") for axis in "xyz"},
+        **{f"use_{bound}_{axis}": False for bound in ("min", "max") for axis in "xyz"},
+        use_transform_limit=False,
+    ),
+    "TRACK_TO": dict(
+        track_axis="TRACK_NEGATIVE_Z",
+        up_axis="UP_Y",
+        use_target_z=False,
+        head_tail=0.0,
+        use_bbone_shape=False,
+    ),
+}
+
+
+class Constraint(bpy_struct):
+    def __init__(self, name, type):
+        super().__init__(
+            name=name,
+            type=type,
+            active=False,
+            enabled=True,
+            influence=1.0,
+            is_valid=True,
+            is_override_data=False,
+            owner_space="WORLD",
+            target_space="WORLD",
+            show_expanded=True,
+            error_location=0.0,
+            error_rotation=0.0,
+            target=None,
+            subtarget="",
+            **_CONSTRAINT_DEFAULTS.get(type, {}),
+        )
+
+    def evaluate(self, owner):
+        """Only Copy Location and Limit Location are evaluated, other types keep the location."""
+        location = list(owner.location)
+        if self.type == "COPY_LOCATION" and self.target is not None:
+            for axis, name in enumerate("xyz"):
+                if getattr(self, f"use_{name}"):
+                    value = self.target.location[axis]
+                    value = -value if getattr(self, f"invert_{name}") else value
+                    location[axis] = location[axis] + value if self.use_offset else value
+        elif self.type == "LIMIT_LOCATION":
+            for axis, name in enumerate("xyz"):
+                if getattr(self, f"use_min_{name}"):
+                    location[axis] = max(location[axis], getattr(self, f"min_{name}"))
+                if getattr(self, f"use_max_{name}"):
+                    location[axis] = min(location[axis], getattr(self, f"max_{name}"))
+        return [
+            old + self.influence * (new - old) for old, new in zip(owner.location, location)
+        ]
+
+
+class ObjectConstraints(bpy_prop_collection):
+    def __init__(self):
+        super().__init__()
+
+    @property
+    def active(self):
+        return next((constraint for constraint in self._items if constraint.active), None)
+
+    @active.setter
+    def active(self, constraint):
+        for item in self._items:
+            item.active = item is constraint
+
+    def new(self, type):
+        name = "IK" if type == "IK" else type.replace("_", " ").title().replace("Spline Ik", "Spline IK")
+        constraint = self._append(Constraint(self._unique_name(name), type))
+        self.active = constraint
+        return constraint
+
+    def remove(self, constraint):
+        if constraint not in self._items:
+            raise ReferenceError(f"Constraint '{constraint.name}' not found")
+        self._discard(constraint)
+
+    def clear(self):
+        self._items.clear()
+
+    def move(self, from_index, to_index):
+        self._items.insert(to_index, self._items.pop(from_index))
+
+    def copy(self, constraint):
+        copy = self._append(Constraint(self._unique_name(constraint.name), constraint.type))
+        copy.__dict__.update({k: v for k, v in constraint.__dict__.items() if k != "name"})
+        return copy
+
+
+class Camera(ID):
+    def __init__(self, name):
+        super().__init__(name)
+        self.type = "PERSP"
+        self.lens = 50.0
+        self.lens_unit = "MILLIMETERS"
+        self.angle = 2.0 * math.atan(36.0 / (2.0 * 50.0))
+        self.sensor_fit = "AUTO"
+        self.sensor_width = 36.0
+        self.sensor_height = 24.0
+        self.shift_x = 0.0
+        self.shift_y = 0.0
+        self.clip_start = 0.1
+        self.clip_end = 1000.0
+        self.ortho_scale = 6.0
+        self.dof = bpy_struct(
+            use_dof=False,
+            focus_object=None,
+            focus_distance=10.0,
+            aperture_fstop=2.8,
+            aperture_blades=0,
+            aperture_ratio=1.0,
+            aperture_rotation=0.0,
+        )
+
+
+class Light(ID):
+    def __init__(self, name, type="POINT"):
+        super().__init__(name)
+        self.type = type
+        self.color = [1.0, 1.0, 1.0]
+        self.energy = 1000.0 if type in ("POINT", "SPOT") else 1.0 if type == "SUN" else 10.0
+        self.specular_factor = 1.0
+        self.diffuse_factor = 1.0
+        self.volume_factor = 1.0
+        self.use_shadow = True
+        self.shadow_soft_size = 0.25
+        self.use_custom_distance = False
+        self.cutoff_distance = 40.0
+        self.shadow_buffer_bias = 1.0
+        self.use_contact_shadow = False
+        self.contact_shadow_distance = 0.2
+        self.contact_shadow_bias = 0.03
+        self.contact_shadow_thickness = 0.2
+        self.shadow_cascade_count = 4
+        self.shadow_cascade_exponent = 0.8
+        self.shadow_cascade_fade = 0.1
+        self.shadow_cascade_max_distance = 200.0
+        self.spot_size = math.radians(45.0)
+        self.spot_blend = 0.15
+        self.show_cone = False
+        self.shape = "SQUARE"
+        self.size = 0.25
+        self.size_y = 0.25
+        self.angle = math.radians(0.526)
+        self.cycles = bpy_struct(
+            cast_shadow=True,
+            is_caustics_light=False,
+            is_portal=False,
+            max_bounces=1024,
+            use_multiple_importance_sampling=True,
+        )
+
+
+# Armature ---------------------------------------------------------------------------------------
+
+
+def _distance(a, b):
+    return sum((x - y) ** 2 for x, y in zip(a, b)) ** 0.5
+
+
+class Bone(bpy_struct):
+    def __init__(self, edit_bone):
+        super().__init__(
+            name=edit_bone.name,
+            head_local=list(edit_bone.head),
+            tail_local=list(edit_bone.tail),
+            use_connect=edit_bone.use_connect,
+            use_deform=edit_bone.use_deform,
+            use_inherit_rotation=edit_bone.use_inherit_rotation,
+            hide=False,
+            select=edit_bone.select,
+            parent=None,
+            children=[],
+        )
+        self._roll = edit_bone.roll
+
+    @property
+    def head(self):
+        """Relative to the tail of the parent."""
+        offset = self.parent.tail_local if self.parent else [0.0, 0.0, 0.0]
+        return [a - b for a, b in zip(self.head_local, offset)]
+
+    @property
+    def tail(self):
+        return [a - b + c for a, b, c in zip(self.tail_local, self.head_local, self.head)]
+
+    @property
+    def length(self):
+        return _distance(self.head_local, self.tail_local)
+
+
+class EditBone(bpy_struct):
+    def __init__(self, name, armature):
+        super().__init__(
+            name=name,
+            head=[0.0, 0.0, 0.0],
+            tail=[0.0, 1.0, 0.0],
+            roll=0.0,
+            parent=None,
+            use_deform=True,
+            use_inherit_rotation=True,
+            use_local_location=True,
+            select=False,
+        )
+        self._use_connect = False
+        self._armature = armature
+
+    @property
+    def use_connect(self):
+        return self._use_connect
+
+    @use_connect.setter
+    def use_connect(self, value):
+        self._use_connect = value and self.parent is not None
+        if self._use_connect:
+            self.head = list(self.parent.tail)
+
+    @property
+    def length(self):
+        return _distance(self.head, self.tail)
+
+    @property
+    def children(self):
+        return [bone for bone in self._armature.edit_bones if bone.parent is self]
+
+
+class ArmatureBones(bpy_prop_collection):
+    @property
+    def active(self):
+        return next((bone for bone in self._items if bone.select), None)
+
+
+class ArmatureEditBones(bpy_prop_collection):
+    def __init__(self, armature):
+        super().__init__()
+        self._armature = armature
+        self.active = None
+
+    def new(self, name):
+        if not self._armature.is_editmode:
+            raise RuntimeError("Armature must be in edit mode to add edit bones")
+        return self._append(EditBone(self._unique_name(name), self._armature))
+
+    def remove(self, bone):
+        if bone not in self._items:
+            raise ReferenceError(f"Edit bone '{bone.name}' not found")
+        for child in bone.children:
+            child.parent = None
+        self._discard(bone)
+        if self.active is bone:
+            self.active = None
+
+
+class Armature(ID):
+    def __init__(self, name):
+        super().__init__(
+            name,
+            display_type="OCTAHEDRAL",
+            pose_position="POSE",
+            show_axes=False,
+            show_names=False,
+            is_editmode=False,
+        )
+        self.bones = ArmatureBones()
+        self.edit_bones = ArmatureEditBones(self)
+
+    def _enter_editmode(self):
+        self.is_editmode = True
+        self.edit_bones = ArmatureEditBones(self)
+        edit_bones = {}
+        for bone in self.bones:
+            edit_bone = self.edit_bones._append(EditBone(bone.name, self))
+            edit_bone.head = list(bone.head_local)
+            edit_bone.tail = list(bone.tail_local)
+            edit_bone.roll = bone._roll
+            edit_bone.use_deform = bone.use_deform
+            edit_bone.use_inherit_rotation = bone.use_inherit_rotation
+            edit_bones[bone.name] = edit_bone
+        for bone in self.bones:
+            if bone.parent is not None:
+                edit_bones[bone.name].parent = edit_bones[bone.parent.name]
+                edit_bones[bone.name]._use_connect = bone.use_connect
+
+    def _exit_editmode(self):
+        bones = {edit_bone.name: Bone(edit_bone) for edit_bone in self.edit_bones}
+        for edit_bone in self.edit_bones:
+            if edit_bone.parent is not None:
+                bone = bones[edit_bone.name]
+                bone.parent = bones[edit_bone.parent.name]
+                bone.parent.children.append(bone)
+        self.bones = ArmatureBones(bones.values())
+        self.edit_bones = ArmatureEditBones(self)
+        self.is_editmode = False
+
+
+class PoseBone(bpy_struct):
+    def __init__(self, bone):
+        super().__init__(
+            name=bone.name,
+            bone=bone,
+            parent=None,
+            children=[],
+            location=[0.0, 0.0, 0.0],
+            rotation_mode="QUATERNION",
+            rotation_quaternion=[1.0, 0.0, 0.0, 0.0],
+            rotation_euler=[0.0, 0.0, 0.0],
+            rotation_axis_angle=[0.0, 0.0, 1.0, 0.0],
+            scale=[1.0, 1.0, 1.0],
+            lock_location=[False, False, False],
+            lock_rotation=[False, False, False],
+            lock_rotation_w=False,
+            lock_rotations_4d=False,
+            lock_scale=[False, False, False],
+        )
+
+    @property
+    def head(self):
+        return list(self.bone.head_local)
+
+    @property
+    def tail(self):
+        return list(self.bone.tail_local)
+
+    @property
+    def length(self):
+        return self.bone.length
+
+
+class Pose(bpy_struct):
+    def __init__(self, armature):
+        super().__init__()
+        self.bones = bpy_prop_collection()
+        self._sync(armature)
+
+    def _sync(self, armature):
+        """Rebuilds the pose bones from the bones of `armature`, keeping the pose of existing bones."""
+        existing = {pose_bone.name: pose_bone for pose_bone in self.bones}
+        pose_bones = {}
+        for bone in armature.bones:
+            pose_bone = PoseBone(bone)
+            if bone.name in existing:
+                old = existing[bone.name]
+                pose_bone.__dict__.update(
+                    {key: value for key, value in old.__dict__.items() if key not in ("bone", "parent", "children")}
+                )
+            pose_bones[bone.name] = pose_bone
+        for bone in armature.bones:
+            if bone.parent is not None:
+                pose_bone = pose_bones[bone.name]
+                pose_bone.parent = pose_bones[bone.parent.name]
+                pose_bone.parent.children.append(pose_bone)
+        self.bones = bpy_prop_collection(pose_bones.values())
+
+
+_OBJECT_TYPES = {
+    Armature: "ARMATURE",
+    Camera: "CAMERA",
+    Light: "LIGHT",
+    Mesh: "MESH",
+}
+
+
+class Object(ID):
+    def __init__(self, name, object_data):
+        super().__init__(name)
+        self.data = object_data
+        self.type = _OBJECT_TYPES.get(type(object_data), "EMPTY")
+        self.mode = "OBJECT"
+        self.location = [0.0, 0.0, 0.0]
+        self.rotation_euler = [0.0, 0.0, 0.0]
+        self.rotation_quaternion = [1.0, 0.0, 0.0, 0.0]
+        self.rotation_mode = "XYZ"
+        self.scale = [1.0, 1.0, 1.0]
+        self.delta_location = [0.0, 0.0, 0.0]
+        self.delta_rotation_euler = [0.0, 0.0, 0.0]
+        self.delta_rotation_quaternion = [1.0, 0.0, 0.0, 0.0]
+        self.delta_scale = [1.0, 1.0, 1.0]
+        self.color = [1.0, 1.0, 1.0, 1.0]
+        self.parent = None
+        self.hide_viewport = False
+        self.hide_render = False
+        self.hide_select = False
+        self.empty_display_type = "PLAIN_AXES"
+        self.empty_display_size = 1.0
+        self.modifiers = ObjectModifiers()
+        self.constraints = ObjectConstraints()
+        self.rigid_body = None
+        self.rigid_body_constraint = None
+        self.pose = Pose(object_data) if isinstance(object_data, Armature) else None
+        self.vertex_groups = VertexGroups(self)
+        self.active_material_index = 0
+        self.active_shape_key_index = 0
+        self._active_material = None
+        self._selected = False
+        self._hidden = False
+        self._has_temporary_mesh = False
+
+    @property
+    def material_slots(self):
+        materials = getattr(self.data, "materials", None)
+        if materials is None:
+            return bpy_prop_collection()
+        return bpy_prop_collection(
+            bpy_struct(name=material.name if material else "", link="DATA", material=material)
+            for material in materials
+        )
+
+    @property
+    def active_shape_key(self):
+        shape_keys = getattr(self.data, "shape_keys", None)
+        if shape_keys is None or self.active_shape_key_index >= len(shape_keys.key_blocks):
+            return None
+        return shape_keys.key_blocks[self.active_shape_key_index]
+
+    def shape_key_add(self, name="Key", from_mix=True):
+        shape_keys = self.data.shape_keys
+        if shape_keys is None:
+            shape_keys = _new_id(_state.data.shape_keys, "Key")
+            shape_keys.user = self.data
+            self.data.shape_keys = shape_keys
+            coordinates = [vertex.co for vertex in self.data.vertices]
+        elif from_mix:
+            coordinates = shape_keys._mix()
+        else:
+            coordinates = [point.co for point in shape_keys.reference_key.data]
+        key_blocks = shape_keys.key_blocks
+        shape_key = key_blocks._append(
+            ShapeKey(
+                shape_keys,
+                key_blocks._unique_name(name),
+                coordinates,
+                shape_keys.reference_key,
+            )
+        )
+        self.active_shape_key_index = len(key_blocks) - 1
+        return shape_key
+
+    def shape_key_remove(self, key):
+        shape_keys = self.data.shape_keys
+        shape_keys.key_blocks._discard(key)
+        for shape_key in shape_keys.key_blocks:
+            if shape_key.relative_key is key:
+                shape_key.relative_key = shape_keys.reference_key
+        if not len(shape_keys.key_blocks):
+            self.shape_key_clear()
+        self.active_shape_key_index = max(self.active_shape_key_index - 1, 0)
+
+    def shape_key_clear(self):
+        if self.data.shape_keys is not None:
+            _state.data.shape_keys._discard(self.data.shape_keys)
+        self.data.shape_keys = None
+        self.active_shape_key_index = 0
+
+    @property
+    def active_material(self):
+        materials = getattr(self.data, "materials", None)
+        if materials is None:
+            return self._active_material
+        if self.active_material_index < len(materials):
+            return materials[self.active_material_index]
+        return None
+
+    @active_material.setter
+    def active_material(self, material):
+        materials = getattr(self.data, "materials", None)
+        if materials is None:
+            self._active_material = material
+        elif self.active_material_index < len(materials):
+            materials._items[self.active_material_index] = material
+        else:
+            materials.append(material)
+
+    @property
+    def children(self):
+        return tuple(obj for obj in _state.data.objects if obj.parent is self)
+
+    @property
+    def children_recursive(self):
+        children = []
+        for child in self.children:
+            children.append(child)
+            children.extend(child.children_recursive)
+        return tuple(children)
+
+    @property
+    def users_collection(self):
+        return tuple(
+            collection
+            for collection in _all_collections()
+            if self in collection.objects._items
+        )
+
+    @property
+    def users_scene(self):
+        return tuple(
+            scene
+            for scene in _state.data.scenes
+            if self in scene.collection.all_objects
+        )
+
+    def select_get(self, view_layer=None):
+        return self._selected
+
+    def select_set(self, state, view_layer=None):
+        self._selected = bool(state)
+
+    def hide_get(self, view_layer=None):
+        return self._hidden
+
+    def hide_set(self, state, view_layer=None):
+        self._hidden = bool(state)
+
+    def visible_get(self, view_layer=None, viewport=None):
+        return not (self._hidden or self.hide_viewport)
+
+    @property
+    def matrix_world(self):
+        """Row-major matrix composed of location, XYZ Euler rotation and scale (incl. parents)."""
+        if getattr(self, "_matrix_world", None) is not None:
+            return [list(row) for row in self._matrix_world]
+        sx, sy, sz = (math.sin(angle) for angle in self.rotation_euler)
+        cx, cy, cz = (math.cos(angle) for angle in self.rotation_euler)
+        rotation = [
+            [cy * cz, sx * sy * cz - cx * sz, cx * sy * cz + sx * sz],
+            [cy * sz, sx * sy * sz + cx * cz, cx * sy * sz - sx * cz],
+            [-sy, sx * cy, cx * cy],
+        ]
+        matrix = [
+            [rotation[row][col] * self.scale[col] for col in range(3)] + [self.location[row]]
+            for row in range(3)
+        ] + [[0.0, 0.0, 0.0, 1.0]]
+        if self.parent is not None:
+            parent = self.parent.matrix_world
+            matrix = [
+                [sum(parent[row][k] * matrix[k][col] for k in range(4)) for col in range(4)]
+                for row in range(4)
+            ]
+        return matrix
+
+    @matrix_world.setter
+    def matrix_world(self, matrix):
+        """Only the translation is decomposed, the full matrix is kept as is."""
+        matrix = [list(row) for row in matrix]
+        self.location = [matrix[row][3] for row in range(3)]
+        self._matrix_world = matrix
+
+    def calc_matrix_camera(self, depsgraph, x=1, y=1, scale_x=1.0, scale_y=1.0):
+        """Row-major projection matrix following `BKE_camera_params_compute_viewplane`."""
+        camera = self.data
+        if not isinstance(camera, Camera):
+            return [[float(row == col) for col in range(4)] for row in range(4)]
+        sensor_fit = camera.sensor_fit
+        if sensor_fit == "AUTO":
+            sensor_fit = "HORIZONTAL" if scale_x * x >= scale_y * y else "VERTICAL"
+        sensor_size = camera.sensor_height if camera.sensor_fit == "VERTICAL" else camera.sensor_width
+        ycor = scale_y / scale_x
+        viewfac = x if sensor_fit == "HORIZONTAL" else ycor * y
+        near, far = camera.clip_start, camera.clip_end
+        if camera.type == "ORTHO":
+            pixsize = camera.ortho_scale / viewfac
+        else:
+            pixsize = sensor_size * near / camera.lens / viewfac
+        dx = camera.shift_x * viewfac
+        dy = camera.shift_y * viewfac
+        left, right = (-0.5 * x + dx) * pixsize, (0.5 * x + dx) * pixsize
+        bottom, top = (-0.5 * ycor * y + dy) * pixsize, (0.5 * ycor * y + dy) * pixsize
+        if camera.type == "ORTHO":
+            return [
+                [2.0 / (right - left), 0.0, 0.0, -(right + left) / (right - left)],
+                [0.0, 2.0 / (top - bottom), 0.0, -(top + bottom) / (top - bottom)],
+                [0.0, 0.0, -2.0 / (far - near), -(far + near) / (far - near)],
+                [0.0, 0.0, 0.0, 1.0],
+            ]
+        return [
+            [2.0 * near / (right - left), 0.0, (right + left) / (right - left), 0.0],
+            [0.0, 2.0 * near / (top - bottom), (top + bottom) / (top - bottom), 0.0],
+            [0.0, 0.0, -(far + near) / (far - near), -2.0 * far * near / (far - near)],
+            [0.0, 0.0, -1.0, 0.0],
+        ]
+
+    def to_mesh(self, preserve_all_data_layers=False, depsgraph=None):
+        if not isinstance(self.data, Mesh):
+            # Blender returns `None` for objects without geometry, e.g., empties and cameras.
+            return None
+        self._has_temporary_mesh = True
+        return self.data
+
+    def to_mesh_clear(self):
+        self._has_temporary_mesh = False
+
+    def update_from_editmode(self):
+        return False
+
+
+# Node tree --------------------------------------------------------------------------------------
+
+_COLOR = [0.8, 0.8, 0.8, 1.0]
+_ZERO = [0.0, 0.0, 0.0]
+
+# Label, input sockets and output sockets (name, type, default value) of the mocked node types.
+_NODE_TYPES = {
+    "ShaderNodeBackground": (
+        "Background",
+        [("Color", "RGBA", _COLOR), ("Strength", "VALUE", 1.0)],
+        [("Background", "SHADER", None)],
+    ),
+    "ShaderNodeBsdfPrincipled": (
+        "Principled BSDF",
+        [
+            ("Base Color", "RGBA", _COLOR),
+            ("Metallic", "VALUE", 0.0),
+            ("Roughness", "VALUE", 0.5),
+            ("Alpha", "VALUE", 1.0),
+            ("Normal", "VECTOR", _ZERO),
+            ("Emission", "RGBA", [0.0, 0.0, 0.0, 1.0]),
+            ("Emission Strength", "VALUE", 1.0),
+        ],
+        [("BSDF", "SHADER", None)],
+    ),
+    "ShaderNodeMapping": (
+        "Mapping",
+        [
+            ("Vector", "VECTOR", _ZERO),
+            ("Location", "VECTOR", _ZERO),
+            ("Rotation", "VECTOR", _ZERO),
+            ("Scale", "VECTOR", [1.0, 1.0, 1.0]),
+        ],
+        [("Vector", "VECTOR", _ZERO)],
+    ),
+    "ShaderNodeMix": (
+        "Mix",
+        [("Factor", "VALUE", 0.5, "Factor_Float")]
+        + [
+            (name, type, default, f"{name}_{suffix}")
+            for type, default, suffix in (
+                ("VALUE", 0.0, "Float"),
+                ("VECTOR", _ZERO, "Vector"),
+                ("RGBA", [0.5, 0.5, 0.5, 1.0], "Color"),
+            )
+            for name in ("A", "B")
+        ],
+        [
+            ("Result", type, default, f"Result_{suffix}")
+            for type, default, suffix in (
+                ("VALUE", 0.0, "Float"),
+                ("VECTOR", _ZERO, "Vector"),
+                ("RGBA", _COLOR, "Color"),
+            )
+        ],
+    ),
+    "ShaderNodeNormalMap": (
+        "Normal Map",
+        [("Strength", "VALUE", 1.0), ("Color", "RGBA", [0.5, 0.5, 1.0, 1.0])],
+        [("Normal", "VECTOR", _ZERO)],
+    ),
+    "ShaderNodeOutputMaterial": (
+        "Material Output",
+        [
+            ("Surface", "SHADER", None),
+            ("Volume", "SHADER", None),
+            ("Displacement", "VECTOR", _ZERO),
+        ],
+        [],
+    ),
+    "ShaderNodeOutputWorld": (
+        "World Output",
+        [("Surface", "SHADER", None), ("Volume", "SHADER", None)],
+        [],
+    ),
+    "ShaderNodeTexCoord": (
+        "Texture Coordinate",
+        [],
+        [
+            (name, "VECTOR", _ZERO)
+            for name in ("Generated", "Normal", "UV", "Object", "Camera", "Window", "Reflection")
+        ],
+    ),
+    "ShaderNodeTexEnvironment": (
+        "Environment Texture",
+        [("Vector", "VECTOR", _ZERO)],
+        [("Color", "RGBA", _COLOR)],
+    ),
+    "ShaderNodeTexImage": (
+        "Image Texture",
+        [("Vector", "VECTOR", _ZERO)],
+        [("Color", "RGBA", _COLOR), ("Alpha", "VALUE", 1.0)],
+    ),
+    "ShaderNodeTexSky": (
+        "Sky Texture",
+        [("Vector", "VECTOR", _ZERO)],
+        [("Color", "RGBA", _COLOR)],
+    ),
+    "ShaderNodeUVMap": ("UV Map", [], [("UV", "VECTOR", _ZERO)]),
+}
+
+# Properties of the mocked node types besides their sockets.
+_NODE_PROPERTIES = {
+    "ShaderNodeBsdfPrincipled": dict(distribution="GGX", subsurface_method="RANDOM_WALK"),
+    "ShaderNodeMapping": dict(vector_type="POINT"),
+    "ShaderNodeMix": dict(data_type="FLOAT", blend_type="MIX", clamp_result=False),
+    "ShaderNodeNormalMap": dict(space="TANGENT", uv_map=""),
+    "ShaderNodeOutputMaterial": dict(is_active_output=True, target="ALL"),
+    "ShaderNodeOutputWorld": dict(is_active_output=True, target="ALL"),
+    "ShaderNodeTexEnvironment": dict(
+        image=None, interpolation="Linear", projection="EQUIRECTANGULAR"
+    ),
+    "ShaderNodeTexImage": dict(
+        image=None, interpolation="Linear", projection="FLAT", extension="REPEAT"
+    ),
+    "ShaderNodeTexSky": dict(
+        sky_type="NISHITA",
+        sun_disc=True,
+        sun_size=math.radians(0.545),
+        sun_intensity=1.0,
+        sun_elevation=math.radians(15.0),
+        sun_rotation=0.0,
+        altitude=0.0,
+        air_density=1.0,
+        dust_density=1.0,
+        ozone_density=1.0,
+    ),
+}
+
+
+class NodeSocket(bpy_struct):
+    def __init__(self, node, name, type, default_value, identifier=None, *, is_output):
+        super().__init__(
+            name=name,
+            identifier=identifier or name,
+            type=type,
+            node=node,
+            is_output=is_output,
+            enabled=True,
+            hide=False,
+        )
+        if default_value is not None:
+            self.default_value = list(default_value) if isinstance(default_value, list) else default_value
+
+    @property
+    def links(self):
+        return tuple(
+            link
+            for link in self.node.id_data.links
+            if link.from_socket is self or link.to_socket is self
+        )
+
+    @property
+    def is_linked(self):
+        return bool(self.links)
+
+
+class NodeSockets(bpy_prop_collection):
+    pass
+
+
+class Node(bpy_struct):
+    def __init__(self, tree, name, bl_idname):
+        label, inputs, outputs = _NODE_TYPES.get(bl_idname, (bl_idname, [], []))
+        super().__init__(
+            name=name,
+            label="",
+            bl_idname=bl_idname,
+            bl_label=label,
+            id_data=tree,
+            location=[0.0, 0.0],
+            width=140.0,
+            mute=False,
+            hide=False,
+            parent=None,
+        )
+        self.inputs = NodeSockets(NodeSocket(self, *socket, is_output=False) for socket in inputs)
+        self.outputs = NodeSockets(NodeSocket(self, *socket, is_output=True) for socket in outputs)
+        for key, value in _NODE_PROPERTIES.get(bl_idname, {}).items():
+            setattr(self, key, value)
+
+
+class Nodes(bpy_prop_collection):
+    def __init__(self, tree):
+        super().__init__()
+        self._tree = tree
+        self.active = None
+
+    def new(self, type):
+        label = _NODE_TYPES.get(type, (type,))[0]
+        return self._append(Node(self._tree, self._unique_name(label), type))
+
+    def remove(self, node):
+        for link in list(self._tree.links):
+            if link.from_node is node or link.to_node is node:
+                self._tree.links.remove(link)
+        if self.active is node:
+            self.active = None
+        self._discard(node)
+
+    def clear(self):
+        self._tree.links.clear()
+        self._items.clear()
+
+
+class NodeLink(bpy_struct):
+    pass
+
+
+class NodeLinks(bpy_prop_collection):
+    def new(self, input, output, verify_limits=True):
+        from_socket, to_socket = (input, output) if input.is_output else (output, input)
+        for link in list(self._items):
+            if link.to_socket is to_socket:
+                self.remove(link)
+        return self._append(
+            NodeLink(
+                from_node=from_socket.node,
+                from_socket=from_socket,
+                to_node=to_socket.node,
+                to_socket=to_socket,
+                is_valid=True,
+                is_muted=False,
+            )
+        )
+
+    def remove(self, link):
+        self._discard(link)
+
+    def clear(self):
+        self._items.clear()
+
+
+_NODE_TREE_TYPES = {
+    "CompositorNodeTree": "COMPOSITING",
+    "GeometryNodeTree": "GEOMETRY",
+    "ShaderNodeTree": "SHADER",
+    "TextureNodeTree": "TEXTURE",
+}
+
+
+class NodeTree(ID):
+    def __init__(self, name, type="ShaderNodeTree"):
+        super().__init__(name)
+        self.bl_idname = type
+        self.type = _NODE_TREE_TYPES.get(type, "CUSTOM")
+        self.nodes = Nodes(self)
+        self.links = NodeLinks()
+
+
+class _NodeTreeOwner(ID):
+    """ID with an embedded node tree that is created on demand via `use_nodes`."""
+
+    _default_nodes = ()
+
+    def __init__(self, name):
+        super().__init__(name)
+        self._use_nodes = False
+        self.node_tree = None
+
+    @property
+    def use_nodes(self):
+        return self._use_nodes
+
+    @use_nodes.setter
+    def use_nodes(self, value):
+        self._use_nodes = bool(value)
+        if self._use_nodes and self.node_tree is None:
+            self.node_tree = NodeTree("Shader Nodetree")
+            self.node_tree.is_embedded_data = True
+            created = [self.node_tree.nodes.new(node_type) for node_type in self._default_nodes]
+            if len(created) == 2:
+                self.node_tree.links.new(created[0].outputs[0], created[1].inputs[0])
+
+
+class World(_NodeTreeOwner):
+    _default_nodes = ("ShaderNodeBackground", "ShaderNodeOutputWorld")
+
+    def __init__(self, name):
+        super().__init__(name)
+        self.color = [0.05, 0.05, 0.05]
+
+
+class Material(_NodeTreeOwner):
+    _default_nodes = ("ShaderNodeBsdfPrincipled", "ShaderNodeOutputMaterial")
+
+    def __init__(self, name):
+        super().__init__(name)
+        self.diffuse_color = [0.8, 0.8, 0.8, 1.0]
+        self.metallic = 0.0
+        self.roughness = 0.4
+        self.blend_method = "OPAQUE"
+        self.shadow_method = "OPAQUE"
+
+
+class _PixelArray:
+    """Float array of `Image.pixels` that supports `foreach_get()` and `foreach_set()`."""
+
+    def __init__(self, values):
+        self._values = values
+
+    def __len__(self):
+        return len(self._values)
+
+    def __getitem__(self, index):
+        return self._values[index]
+
+    def foreach_get(self, seq):
+        self._check_length(seq)
+        _fill_sequence(seq, self._values)
+
+    def foreach_set(self, seq):
+        self._check_length(seq)
+        self._values = [float(value) for value in seq]
+
+    def _check_length(self, seq):
+        if len(seq) != len(self._values):
+            raise RuntimeError(
+                f"internal error setting the array (expected {len(self._values)}, got {len(seq)})"
+            )
+
+
+class Image(ID):
+    def __init__(self, name, width=0, height=0, alpha=False, float_buffer=False, **kwargs):
+        super().__init__(name)
+        self.filepath = ""
+        self.filepath_raw = ""
+        self.size = [width, height]
+        self.source = "GENERATED"
+        self.file_format = "PNG"
+        self.alpha_mode = "STRAIGHT"
+        self.channels = 4
+        self.depth = 128 if float_buffer else 32
+        self.is_float = float_buffer
+        self.is_dirty = False
+        self.use_half_precision = False
+        self.colorspace_settings = bpy_struct(name="Linear" if float_buffer else "sRGB")
+        self.packed_file = None
+        self._pixels = _PixelArray([0.0, 0.0, 0.0, 1.0] * (width * height))
+
+    @property
+    def has_data(self):
+        return len(self._pixels) > 0
+
+    @property
+    def pixels(self):
+        return self._pixels
+
+    @pixels.setter
+    def pixels(self, values):
+        self._pixels.foreach_set(values)
+        self.is_dirty = True
+
+    def pack(self):
+        self.packed_file = bpy_struct(size=len(self._pixels) * 4)
+
+    def unpack(self, method="USE_LOCAL"):
+        if self.packed_file is None:
+            raise RuntimeError(f"Error: Image '{self.name}' not packed")
+        self.packed_file = None
+
+    def save(self, filepath=None, quality=None):
+        filepath = filepath or self.filepath_raw or self.filepath
+        if not filepath:
+            raise RuntimeError(f"Error: Image '{self.name}' does not have any image data")
+        _touch(filepath)
+        self.filepath = filepath
+        self.is_dirty = False
+
+    def save_render(self, filepath, scene=None, quality=None):
+        _touch(os.fspath(filepath))
+
+    def reload(self):
+        self.is_dirty = False
+
+    def update(self):
+        pass
+
+    def scale(self, width, height, frame=0, tile_index=0):
+        self.size = [width, height]
+        self._pixels = _PixelArray([0.0, 0.0, 0.0, 1.0] * (width * height))
+
+
+class BlendDataImages:
+    """Mixin for `bpy.data.images.load()`."""
+
+    def load(self, filepath, check_existing=False):
+        filepath = str(filepath)
+        if check_existing:
+            for image in self._items:
+                if image.filepath == filepath:
+                    return image
+        if not os.path.isfile(filepath):
+            raise RuntimeError(f"Error: Cannot read '{filepath}': No such file or directory")
+        image = self._append(Image(self._unique_name(os.path.basename(filepath))))
+        image.filepath = filepath
+        image.filepath_raw = filepath
+        image.source = "FILE"
+        return image
+
+
+# Collection / Scene -----------------------------------------------------------------------------
+
+
+
+
+class CollectionObjects(bpy_prop_collection):
+    def link(self, object):
+        if object in self._items:
+            raise RuntimeError(f"Object '{object.name}' already in collection")
+        self._append(object)
+        object.users += 1
+
+    def unlink(self, object):
+        if object not in self._items:
+            raise RuntimeError(f"Object '{object.name}' not in collection")
+        self._discard(object)
+        object.users -= 1
+
+
+class CollectionChildren(bpy_prop_collection):
+    def link(self, child):
+        if child in self._items:
+            raise RuntimeError(f"Collection '{child.name}' already in collection")
+        self._append(child)
+        child.users += 1
+
+    def unlink(self, child):
+        self._discard(child)
+        child.users -= 1
+
+
+class Collection(ID):
+    def __init__(self, name):
+        super().__init__(name)
+        self.objects = CollectionObjects()
+        self.children = CollectionChildren()
+        self.hide_viewport = False
+        self.hide_render = False
+        self.hide_select = False
+        self.instance_offset = [0.0, 0.0, 0.0]
+        self.color_tag = "NONE"
+
+    @property
+    def all_objects(self):
+        objects = list(self.objects)
+        for child in self.children:
+            objects.extend(obj for obj in child.all_objects if obj not in objects)
+        return bpy_prop_collection(objects)
+
+    @property
+    def children_recursive(self):
+        children = []
+        for child in self.children:
+            children.append(child)
+            children.extend(child.children_recursive)
+        return children
+
+
+class LayerObjects(bpy_prop_collection):
+    def __init__(self, scene):
+        super().__init__()
+        self._scene = scene
+        self.active = None
+
+    @property
+    def _items(self):
+        return list(self._scene.collection.all_objects)
+
+    @_items.setter
+    def _items(self, value):
+        pass
+
+    @property
+    def selected(self):
+        return bpy_prop_collection(obj for obj in self._items if obj.select_get())
+
+
+class ViewLayer(bpy_struct):
+    def __init__(self, name, scene):
+        super().__init__(name=name, use=True, samples=0)
+        self.objects = LayerObjects(scene)
+
+    def update(self):
+        pass
+
+
+class ViewLayers(bpy_prop_collection):
+    def __init__(self, scene):
+        super().__init__()
+        self._scene = scene
+
+    def new(self, name):
+        return self._append(ViewLayer(self._unique_name(name), self._scene))
+
+    def remove(self, layer):
+        self._discard(layer)
+
+
+# Color depths that are supported by each file format, the first of which is the default.
+_COLOR_DEPTHS = {
+    "BMP": ("8",),
+    "IRIS": ("8",),
+    "PNG": ("8", "16"),
+    "JPEG": ("8",),
+    "JPEG2000": ("8", "12", "16"),
+    "TARGA": ("8",),
+    "TARGA_RAW": ("8",),
+    "CINEON": ("10",),
+    "DPX": ("8", "10", "12", "16"),
+    "OPEN_EXR_MULTILAYER": ("16", "32"),
+    "OPEN_EXR": ("16", "32"),
+    "HDR": ("32",),
+    "TIFF": ("8", "16"),
+    "WEBP": ("8",),
+}
+
+
+class ImageFormatSettings(bpy_struct):
+    def __init__(self):
+        self._file_format = "PNG"
+        self._color_depth = "8"
+        super().__init__(
+            color_mode="RGBA",
+            compression=15,
+            quality=90,
+        )
+
+    @property
+    def file_format(self):
+        return self._file_format
+
+    @file_format.setter
+    def file_format(self, value):
+        if value not in _COLOR_DEPTHS:
+            raise TypeError(f'bpy_struct: item.attr = val: enum "{value}" not found')
+        self._file_format = value
+        # Blender falls back to a supported color depth when the file format changes.
+        if self._color_depth not in _COLOR_DEPTHS[value]:
+            self._color_depth = _COLOR_DEPTHS[value][0]
+
+    @property
+    def color_depth(self):
+        return self._color_depth
+
+    @color_depth.setter
+    def color_depth(self, value):
+        depths = _COLOR_DEPTHS[self._file_format]
+        if value not in depths:
+            raise TypeError(f'bpy_struct: item.attr = val: enum "{value}" not found in {depths}')
+        self._color_depth = value
+
+
+def _render_engines():
+    """Identifiers of the render engines, where EEVEE was called `BLENDER_EEVEE_NEXT` in 4.2-4.5."""
+    eevee = "BLENDER_EEVEE_NEXT" if (4, 2) <= tuple(app.version[:2]) < (5, 0) else "BLENDER_EEVEE"
+    return (eevee, "BLENDER_WORKBENCH", "CYCLES")
+
+
+class RenderSettings(bpy_struct):
+    def __init__(self):
+        self._engine = _render_engines()[0]
+        super().__init__(
+            resolution_x=1920,
+            resolution_y=1080,
+            resolution_percentage=100,
+            pixel_aspect_x=1.0,
+            pixel_aspect_y=1.0,
+            fps=24,
+            fps_base=1.0,
+            film_transparent=False,
+            filepath="/tmp/",
+            use_file_extension=True,
+            image_settings=ImageFormatSettings(),
+        )
+
+    @property
+    def engine(self):
+        return self._engine
+
+    @engine.setter
+    def engine(self, value):
+        engines = _render_engines()
+        if value not in engines:
+            raise TypeError(f'bpy_struct: item.attr = val: enum "{value}" not found in {engines}')
+        self._engine = value
+
+    def frame_path(self, frame=-2147483648, preview=False, view=""):
+        return self.filepath
+
+
+# Rigid bodies -----------------------------------------------------------------------------------
+
+
+class RigidBodyWorld(bpy_struct):
+    def __init__(self, scene, collection):
+        super().__init__(
+            collection=collection,
+            constraints=None,
+            enabled=True,
+            point_cache=bpy_struct(frame_start=1, frame_end=250),
+            solver_iterations=10,
+            substeps_per_frame=10,
+            time_scale=1.0,
+            use_split_impulse=False,
+        )
+        self.id_data = scene
+
+    def _reset_cache(self):
+        """Like in Blender, changes to the simulated bodies invalidate all cached frames."""
+        self._cached_frame = self.point_cache.frame_start
+
+    def _step(self, frame):
+        """Only free fall is mocked: active bodies are treated as points that fall under gravity
+        onto the highest passive body (or endlessly if there is none).
+
+        Like in Blender, a frame is only simulated if its predecessor is cached, i.e. jumping
+        ahead of the last simulated frame leaves all bodies unchanged."""
+        start, end = self.point_cache.frame_start, self.point_cache.frame_end
+        cached = getattr(self, "_cached_frame", start)
+        if frame <= start:
+            self._cached_frame = start
+        elif frame == cached + 1:
+            self._cached_frame = frame
+        elif frame > cached:
+            return
+        scene = self.id_data
+        objects = list(self.collection.all_objects) if self.collection else []
+        passive = [obj for obj in objects if obj.rigid_body.type == "PASSIVE"]
+        floor = max((obj.location[2] for obj in passive), default=-math.inf)
+        fps = scene.render.fps / scene.render.fps_base
+        time = (min(frame, end) - start) / fps * self.time_scale
+        gravity = scene.gravity[2] if scene.use_gravity else 0.0
+        for obj in objects:
+            body = obj.rigid_body
+            if body.type != "ACTIVE" or not body.enabled or body.kinematic:
+                continue
+            if getattr(obj, "_rigid_body_rest", None) is None:
+                obj._rigid_body_rest = list(obj.location)
+            rest = obj._rigid_body_rest
+            if frame <= start or not self.enabled:
+                obj.location = list(rest)
+                continue
+            z = rest[2] + 0.5 * gravity * time**2
+            obj.location = [rest[0], rest[1], max(z, floor) if rest[2] >= floor else z]
+
+
+class RigidBodyObject(bpy_struct):
+    def __init__(self, type="ACTIVE"):
+        super().__init__(
+            type=type,
+            enabled=True,
+            kinematic=False,
+            mass=1.0,
+            friction=0.5,
+            restitution=0.0,
+            collision_shape="CONVEX_HULL",
+            collision_margin=0.04,
+            use_margin=False,
+            linear_damping=0.04,
+            angular_damping=0.1,
+        )
+
+
+class RigidBodyConstraint(bpy_struct):
+    def __init__(self, type="FIXED"):
+        super().__init__(
+            type=type,
+            enabled=True,
+            object1=None,
+            object2=None,
+            disable_collisions=True,
+            breaking_threshold=10.0,
+            use_breaking=False,
+            use_limit_ang_z=False,
+            limit_ang_z_lower=-math.pi / 4,
+            limit_ang_z_upper=math.pi / 4,
+            use_limit_lin_x=False,
+            limit_lin_x_lower=-1.0,
+            limit_lin_x_upper=1.0,
+            use_motor_ang=False,
+            motor_ang_target_velocity=1.0,
+            motor_ang_max_impulse=1.0,
+        )
+
+
+class Scene(ID):
+    def __init__(self, name):
+        super().__init__(name)
+        self.frame_start = 1
+        self.frame_end = 250
+        self.frame_step = 1
+        self.frame_current = 1
+        self.frame_float = 1.0
+        self.camera = None
+        self.world = None
+        self.use_gravity = True
+        self.gravity = [0.0, 0.0, -9.81]
+        self.render = RenderSettings()
+        self.eevee = bpy_struct(taa_render_samples=64, taa_samples=16)
+        self.cycles = bpy_struct(samples=4096, preview_samples=1024, device="CPU")
+        self.unit_settings = bpy_struct(
+            system="METRIC", length_unit="METERS", scale_length=1.0
+        )
+        self.cursor = bpy_struct(
+            location=[0.0, 0.0, 0.0], rotation_euler=[0.0, 0.0, 0.0]
+        )
+        self.collection = Collection("Scene Collection")
+        self.collection.is_embedded_data = True
+        self.view_layers = ViewLayers(self)
+        self.view_layers.new("ViewLayer")
+        self.rigidbody_world = None
+
+    @property
+    def objects(self):
+        return self.collection.all_objects
+
+    @property
+    def frame_current_final(self):
+        return float(self.frame_current)
+
+    def frame_set(self, frame, subframe=0.0):
+        self.frame_current = int(frame)
+        self.frame_float = frame + subframe
+        if self.rigidbody_world is not None:
+            self.rigidbody_world._step(self.frame_current)
+
+
+# --------------------------------------------------------------------------------------------------
+# bpy.data
+# --------------------------------------------------------------------------------------------------
+
+
+class BlendDataCollection(bpy_prop_collection):
+    def __init__(self, factory=None):
+        super().__init__()
+        self._factory = factory
+
+    def new(self, name, *args, **kwargs):
+        if self._factory is None:
+            raise NotImplementedError("Creating new data of this type is not mocked")
+        return self._append(self._factory(self._unique_name(name), *args, **kwargs))
+
+    def remove(self, item=None, do_unlink=True, do_id_user=True, do_ui_user=True, **kwargs):
+        # Blender names the removed data-block after its type, e.g., `object` or `collection`.
+        if item is None:
+            (item,) = kwargs.values()
+        if item not in self._items:
+            raise ReferenceError(f"'{item.name}' is not in this collection")
+        if do_unlink:
+            for collection in _all_collections():
+                if isinstance(item, Object) and item in collection.objects._items:
+                    collection.objects.unlink(item)
+        self._discard(item)
+
+
+class BlendDataImageCollection(BlendDataImages, BlendDataCollection):
+    pass
+
+
+class BlendDataObjects(BlendDataCollection):
+    def __init__(self):
+        super().__init__(Object)
+
+    def new(self, name, object_data):
+        obj = super().new(name, object_data)
+        if object_data is not None:
+            object_data.users += 1
+        return obj
+
+
+class Library(ID):
+    def __init__(self, name, filepath=""):
+        super().__init__(name)
+        self.filepath = filepath
+
+
+class BlendDataLibraries(BlendDataCollection):
+    """Libraries are mocked as JSON files that list the names of data-blocks by collection, as
+    written by the mocked `bpy.ops.wm.save_mainfile()`."""
+
+    def __init__(self):
+        super().__init__(Library)
+
+    def load(self, filepath, link=False, relative=False, assets_only=False):
+        return _LibraryLoad(os.fspath(filepath), link, relative)
+
+
+class _LibraryLoad:
+    def __init__(self, filepath, link, relative):
+        self._filepath = filepath
+        self._link = link
+        self._relative = relative
+
+    def __enter__(self):
+        with open(self._filepath) as file:
+            index = json.load(file)
+        attrs = _data_collection_names()
+        self._data_from = _types.SimpleNamespace(**{attr: list(index.get(attr, [])) for attr in attrs})
+        # Names listed under `_unreadable` are available but fail to load (e.g., corrupted data)
+        self._unreadable = set(index.get("_unreadable", []))
+        self._data_to = _types.SimpleNamespace(**{attr: [] for attr in attrs})
+        return self._data_from, self._data_to
+
+    def __exit__(self, exc_type, exc_value, traceback):
+        if exc_type is not None:
+            return False
+        library = None
+        if self._link:
+            filepath = self._filepath
+            if self._relative and _state.data.filepath:
+                filepath = "//" + os.path.relpath(filepath, os.path.dirname(_state.data.filepath))
+            library = _state.data.libraries.new(os.path.basename(self._filepath), filepath)
+        for attr in _data_collection_names():
+            available = getattr(self._data_from, attr)
+            loaded = []
+            for name in getattr(self._data_to, attr):
+                if name not in available or name in self._unreadable:
+                    loaded.append(None)
+                    continue
+                id_data = _new_id(getattr(_state.data, attr), name)
+                id_data.library = library
+                loaded.append(id_data)
+            setattr(self._data_to, attr, loaded)
+        return False
+
+
+class BlendData:
+    def __init__(self):
+        self.filepath = ""
+        self.is_dirty = False
+        self.is_saved = False
+        self.use_autopack = False
+        self.version = (3, 6, 0)
+        self.objects = BlendDataObjects()
+        self.meshes = BlendDataCollection(Mesh)
+        self.collections = BlendDataCollection(Collection)
+        self.scenes = BlendDataCollection(Scene)
+        self.libraries = BlendDataLibraries()
+        self.materials = BlendDataCollection(Material)
+        self.cameras = BlendDataCollection(Camera)
+        self.lights = BlendDataCollection(Light)
+        self.images = BlendDataImageCollection(Image)
+        self.node_groups = BlendDataCollection(NodeTree)
+        self.worlds = BlendDataCollection(World)
+        self.actions = BlendDataCollection(Action)
+        self.armatures = BlendDataCollection(Armature)
+        self.shape_keys = BlendDataCollection(Key)
+        for name in (
+            "brushes",
+            "cache_files",
+            "curves",
+            "fonts",
+            "grease_pencils",
+            "hair_curves",
+            "lattices",
+            "lightprobes",
+            "linestyles",
+            "masks",
+            "metaballs",
+            "movieclips",
+            "paint_curves",
+            "palettes",
+            "particles",
+            "pointclouds",
+            "screens",
+            "sounds",
+            "speakers",
+            "texts",
+            "textures",
+            "volumes",
+            "window_managers",
+            "workspaces",
+        ):
+            setattr(self, name, BlendDataCollection())
+
+    def orphans_purge(self, do_local_ids=True, do_linked_ids=True, do_recursive=False):
+        return 0
+
+    def user_map(self, subset=None, key_types=None, value_types=None):
+        return {}
+
+
+def _data_collection_of(id_data):
+    for name, collection in vars(_state.data).items():
+        if isinstance(collection, bpy_prop_collection) and id_data in collection._items:
+            return collection
+    return bpy_prop_collection()
+
+
+def _data_collection_names():
+    return [
+        name
+        for name, collection in vars(_state.data).items()
+        if isinstance(collection, BlendDataCollection)
+    ]
+
+
+def _new_id(collection, name):
+    if isinstance(collection, BlendDataObjects):
+        return collection.new(name, None)
+    if collection._factory is not None:
+        return collection.new(name)
+    return collection._append(ID(collection._unique_name(name)))
+
+
+def _all_collections():
+    collections = list(_state.data.collections)
+    collections.extend(scene.collection for scene in _state.data.scenes)
+    return collections
+
+
+# --------------------------------------------------------------------------------------------------
+# bpy.context
+# --------------------------------------------------------------------------------------------------
+
+
+class Context:
+    def __init__(self):
+        self._overrides = []
+
+    def _lookup(self, key, default):
+        for override in reversed(self._overrides):
+            if key in override:
+                return override[key]
+        return default
+
+    @property
+    def blend_data(self):
+        return _state.data
+
+    @property
+    def scene(self):
+        return self._lookup("scene", _state.scene)
+
+    @property
+    def view_layer(self):
+        return self._lookup("view_layer", self.scene.view_layers[0])
+
+    @property
+    def collection(self):
+        return self._lookup("collection", self.scene.collection)
+
+    @property
+    def active_object(self):
+        return self._lookup("active_object", self.view_layer.objects.active)
+
+    @property
+    def object(self):
+        return self._lookup("object", self.active_object)
+
+    @property
+    def selected_objects(self):
+        return self._lookup(
+            "selected_objects",
+            [obj for obj in self.view_layer.objects if obj.select_get()],
+        )
+
+    @property
+    def selected_editable_objects(self):
+        return self.selected_objects
+
+    @property
+    def visible_objects(self):
+        return [obj for obj in self.view_layer.objects if obj.visible_get()]
+
+    @property
+    def mode(self):
+        active = self.active_object
+        if active is None or active.mode == "OBJECT":
+            return "OBJECT"
+        return f"{active.mode}_{active.type}"
+
+    @property
+    def window(self):
+        return self._lookup("window", None)
+
+    @property
+    def screen(self):
+        return self._lookup("screen", None)
+
+    @property
+    def area(self):
+        return self._lookup("area", None)
+
+    @property
+    def region(self):
+        return self._lookup("region", None)
+
+    @property
+    def world(self):
+        return self.scene.world
+
+    def evaluated_depsgraph_get(self):
+        return Depsgraph(self.scene, self.view_layer)
+
+    def temp_override(self, **kwargs):
+        return _TempOverride(self, kwargs)
+
+    def __getattr__(self, name):
+        if name.startswith("_"):
+            raise AttributeError(name)
+        return self._lookup(name, None)
+
+
+class _TempOverride:
+    def __init__(self, context, overrides):
+        self._context = context
+        self._overrides = overrides
+
+    def __enter__(self):
+        self._context._overrides.append(self._overrides)
+        return self
+
+    def __exit__(self, *args):
+        self._context._overrides.remove(self._overrides)
+        return False
+
+
+class Depsgraph(bpy_struct):
+    def __init__(self, scene, view_layer):
+        super().__init__(scene=scene, view_layer=view_layer, mode="VIEWPORT")
+
+    @property
+    def objects(self):
+        return bpy_prop_collection(self.view_layer.objects)
+
+    @property
+    def ids(self):
+        return bpy_prop_collection(
+            id_data
+            for collection in (_state.data.objects, _state.data.meshes, _state.data.scenes)
+            for id_data in collection
+        )
+
+    @property
+    def scene_eval(self):
+        return self.scene
+
+    @property
+    def view_layer_eval(self):
+        return self.view_layer
+
+    def id_eval_get(self, id_data):
+        return id_data
+
+    def update(self):
+        pass
+
+
+# --------------------------------------------------------------------------------------------------
+# bpy.ops
+# --------------------------------------------------------------------------------------------------
+
+_FINISHED = {"FINISHED"}
+
+
+def _op_object_add(
+    radius=1.0,
+    type="EMPTY",
+    enter_editmode=False,
+    align="WORLD",
+    location=(0.0, 0.0, 0.0),
+    rotation=(0.0, 0.0, 0.0),
+    scale=(0.0, 0.0, 0.0),
+    **kwargs,
+):
+    name = type.capitalize()
+    object_data = None
+    if type == "MESH":
+        object_data = _state.data.meshes.new(name)
+    elif type == "CAMERA":
+        object_data = _state.data.cameras.new(name)
+    elif type == "LIGHT":
+        object_data = _state.data.lights.new(name, kwargs.get("light_type", "POINT"))
+    elif type == "ARMATURE":
+        object_data = _state.data.armatures.new(name)
+        object_data._enter_editmode()
+        object_data.edit_bones.new("Bone").tail = [0.0, 0.0, radius]
+        object_data._exit_editmode()
+    obj = _state.data.objects.new(name, object_data)
+    if object_data is None:
+        obj.type = type
+    obj.location = list(location)
+    obj.rotation_euler = list(rotation)
+    context.collection.objects.link(obj)
+    for other in context.view_layer.objects:
+        other.select_set(False)
+    obj.select_set(True)
+    context.view_layer.objects.active = obj
+    if enter_editmode:
+        obj.mode = "EDIT"
+    return _FINISHED
+
+
+def _op_object_empty_add(type="PLAIN_AXES", radius=1.0, **kwargs):
+    _op_object_add(type="EMPTY", **kwargs)
+    context.active_object.empty_display_type = type
+    context.active_object.empty_display_size = radius
+    return _FINISHED
+
+
+def _op_object_mode_set(mode="OBJECT", toggle=False):
+    active = context.active_object
+    if active is None:
+        raise RuntimeError("Operator bpy.ops.object.mode_set.poll() failed, context is incorrect")
+    mode = "OBJECT" if toggle and active.mode == mode else mode
+    if isinstance(active.data, Armature) and (active.mode == "EDIT") != (mode == "EDIT"):
+        if mode == "EDIT":
+            active.data._enter_editmode()
+        else:
+            active.data._exit_editmode()
+            active.pose._sync(active.data)
+    active.mode = mode
+    return _FINISHED
+
+
+def _op_object_parent_set(type="OBJECT", xmirror=False, keep_transform=False, **kwargs):
+    """Only parenting to objects and to armatures with automatic weights is mocked."""
+    parent = context.active_object
+    for obj in context.selected_objects:
+        if obj is parent:
+            continue
+        obj.parent = parent
+        if type == "ARMATURE_AUTO":
+            modifier = obj.modifiers.new("Armature", "ARMATURE")
+            modifier.object = parent
+            for bone in parent.data.bones:
+                if bone.use_deform and obj.vertex_groups.find(bone.name) == -1:
+                    obj.vertex_groups.new(name=bone.name)
+    return _FINISHED
+
+
+def _op_object_vertex_group_normalize_all(group_select_mode="", lock_active=True, **kwargs):
+    obj = context.object
+    active = obj.vertex_groups.active_index if lock_active else -1
+    for vertex in obj.data.vertices:
+        locked = sum(element.weight for element in vertex.groups if element.group == active)
+        total = sum(element.weight for element in vertex.groups if element.group != active)
+        if total > 0.0:
+            for element in vertex.groups:
+                if element.group != active:
+                    element.weight *= max(1.0 - locked, 0.0) / total
+    return _FINISHED
+
+
+def _op_object_vertex_group_limit_total(group_select_mode="", limit=4, **kwargs):
+    for vertex in context.object.data.vertices:
+        ranked = sorted(vertex.groups, key=lambda element: element.weight, reverse=True)
+        for element in ranked[limit:]:
+            vertex.groups._discard(element)
+    return _FINISHED
+
+
+def _op_object_select_all(action="TOGGLE"):
+    objects = list(context.view_layer.objects)
+    if action == "TOGGLE":
+        action = "DESELECT" if any(obj.select_get() for obj in objects) else "SELECT"
+    for obj in objects:
+        if action == "SELECT":
+            obj.select_set(True)
+        elif action == "DESELECT":
+            obj.select_set(False)
+        elif action == "INVERT":
+            obj.select_set(not obj.select_get())
+    return _FINISHED
+
+
+def _op_object_delete(use_global=False, confirm=True):
+    for obj in list(context.selected_objects):
+        _state.data.objects.remove(obj)
+        if context.view_layer.objects.active is obj:
+            context.view_layer.objects.active = None
+    return _FINISHED
+
+
+def _op_object_bake(type="COMBINED", use_selected_to_active=False, **kwargs):
+    if context.scene.render.engine != "CYCLES":
+        raise RuntimeError("Error: Current render engine does not support baking")
+    active = context.view_layer.objects.active
+    if use_selected_to_active and not [obj for obj in context.selected_objects if obj is not active]:
+        raise RuntimeError("Error: No valid selected objects")
+    for slot in active.material_slots:
+        node = slot.material.node_tree.nodes.active
+        if node is None or node.bl_idname != "ShaderNodeTexImage" or node.image is None:
+            raise RuntimeError(f'Error: No active image found in material "{slot.material.name}"')
+        # Baked values are mocked as mid-gray.
+        node.image.pixels = [0.5] * len(node.image.pixels)
+    return _FINISHED
+
+
+def _op_object_modifier_add(type="SUBSURF"):
+    active = context.active_object
+    active.modifiers.new(type.capitalize(), type)
+    return _FINISHED
+
+
+def _op_object_modifier_remove(modifier="", report=False):
+    active = context.active_object
+    active.modifiers.remove(active.modifiers[modifier])
+    return _FINISHED
+
+
+def _op_constraint_apply(constraint="", owner="OBJECT", report=False):
+    active = context.object
+    if active is None:
+        raise RuntimeError("Operator bpy.ops.constraint.apply.poll() failed, context is incorrect")
+    applied = active.constraints[constraint]
+    active.location = applied.evaluate(active)
+    active.constraints.remove(applied)
+    return _FINISHED
+
+
+def _rigidbody_world_ensure(scene):
+    if scene.rigidbody_world is None:
+        collection = _state.data.collections.new("RigidBodyWorld")
+        scene.rigidbody_world = RigidBodyWorld(scene, collection)
+    return scene.rigidbody_world
+
+
+def _op_rigidbody_world_add():
+    _rigidbody_world_ensure(context.scene)
+    return _FINISHED
+
+
+def _op_rigidbody_world_remove():
+    context.scene.rigidbody_world = None
+    return _FINISHED
+
+
+def _op_rigidbody_object_add(type="ACTIVE"):
+    obj = context.object
+    world = _rigidbody_world_ensure(context.scene)
+    if obj.rigid_body is None:
+        obj.rigid_body = RigidBodyObject(type)
+        world.collection.objects.link(obj)
+        world._reset_cache()
+    return _FINISHED
+
+
+def _rigidbody_object_remove(obj):
+    world = context.scene.rigidbody_world
+    if obj.rigid_body is not None and world is not None:
+        world.collection.objects.unlink(obj)
+        world._reset_cache()
+    obj.rigid_body = None
+    obj._rigid_body_rest = None
+
+
+def _op_rigidbody_object_remove():
+    _rigidbody_object_remove(context.object)
+    return _FINISHED
+
+
+def _op_rigidbody_objects_remove():
+    for obj in context.selected_objects:
+        _rigidbody_object_remove(obj)
+    return _FINISHED
+
+
+def _op_rigidbody_constraint_add(type="FIXED"):
+    _rigidbody_world_ensure(context.scene)._reset_cache()
+    context.object.rigid_body_constraint = RigidBodyConstraint(type)
+    return _FINISHED
+
+
+def _op_rigidbody_constraint_remove():
+    context.object.rigid_body_constraint = None
+    if context.scene.rigidbody_world is not None:
+        context.scene.rigidbody_world._reset_cache()
+    return _FINISHED
+
+
+def _op_rigidbody_bake_to_keyframes(frame_start=1, frame_end=250, step=1):
+    scene = context.scene
+    objects = [obj for obj in context.selected_objects if obj.rigid_body is not None]
+    frames = list(range(frame_start, frame_end + 1, step))
+    locations = {obj.name: [] for obj in objects}
+    # Every frame is simulated, but only every `step` frame is keyed.
+    for frame in range(frame_start, frame_end + 1):
+        scene.frame_set(frame)
+        if frame in frames:
+            for obj in objects:
+                locations[obj.name].append(list(obj.location))
+    for obj in objects:
+        action = _state.data.actions.new(f"{obj.name}Action")
+        obj.animation_data_create().action = action
+        for index in range(3):
+            fcurve = action.fcurves.new("location", index)
+            for frame, location in zip(frames, locations[obj.name]):
+                fcurve.keyframe_points.insert(frame, location[index])
+        _rigidbody_object_remove(obj)
+    return _FINISHED
+
+
+def _op_wm_read_factory_settings(use_empty=False):
+    reset(use_empty=use_empty)
+    return _FINISHED
+
+
+def _op_import_scene_obj(filepath="", **kwargs):
+    """Imports a single mesh object with one material, named after the stem of `filepath`."""
+    name = os.path.splitext(os.path.basename(filepath))[0]
+    mesh = _state.data.meshes.new(name)
+    mesh.from_pydata([[0.0, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]], [], [[0, 1, 2]])
+    mesh.materials.append(_state.data.materials.new(name))
+    obj = _state.data.objects.new(name, mesh)
+    context.collection.objects.link(obj)
+    return _FINISHED
+
+
+def _op_export_scene_obj(filepath="", use_selection=False, **kwargs):
+    """Writes the names of all exported objects into `filepath`, one per line."""
+    objects = context.selected_objects if use_selection else context.view_layer.objects
+    with open(filepath, "w") as file:
+        file.writelines(f"{obj.name}\n" for obj in objects)
+    return _FINISHED
+
+
+def _op_wm_save_mainfile(filepath="", **kwargs):
+    filepath = filepath or _state.data.filepath
+    with open(filepath, "w") as file:
+        json.dump({name: getattr(_state.data, name).keys() for name in _data_collection_names()}, file)
+    _state.data.filepath = filepath
+    _state.data.is_saved = True
+    _state.data.is_dirty = False
+    return _FINISHED
+
+
+def _op_wm_read_homefile(use_empty=False, **kwargs):
+    return _op_wm_read_factory_settings(use_empty=use_empty)
+
+
+def _op_render_render(
+    animation=False, write_still=False, use_viewport=False, layer="", scene=""
+):
+    render = context.scene.render
+    if write_still:
+        _touch(render.filepath)
+    elif animation:
+        for frame in range(
+            context.scene.frame_start,
+            context.scene.frame_end + 1,
+            context.scene.frame_step,
+        ):
+            _touch(os.path.join(render.filepath, f"{frame:04}"))
+    return _FINISHED
+
+
+def _touch(filepath):
+    os.makedirs(os.path.dirname(os.path.abspath(filepath)), exist_ok=True)
+    with open(filepath, "wb"):
+        pass
+
+
+class _Operator:
+    def __init__(self, module, name, implementation=None):
+        self._module = module
+        self._name = name
+        self._implementation = implementation
+
+    def __call__(self, *args, **kwargs):
+        ops.calls.append((f"{self._module}.{self._name}", dict(kwargs)))
+        if self._implementation is None:
+            return _FINISHED
+        return self._implementation(**kwargs)
+
+    def poll(self, *args):
+        return True
+
+    def idname(self):
+        return f"{self._module.upper()}_OT_{self._name}"
+
+    def idname_py(self):
+        return f"{self._module}.{self._name}"
+
+    def __repr__(self):
+        return f"# mock operator\nbpy.ops.{self._module}.{self._name}()"
+
+
+class _OperatorModule:
+    def __init__(self, name, implementations):
+        self._name = name
+        self._implementations = implementations
+
+    def __getattr__(self, name):
+        if name.startswith("_"):
+            raise AttributeError(name)
+        return _Operator(self._name, name, self._implementations.get(name))
+
+
+class _Ops(_types.ModuleType):
+    def __init__(self):
+        super().__init__("bpy.ops")
+        self.calls = []
+        self._implementations = {
+            "constraint": {
+                "apply": _op_constraint_apply,
+            },
+            "rigidbody": {
+                "bake_to_keyframes": _op_rigidbody_bake_to_keyframes,
+                "constraint_add": _op_rigidbody_constraint_add,
+                "constraint_remove": _op_rigidbody_constraint_remove,
+                "object_add": _op_rigidbody_object_add,
+                "object_remove": _op_rigidbody_object_remove,
+                "objects_remove": _op_rigidbody_objects_remove,
+                "world_add": _op_rigidbody_world_add,
+                "world_remove": _op_rigidbody_world_remove,
+            },
+            "object": {
+                "add": _op_object_add,
+                "bake": _op_object_bake,
+                "camera_add": lambda **kwargs: _op_object_add(type="CAMERA", **kwargs),
+                "light_add": lambda type="POINT", radius=1.0, **kwargs: _op_object_add(
+                    type="LIGHT", light_type=type, **kwargs
+                ),
+                "empty_add": _op_object_empty_add,
+                "pointcloud_add": lambda **kwargs: _op_object_add(type="POINTCLOUD", **kwargs),
+                "text_add": lambda radius=1.0, enter_editmode=False, **kwargs: _op_object_add(
+                    type="FONT", **kwargs
+                ),
+                "volume_add": lambda **kwargs: _op_object_add(type="VOLUME", **kwargs),
+                "armature_add": lambda radius=1.0, **kwargs: _op_object_add(
+                    type="ARMATURE", radius=radius, **kwargs
+                ),
+                "mode_set": _op_object_mode_set,
+                "parent_set": _op_object_parent_set,
+                "select_all": _op_object_select_all,
+                "delete": _op_object_delete,
+                "modifier_add": _op_object_modifier_add,
+                "modifier_remove": _op_object_modifier_remove,
+                "vertex_group_limit_total": _op_object_vertex_group_limit_total,
+                "vertex_group_normalize_all": _op_object_vertex_group_normalize_all,
+            },
+            "export_scene": {
+                "obj": _op_export_scene_obj,
+            },
+            "import_scene": {
+                "obj": _op_import_scene_obj,
+            },
+            "render": {
+                "render": _op_render_render,
+            },
+            "wm": {
+                "read_factory_settings": _op_wm_read_factory_settings,
+                "read_homefile": _op_wm_read_homefile,
+                "save_mainfile": _op_wm_save_mainfile,
+            },
+        }
+
+    def __getattr__(self, name):
+        if name.startswith("_"):
+            raise AttributeError(name)
+        return _OperatorModule(name, self._implementations.setdefault(name, {}))
+
+
+# --------------------------------------------------------------------------------------------------
+# bpy.app
+# --------------------------------------------------------------------------------------------------
+
+app = _types.SimpleNamespace(
+    binary_path="",
+    tempdir="/tmp/",
+    background=True,
+    factory_startup=True,
+    version=(3, 6, 0),
+    version_file=(3, 6, 0),
+    version_cycle="release",
+    version_string="3.6.0 (mock)",
+    build_options=_types.SimpleNamespace(),
+    handlers=_types.SimpleNamespace(),
+)
+
+# --------------------------------------------------------------------------------------------------
+# State
+# --------------------------------------------------------------------------------------------------
+
+is_mock = True
+
+types = _types.SimpleNamespace(
+    **{
+        name: value
+        for name, value in dict(globals()).items()
+        if isinstance(value, type) and issubclass(value, (bpy_struct, bpy_prop_collection))
+    }
+)
+
+context = Context()
+ops = _Ops()
+data = None
+
+_state = _types.SimpleNamespace(data=None, scene=None)
+
+
+def reset(use_empty=False):
+    """Restores the initial state, equivalent to `bpy.ops.wm.read_factory_settings()`."""
+    global data
+    _state.data = BlendData()
+    _state.scene = _state.data.scenes.new("Scene")
+    data = _state.data
+    context._overrides.clear()
+    ops.calls.clear()
+
+
+reset()
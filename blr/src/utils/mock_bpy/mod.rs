@@ -0,0 +1,101 @@
+//! Pure-Python stand-in for the Python module of Blender (`bpy`) that allows testing code built on
+//! top of `blr` without Blender being installed.
+//!
+//! The mocked module covers `bpy.app`, `bpy.context`, `bpy.data` and `bpy.types` for basic
+//! mesh, object, modifier and scene behaviour. Operators of `bpy.ops` that are not mocked
+//! explicitly are accepted as no-op stubs, and every call is recorded in `bpy.ops.calls`.
+//!
+//! # Examples
+//!
+//! ```
+//! use blr::{mock_bpy::with_mock_bpy, Object, ObjectType};
+//!
+//! with_mock_bpy(|py| {
+//!     let object = Object::new_mesh(py, [1.0, 2.0, 3.0], [0.0, 0.0, 0.0])?;
+//!     assert_eq!(object.object_type(py)?, ObjectType::Mesh);
+//!     Ok(())
+//! })
+//! .unwrap();
+//! ```
+use crate::{error::BlError, result::Result};
+use pyo3::{
+    intern,
+    types::{PyDict, PyModule},
+    Python,
+};
+use std::sync::{Mutex, PoisonError};
+
+/// Source code of the mocked `bpy` module.
+const MOCK_BPY_SOURCE: &str = include_str!("bpy.py");
+
+/// Serializes access to the mocked module, which holds global state just like `bpy` does.
+static MOCK_BPY_LOCK: Mutex<()> = Mutex::new(());
+
+/// Injects the mocked `bpy` module into `sys.modules`, so that all subsequent imports of `bpy`
+/// (including those of `blr`) resolve to it. Calling this function again has no effect.
+///
+/// # Errors
+///
+/// Returns an error if a different `bpy` module has already been imported.
+pub fn install_mock_bpy(py: Python) -> Result<()> {
+    let sys_modules: &PyDict = py
+        .import(intern!(py, "sys"))?
+        .getattr(intern!(py, "modules"))?
+        .downcast()
+        .map_err(pyo3::PyErr::from)?;
+
+    match sys_modules.get_item(intern!(py, "bpy"))? {
+        Some(module) if is_mock_bpy(module) => Ok(()),
+        Some(_) => Err(BlError::DependencyError(
+            "The Python module of Blender (`bpy`) is already imported and cannot be mocked"
+                .to_string(),
+        )),
+        None => {
+            let module = PyModule::from_code(py, MOCK_BPY_SOURCE, "bpy.py", "bpy")?;
+            sys_modules.set_item(intern!(py, "bpy"), module)?;
+            sys_modules.set_item(intern!(py, "bpy.ops"), module.getattr(intern!(py, "ops"))?)?;
+            Ok(())
+        }
+    }
+}
+
+/// Restores the mocked `bpy` module to its initial state with a single empty scene.
+///
+/// # Errors
+///
+/// Returns an error if the mocked `bpy` module is not installed.
+pub fn reset_mock_bpy(py: Python) -> Result<()> {
+    let module = py.import(intern!(py, "bpy"))?;
+    if !is_mock_bpy(module) {
+        return Err(BlError::DependencyError(
+            "The mocked Python module of Blender (`bpy`) is not installed".to_string(),
+        ));
+    }
+    module.call_method0(intern!(py, "reset"))?;
+    Ok(())
+}
+
+/// Test harness that runs `f` with the mocked `bpy` module installed and freshly reset.
+///
+/// Calls are serialized across threads because the mocked module holds global state,
+/// which makes this function safe to use from tests that run in parallel.
+///
+/// # Errors
+///
+/// Returns an error if the mocked `bpy` module cannot be installed or reset.
+/// Furthermore, errors returned by `f` are propagated.
+pub fn with_mock_bpy<T>(f: impl FnOnce(Python) -> Result<T>) -> Result<T> {
+    let _guard = MOCK_BPY_LOCK.lock().unwrap_or_else(PoisonError::into_inner);
+    Python::with_gil(|py| {
+        install_mock_bpy(py)?;
+        reset_mock_bpy(py)?;
+        f(py)
+    })
+}
+
+fn is_mock_bpy(module: &pyo3::PyAny) -> bool {
+    module
+        .getattr(intern!(module.py(), "is_mock"))
+        .and_then(pyo3::PyAny::is_true)
+        .unwrap_or(false)
+}
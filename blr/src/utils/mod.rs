@@ -3,6 +3,8 @@
 pub mod app;
 pub mod error;
 pub(crate) mod macros;
+#[cfg(feature = "mock-bpy")]
+pub mod mock_bpy;
 pub mod python;
 pub mod requirements;
 pub mod result;
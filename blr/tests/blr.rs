@@ -8,7 +8,9 @@ mod tests {
             PlyExporter, StlExporter, UsdExporter, X3dExporter,
         },
         prelude::*,
-        BlResult, Object,
+        render::{RenderSettings, RenderSettingsBuilder},
+        BlResult, ColorDepth, ImageFileFormat, KeyframeInterpolation, Mesh, Object, RenderEngine,
+        RigidBodyType,
     };
     use pyo3::Python;
     use std::path::Path;
@@ -22,6 +24,10 @@ mod tests {
             parse_version(py)?;
             let blend = setup_scene(py)?;
             export(&blend)?;
+            render(py)?;
+            mesh_bulk_data(py)?;
+            keyframe_interpolation(py)?;
+            rigid_body_simulation(py)?;
             Ok(())
         })
     }
@@ -212,4 +218,149 @@ mod tests {
 
         Ok(())
     }
+
+    fn render(py: Python) -> BlResult<()> {
+        // Arrange
+        let output = tempdir().unwrap();
+        let blend = setup_scene(py)?;
+        let camera = Object::new_camera(
+            py,
+            [0.0, -5.0, 0.0],
+            [std::f32::consts::FRAC_PI_2, 0.0, 0.0],
+        )?;
+        let mut scene = bpy::context::scene(py)?;
+        scene.set_camera(py, Some(&camera))?;
+        let settings = RenderSettingsBuilder::default()
+            .engine(RenderEngine::BlenderWorkbench)
+            .resolution_x(32)
+            .resolution_y(32)
+            .file_format(ImageFileFormat::Png)
+            .color_depth(ColorDepth::Bits16)
+            .build();
+
+        // Act
+        settings.apply(py, &mut scene)?;
+        let still = blend.render_still(py, output.path().join("still"))?;
+
+        // Assert
+        assert_eq!(still, output.path().join("still.png"));
+        assert!(still.is_file());
+        let applied = RenderSettings::from_scene(py, &scene)?;
+        assert_eq!(applied.color_depth, ColorDepth::Bits16);
+
+        // Act + Assert (EEVEE is resolved to the identifier of the running Blender version)
+        RenderSettingsBuilder::default()
+            .engine(RenderEngine::BlenderEevee)
+            .build()
+            .apply(py, &mut scene)?;
+        assert_eq!(
+            RenderSettings::from_scene(py, &scene)?.engine,
+            RenderEngine::eevee(py)?
+        );
+        Ok(())
+    }
+
+    fn mesh_bulk_data(py: Python) -> BlResult<()> {
+        // Arrange
+        let _blend = BlendProject::empty(py)?;
+        let object = Object::new_mesh_primitive_cube(
+            py,
+            2.0,
+            true,
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [1.0, 1.0, 1.0],
+        )?;
+        let mut mesh: Mesh = object.try_into()?;
+
+        // Act
+        let positions = mesh.vertex_positions(py)?;
+        let shifted: Vec<[f32; 3]> = positions.iter().map(|&[x, y, z]| [x, y, z + 1.0]).collect();
+        mesh.set_vertex_positions(py, &shifted)?;
+
+        // Assert
+        assert_eq!(positions.len(), 8);
+        assert!(positions
+            .iter()
+            .all(|position| position.iter().all(|value| value.abs() == 1.0)));
+        assert_eq!(mesh.vertex_positions(py)?, shifted);
+        assert_eq!(mesh.loop_triangle_indices(py)?.len(), 12);
+        assert!(mesh.set_vertex_positions(py, &shifted[..2]).is_err());
+        Ok(())
+    }
+
+    fn keyframe_interpolation(py: Python) -> BlResult<()> {
+        // Arrange
+        let _blend = BlendProject::empty(py)?;
+        let object = Object::new_mesh_primitive_cube(
+            py,
+            1.0,
+            true,
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [1.0, 1.0, 1.0],
+        )?;
+
+        // Act
+        let mut fcurves = object.insert_keyframes(
+            py,
+            "location",
+            &[1.0, 11.0],
+            &[[0.0, 0.0, 0.0], [1.0, 2.0, 3.0]],
+            KeyframeInterpolation::Sine,
+        )?;
+        fcurves[1].set_interpolation(py, KeyframeInterpolation::Back)?;
+
+        // Assert (the integer values written in bulk match the interpolation modes of Blender)
+        for (fcurve, interpolation) in [
+            (&fcurves[0], KeyframeInterpolation::Sine),
+            (&fcurves[1], KeyframeInterpolation::Back),
+            (&fcurves[2], KeyframeInterpolation::Sine),
+        ] {
+            let keyframe = fcurve.keyframe_points(py)?.get_index(py, 0)?;
+            assert_eq!(keyframe.interpolation(py)?, interpolation);
+        }
+        assert_eq!(fcurves[2].evaluate(py, 11.0)?, 3.0);
+        Ok(())
+    }
+
+    fn rigid_body_simulation(py: Python) -> BlResult<()> {
+        // Arrange
+        let _blend = BlendProject::empty(py)?;
+        let mut ground = Object::new_mesh_primitive_cube(
+            py,
+            1.0,
+            true,
+            [0.0, 0.0, 0.0],
+            [0.0, 0.0, 0.0],
+            [10.0, 10.0, 1.0],
+        )?;
+        ground.set_name(py, "Ground")?;
+        let mut crate_ = Object::new_mesh_primitive_cube(
+            py,
+            1.0,
+            true,
+            [0.0, 0.0, 5.0],
+            [0.0, 0.0, 0.0],
+            [1.0, 1.0, 1.0],
+        )?;
+        crate_.set_name(py, "Crate")?;
+        let scene = bpy::context::scene(py)?;
+        scene.rigid_body_world_ensure(py)?;
+        ground.rigid_body_add(py, RigidBodyType::Passive)?;
+        crate_.rigid_body_add(py, RigidBodyType::Active)?;
+
+        // Act
+        let poses = scene.simulate_rigid_bodies(py, [12, 24])?;
+        scene.apply_rigid_body_transforms(py, 48)?;
+
+        // Assert
+        assert!(poses["Crate"][0][2][3] < 5.0, "the crate falls");
+        assert!(poses["Crate"][1][2][3] < poses["Crate"][0][2][3]);
+        assert!((poses["Ground"][1][2][3]).abs() < 1e-6);
+        assert!(crate_.rigid_body(py)?.is_none());
+        assert!(crate_.location(py)?[2] < 5.0);
+        assert_eq!(scene.frame_current(py)?, 1);
+        Ok(())
+    }
 }
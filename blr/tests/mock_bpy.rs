@@ -0,0 +1,1679 @@
+#![cfg(feature = "mock-bpy")]
+
+use blr::{
+    bake::BakeBuilder,
+    constraints::{CopyLocationConstraint, LimitLocationConstraint, TrackToConstraint},
+    export::{BlendExport, BlendExporter, ExportScope, ObjExporter},
+    import::{BlendImport, ObjImporter},
+    library::{self, LibraryLoadOptions, LibraryLoadOptionsBuilder},
+    mock_bpy::{reset_mock_bpy, with_mock_bpy},
+    modifiers::DecimateModifier,
+    prelude::*,
+    render::{RenderSettings, RenderSettingsBuilder},
+    types::{
+        BoneSpec, EditModeGuard, EnvironmentTextureBuilder, Image, ImageOptionsBuilder, Node,
+        NodeGraph, NodeSpec, PbrInput, PbrMaterialBuilder, Scene, SceneCollection,
+        SkyTextureBuilder, World,
+    },
+    AttributeDomain, AttributeType, AxisXYZ, BakeType, BlError, Camera, CameraSensorFit,
+    CameraType, ColorDepth, Constraint, ConstraintSpace, ConstraintType, DriverTransformSpace,
+    DriverTransformType, DriverType, DriverVariableType, FModifierType, ImageFileFormat,
+    KeyframeInterpolation, LightAreaShape, LightType, Mesh, Modifier, ModifierType, Object,
+    ObjectType, RenderEngine, RigidBodyCollisionShape, RigidBodyConstraintType, RigidBodyType,
+    RotationMode, ShapeKeyInterpolation, TrackAxis, VertexGroupAssignMode,
+};
+use nalgebra::{Matrix3, Matrix4};
+use std::{collections::HashMap, ffi::OsStr, os::unix::ffi::OsStrExt, path::PathBuf};
+
+#[test]
+fn mock_bpy_version() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Act
+        let version = bpy_version(py)?;
+
+        // Assert
+        assert_eq!(version, semver::Version::new(3, 6, 0));
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_new_mesh() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Act
+        let object = Object::new_mesh(py, [1.0, 2.0, 3.0], [0.0, 0.0, 0.5])?;
+
+        // Assert
+        assert_eq!(object.object_type(py)?, ObjectType::Mesh);
+        assert_eq!(object.location(py)?, [1.0, 2.0, 3.0]);
+        assert_eq!(object.rotation_euler(py)?, [0.0, 0.0, 0.5]);
+        assert_eq!(Object::from_active(py)?.name(py)?, object.name(py)?);
+        assert_eq!(bpy::data::objects(py)?.keys(py)?, vec!["Mesh".to_string()]);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_modifier() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+
+        // Act
+        let modifier = DecimateModifier::new(py, &object, "decimate")?;
+
+        // Assert
+        assert_eq!(modifier.modifier_type(py)?, ModifierType::Decimate);
+        assert!(matches!(
+            object.modifiers(py)?.get(py, "decimate")?,
+            Modifier::Decimate(_)
+        ));
+
+        // Act
+        modifier.remove(py, &object)?;
+
+        // Assert
+        assert!(object.modifiers(py)?.keys(py)?.is_empty());
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_constraints() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let target = Object::new_mesh(py, [1.0, 2.0, 3.0], [0.0, 0.0, 0.0])?;
+
+        // Act
+        let mut track_to = TrackToConstraint::new(py, &object, "track")?;
+        track_to.set_target(py, Some(&target))?;
+        track_to.set_track_axis(py, TrackAxis::NegativeZ)?;
+        track_to.set_up_axis(py, "UP_Y")?;
+        track_to.set_owner_space(py, ConstraintSpace::Local)?;
+        let mut copy_location = CopyLocationConstraint::new(py, &object, "copy")?;
+        copy_location.set_target(py, Some(&target))?;
+        copy_location.set_use_z(py, false)?;
+        let mut limit_location = LimitLocationConstraint::new(py, &object, "limit")?;
+        limit_location.set_use_max_y(py, true)?;
+        limit_location.set_max_y(py, 1.5)?;
+
+        // Assert
+        let constraints = object.constraints(py)?;
+        assert_eq!(constraints.keys(py)?, ["track", "copy", "limit"]);
+        assert_eq!(track_to.constraint_type(py)?, ConstraintType::TrackTo);
+        assert_eq!(track_to.target(py)?.unwrap().name(py)?, target.name(py)?);
+        assert_eq!(track_to.owner_space(py)?, ConstraintSpace::Local);
+        assert!(matches!(
+            constraints.get(py, "copy")?,
+            Constraint::CopyLocation(_)
+        ));
+        assert_eq!(
+            constraints.find_of_type(py, ConstraintType::LimitLocation)?,
+            [2]
+        );
+        assert!(matches!(
+            TryInto::<CopyLocationConstraint>::try_into(constraints.get(py, "track")?),
+            Err(BlError::TypeError(_))
+        ));
+
+        // Act
+        copy_location.apply(py, &object)?;
+        limit_location.apply(py, &object)?;
+        track_to.remove(py, &object)?;
+
+        // Assert
+        assert_eq!(object.location(py)?, [1.0, 1.5, 0.0]);
+        assert!(object.constraints(py)?.is_empty(py)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_scene() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let mut scene: Scene = bpy::context::scene(py)?;
+
+        // Act
+        scene.set_frame_end(py, 10)?;
+        scene.frame_set(py, 5, 0.0)?;
+
+        // Assert
+        assert_eq!(scene.frame_end(py)?, 10);
+        assert_eq!(scene.frame_current(py)?, 5);
+        assert_eq!(scene.fps(py)?, 24);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_render() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let output = tempfile::tempdir()?;
+        let blend = BlendProject::empty(py)?;
+        let mut scene = bpy::context::scene(py)?;
+        let settings = RenderSettingsBuilder::default()
+            .file_format(ImageFileFormat::Png)
+            .color_depth(ColorDepth::Bits16)
+            .resolution_x(640)
+            .build();
+        scene.frame_set(py, 7, 0.0)?;
+
+        // Act
+        settings.apply(py, &mut scene)?;
+        let still = blend.render_still(py, output.path().join("still"))?;
+        let still_png = blend.render_still(py, output.path().join("still.PNG"))?;
+        let frames = blend.render_animation(py, output.path().join("frames"), 1..=3)?;
+
+        // Assert
+        let applied = RenderSettings::from_scene(py, &scene)?;
+        assert_eq!(applied.file_format, ImageFileFormat::Png);
+        assert_eq!(applied.color_depth, ColorDepth::Bits16);
+        assert_eq!(applied.resolution_x, 640);
+        assert_eq!(still, output.path().join("still.png"));
+        assert!(still.is_file());
+        assert_eq!(still_png, output.path().join("still.PNG"));
+        assert!(still_png.is_file());
+        assert_eq!(
+            frames,
+            ["0001.png", "0002.png", "0003.png"]
+                .map(|name| output.path().join("frames").join(name))
+        );
+        assert!(frames.iter().all(|frame| frame.is_file()));
+        assert_eq!(scene.frame_current(py)?, 7);
+        assert_eq!(applied.filepath, PathBuf::from("/tmp/"));
+        assert!(matches!(
+            blend.render_still(py, output.path().join("still.jpg")),
+            Err(BlError::ValueError(_))
+        ));
+        assert!(matches!(
+            blend.render_still(py, output.path()),
+            Err(BlError::ValueError(_))
+        ));
+        let non_utf8 = output.path().join(OsStr::from_bytes(b"still.\xff"));
+        assert!(matches!(
+            blend.render_still(py, non_utf8),
+            Err(BlError::ValueError(_))
+        ));
+        assert!(
+            RenderSettingsBuilder::default()
+                .file_format(ImageFileFormat::Jpeg)
+                .color_depth(ColorDepth::Bits16)
+                .build()
+                .apply(py, &mut scene)
+                .is_err(),
+            "JPEG only supports a color depth of 8 bits"
+        );
+
+        // Act + Assert (EEVEE is called `BLENDER_EEVEE_NEXT` in Blender 4.2 until 5.0)
+        let app = py.import("bpy")?.getattr("app")?;
+        let version = app.getattr("version")?;
+        app.setattr("version", (4, 2, 0))?;
+        let result = RenderSettings::default().apply(py, &mut scene);
+        let engine = RenderSettings::from_scene(py, &scene).map(|settings| settings.engine);
+        app.setattr("version", version)?;
+        result?;
+        assert_eq!(engine?, RenderEngine::BlenderEeveeNext);
+        assert_eq!(RenderEngine::eevee(py)?, RenderEngine::BlenderEevee);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_mesh_bulk_data() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let mut mesh: Mesh = object.try_into()?;
+        mesh.as_ref(py).call_method1(
+            "from_pydata",
+            (
+                vec![
+                    [0.0_f32, 0.0, 0.0],
+                    [1.0, 0.0, 0.0],
+                    [1.0, 1.0, 0.0],
+                    [0.0, 1.0, 0.0],
+                ],
+                Vec::<[usize; 2]>::new(),
+                vec![vec![0_usize, 1, 2, 3]],
+            ),
+        )?;
+
+        // Act + Assert (read)
+        assert_eq!(mesh.vertex_positions(py)?[2], [1.0, 1.0, 0.0]);
+        assert_eq!(mesh.polygon_vertex_indices(py)?, vec![vec![0, 1, 2, 3]]);
+        assert_eq!(mesh.loop_triangle_indices(py)?, vec![[0, 1, 2], [0, 2, 3]]);
+        assert!(mesh
+            .vertex_normal_vectors(py)?
+            .iter()
+            .all(|normal| *normal == [0.0, 0.0, 1.0]));
+
+        // Act + Assert (write)
+        let shifted: Vec<[f32; 3]> = mesh
+            .vertex_positions(py)?
+            .into_iter()
+            .map(|[x, y, z]| [x, y, z + 1.0])
+            .collect();
+        mesh.set_vertex_positions(py, &shifted)?;
+        assert_eq!(mesh.vertex_positions(py)?, shifted);
+        assert!(mesh.set_vertex_positions(py, &shifted[..2]).is_err());
+
+        // Act + Assert (attributes)
+        mesh.attributes(py)?
+            .new(py, "weight", AttributeType::Float, AttributeDomain::Point)?;
+        mesh.set_attribute_data(py, "weight", AttributeDomain::Point, &[0.5_f32; 4])?;
+        assert_eq!(
+            mesh.attribute_data::<f32>(py, "weight", AttributeDomain::Point)?,
+            vec![0.5; 4]
+        );
+        assert!(mesh
+            .attribute_data::<[f32; 3]>(py, "weight", AttributeDomain::Point)
+            .is_err());
+        assert!(mesh
+            .attribute_data::<f32>(py, "weight", AttributeDomain::Face)
+            .is_err());
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_mesh_attributes() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let mesh: Mesh = object.try_into()?;
+        mesh.as_ref(py).call_method1(
+            "from_pydata",
+            (
+                vec![[0.0_f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+                Vec::<[usize; 2]>::new(),
+                vec![vec![0_usize, 1, 2]],
+            ),
+        )?;
+        let mut attributes = mesh.attributes(py)?;
+
+        // Act
+        let color = attributes.new(
+            py,
+            "color",
+            AttributeType::FloatColor,
+            AttributeDomain::Corner,
+        )?;
+        let flags = attributes.new(py, "flags", AttributeType::Boolean, AttributeDomain::Face)?;
+        attributes.set_data(py, "color", &[[1.0_f32, 0.0, 0.0, 1.0]; 3])?;
+        attributes.set_data(py, "flags", &[true])?;
+
+        // Assert
+        assert_eq!(color.data_type(py)?, AttributeType::FloatColor);
+        assert_eq!(color.domain(py)?, AttributeDomain::Corner);
+        assert_eq!(
+            attributes.get_data::<[f32; 4]>(py, "color")?,
+            vec![[1.0, 0.0, 0.0, 1.0]; 3]
+        );
+        assert_eq!(flags.data::<bool>(py)?, vec![true]);
+        assert!(color.data::<[f32; 3]>(py).is_err());
+        assert!(attributes.set_data(py, "flags", &[true, false]).is_err());
+        assert_eq!(attributes.active(py)?.unwrap().name(py)?, "flags");
+
+        attributes.set_active(py, &color)?;
+        assert_eq!(attributes.active(py)?.unwrap().name(py)?, "color");
+        attributes.remove(py, &color)?;
+        assert_eq!(attributes.keys(py)?, vec!["flags".to_string()]);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_evaluated_geometry() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let mut object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let mesh: Mesh = object.clone().try_into()?;
+        mesh.as_ref(py).call_method1(
+            "from_pydata",
+            (
+                vec![[0.0_f32, 0.0, 0.0], [1.0, 0.0, 0.0], [0.0, 1.0, 0.0]],
+                Vec::<[usize; 2]>::new(),
+                vec![vec![0_usize, 1, 2]],
+            ),
+        )?;
+        object.set_location(py, [1.0, 2.0, 3.0])?;
+        object.set_scale(py, [2.0, 2.0, -1.0])?;
+        let mut offset = mesh.attributes(py)?.new(
+            py,
+            "offset",
+            AttributeType::FloatVector,
+            AttributeDomain::Point,
+        )?;
+        offset.set_data(py, &[[0.0_f32, 0.0, 1.0], [0.0, 0.0, 2.0], [0.0, 0.0, 3.0]])?;
+
+        // Act
+        let geometry = object.evaluated_geometry(py)?;
+        let output_offset = mesh.get_output_attribute_data::<[f32; 3]>(py, "offset")?;
+
+        // Assert
+        assert_eq!(
+            geometry.positions,
+            vec![[1.0, 2.0, 3.0], [3.0, 2.0, 3.0], [1.0, 4.0, 3.0]]
+        );
+        assert_eq!(geometry.normals, vec![[0.0, 0.0, -1.0]; 3]);
+        assert_eq!(geometry.indices, vec![[0, 2, 1]]);
+        assert_eq!(
+            output_offset,
+            vec![[0.0, 0.0, 1.0], [0.0, 0.0, 2.0], [0.0, 0.0, 3.0]]
+        );
+        assert!(mesh.get_output_attribute_data::<f32>(py, "offset").is_err());
+
+        // Act + Assert (temporary mesh is released on drop)
+        let depsgraph = bpy::context::evaluated_depsgraph_get(py)?;
+        let evaluated_mesh = object.evaluated_mesh(py, &depsgraph)?;
+        assert_eq!(evaluated_mesh.vertex_positions(py)?.len(), 3);
+        assert!(object
+            .getattr(py, "_has_temporary_mesh")?
+            .extract::<bool>(py)?);
+        drop(evaluated_mesh);
+        assert!(!object
+            .getattr(py, "_has_temporary_mesh")?
+            .extract::<bool>(py)?);
+
+        // Act + Assert (objects without geometry are rejected)
+        let camera = Object::new_camera(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        assert!(matches!(
+            camera.evaluated_mesh(py, &depsgraph),
+            Err(BlError::ValueError(_))
+        ));
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_library_append_link() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let library_filepath = std::env::temp_dir().join("blr_mock_bpy_library.blend");
+        Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?.set_name(py, "Asset")?;
+        bpy::data::collections(py)?.call_method1(py, "new", ("Props",))?;
+        bpy::ops::wm::save_mainfile(py, &library_filepath)?;
+        reset_mock_bpy(py)?;
+        let index = std::fs::read_to_string(&library_filepath)?.replacen(
+            "\"objects\": [",
+            "\"_unreadable\": [\"Broken\"], \"objects\": [\"Broken\", ",
+            1,
+        );
+        std::fs::write(&library_filepath, index)?;
+
+        // Act
+        let objects = library::append::<Object>(
+            py,
+            &library_filepath,
+            ["Asset", "Broken", "Missing"],
+            &LibraryLoadOptions::default(),
+        )?;
+        let collections = library::link::<SceneCollection>(
+            py,
+            &library_filepath,
+            ["Props"],
+            &LibraryLoadOptionsBuilder::default()
+                .instance_collections(true)
+                .build(),
+        )?;
+
+        // Assert
+        assert_eq!(objects.data_blocks.len(), 1);
+        assert_eq!(objects.data_blocks[0].name(py)?, "Asset");
+        assert_eq!(
+            objects.missing,
+            vec!["Missing".to_string(), "Broken".to_string()]
+        );
+        assert!(!objects.is_complete());
+        assert!(bpy::context::scene(py)?
+            .collection(py)?
+            .objects(py)?
+            .as_ref(py)
+            .contains(objects.data_blocks[0].as_ref(py))?);
+
+        assert!(collections.is_complete());
+        assert_eq!(collections.data_blocks[0].name(py)?, "Props");
+        assert_eq!(collections.instances.len(), 1);
+        assert_eq!(
+            collections.instances[0]
+                .getattr(py, "instance_type")?
+                .extract::<String>(py)?,
+            "COLLECTION"
+        );
+        assert_eq!(bpy::data::libraries(py)?.len(py)?, 1);
+
+        assert!(library::append::<Object>(
+            py,
+            library_filepath.with_extension("missing"),
+            ["Asset"],
+            &LibraryLoadOptions::default(),
+        )
+        .is_err());
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_import_result() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let filepath = std::env::temp_dir().join("blr_mock_bpy_import.obj");
+        std::fs::write(&filepath, "")?;
+        Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+
+        // Act
+        let imported = ObjImporter::default().import(&filepath)?;
+
+        // Assert
+        assert_eq!(imported.objects.len(), 1);
+        assert_eq!(imported.objects[0].name(py)?, "blr_mock_bpy_import");
+        assert_eq!(imported.meshes.len(), 1);
+        assert_eq!(imported.materials.len(), 1);
+        assert!(imported.images.is_empty());
+        assert_eq!(imported.root_objects(py)?.len(), 1);
+
+        // Act + Assert (placement into an existing collection that is not in the scene)
+        let existing = SceneCollection::new(py, "Imported")?;
+        let collection = imported.move_to_collection(py, "Imported")?;
+        assert!(collection.as_ref(py).eq(existing.as_ref(py))?);
+        let scene_children = SceneCollection::from_scene(py)?.children_recursive(py)?;
+        assert!(
+            scene_children
+                .iter()
+                .any(|child| child.as_ref(py).eq(collection.as_ref(py)).unwrap()),
+            "the collection is linked to the scene"
+        );
+        let users_collection: Vec<&pyo3::PyAny> = imported.objects[0]
+            .as_ref(py)
+            .getattr("users_collection")?
+            .extract()?;
+        assert_eq!(users_collection.len(), 1);
+        assert!(users_collection[0].eq(collection.as_ref(py))?);
+
+        let empty =
+            imported.parent_to_empty(py, "Root", [1.0, 2.0, 3.0], [0.0; 3], [2.0, 2.0, 2.0])?;
+        assert_eq!(empty.location(py)?, [1.0, 2.0, 3.0]);
+        assert_eq!(imported.objects[0].parent(py)?.name(py)?, "Root");
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_export_objects() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let filepath = std::env::temp_dir().join("blr_mock_bpy_export.obj");
+        let mut objects = Vec::new();
+        for name in ["A", "B", "C"] {
+            let mut object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+            object.set_name(py, name)?;
+            objects.push(object);
+        }
+        objects[2].select_set(py, true, None)?;
+        objects[2].set_active(py)?;
+        let exported_names = || -> BlResult<Vec<String>> {
+            Ok(std::fs::read_to_string(&filepath)?
+                .lines()
+                .map(str::to_string)
+                .collect())
+        };
+
+        // Act
+        ObjExporter::default().export_objects(&objects[..2], &filepath)?;
+
+        // Assert
+        assert_eq!(exported_names()?, vec!["A", "B"]);
+        let selected = bpy::context::selected_objects(py)?;
+        assert_eq!(selected.len(), 1);
+        assert_eq!(selected[0].name(py)?, "C");
+        assert_eq!(bpy::context::active_object(py)?.name(py)?, "C");
+
+        // Act + Assert (collection scope)
+        let collection = bpy::data::collections(py)?.call_method1(py, "new", ("Scoped",))?;
+        collection
+            .getattr(py, "objects")?
+            .call_method1(py, "link", (objects[1].as_ref(py),))?;
+        BlendExporter::from_filepath_extension(&filepath)?
+            .export_scoped(&ExportScope::Collection("Scoped".to_string()), &filepath)?;
+        assert_eq!(exported_names()?, vec!["B"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_camera_intrinsics_extrinsics() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let mut scene = bpy::context::scene(py)?;
+        scene.render(py)?.setattr("resolution_x", 640)?;
+        scene.render(py)?.setattr("resolution_y", 480)?;
+        let mut object = Object::new_camera(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let k = Matrix3::new(600.0, 0.0, 300.0, 0.0, 500.0, 250.0, 0.0, 0.0, 1.0);
+        let extrinsic = Matrix4::new(
+            0.0, -1.0, 0.0, 1.0, //
+            0.0, 0.0, -1.0, 2.0, //
+            1.0, 0.0, 0.0, 3.0, //
+            0.0, 0.0, 0.0, 1.0,
+        );
+
+        // Act
+        object.set_camera_intrinsic_matrix(py, &mut scene, &k)?;
+        object.set_camera_extrinsic_matrix(py, &extrinsic)?;
+
+        // Assert
+        let camera: Camera = object.clone().try_into()?;
+        assert_eq!(camera.camera_type(py)?, CameraType::Perspective);
+        assert_eq!(camera.sensor_fit(py)?, CameraSensorFit::Horizontal);
+        assert!((camera.lens(py)? - 600.0 * 36.0 / 640.0).abs() < 1e-4);
+        assert!(
+            (object.camera_intrinsic_matrix(py, &scene)? - k)
+                .abs()
+                .max()
+                < 1e-3
+        );
+        assert!(
+            (object.camera_extrinsic_matrix(py)? - extrinsic)
+                .abs()
+                .max()
+                < 1e-6
+        );
+        assert_eq!(object.location(py)?, [-3.0, 1.0, 2.0]);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_light() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_light(py, LightType::Area, [0.0, 0.0, 3.0], [0.0, 0.0, 0.0])?;
+        let mut light = object.light_data()?;
+
+        // Act
+        light.set_energy(py, 250.0)?;
+        light.set_color(py, [1.0, 0.5, 0.25])?;
+        light.set_area_shape(py, LightAreaShape::Rectangle)?;
+        light.set_area_size_y(py, 2.0)?;
+        light.set_cycles_max_bounces(py, 8)?;
+
+        // Assert
+        assert_eq!(light.light_type(py)?, LightType::Area);
+        assert_eq!(light.energy(py)?, 250.0);
+        assert_eq!(light.color(py)?, [1.0, 0.5, 0.25]);
+        assert_eq!(light.area_shape(py)?, LightAreaShape::Rectangle);
+        assert_eq!(light.area_size_y(py)?, 2.0);
+        assert_eq!(light.cycles_max_bounces(py)?, 8);
+        assert!(light.use_shadow(py)?);
+        let mesh = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        assert!(matches!(mesh.light_data(), Err(BlError::TypeError(_))));
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_world() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let hdri = tempfile::Builder::new().suffix(".hdr").tempfile()?;
+        let mut scene = bpy::context::scene(py)?;
+        let mut world = World::new(py, "Environment")?;
+
+        // Act
+        world.set_environment_texture(
+            py,
+            &EnvironmentTextureBuilder::default()
+                .filepath(hdri.path())
+                .rotation([0.0, 0.0, 1.5])
+                .strength(2.0)
+                .build(),
+        )?;
+        world.assign_to(py, &mut scene)?;
+
+        // Assert
+        assert_eq!(scene.world(py)?.unwrap().name(py)?, "Environment");
+        let tree = world.node_tree(py)?.unwrap();
+        let nodes = tree.nodes(py)?;
+        let node_types: Vec<String> = nodes
+            .as_ref(py)
+            .iter()?
+            .map(|node| node?.getattr("bl_idname")?.extract())
+            .collect::<pyo3::PyResult<_>>()?;
+        assert_eq!(
+            node_types,
+            [
+                "ShaderNodeTexCoord",
+                "ShaderNodeMapping",
+                "ShaderNodeTexEnvironment",
+                "ShaderNodeBackground",
+                "ShaderNodeOutputWorld",
+            ]
+        );
+        assert_eq!(tree.links(py)?.len(py)?, 4);
+        let background = nodes.as_ref(py).get_item("Background")?;
+        let strength: f32 = background
+            .getattr("inputs")?
+            .get_item("Strength")?
+            .getattr("default_value")?
+            .extract()?;
+        assert_eq!(strength, 2.0);
+
+        // Act + Assert (sky texture replaces the environment texture)
+        world.set_sky_texture(
+            py,
+            &SkyTextureBuilder::default()
+                .sun_elevation(0.5)
+                .sun_rotation(1.0)
+                .build(),
+        )?;
+        let nodes = tree.nodes(py)?;
+        assert_eq!(nodes.len(py)?, 3);
+        let sky = nodes.as_ref(py).get_item("Sky Texture")?;
+        assert_eq!(sky.getattr("sun_elevation")?.extract::<f32>()?, 0.5);
+        assert!(world
+            .set_environment_texture(
+                py,
+                &EnvironmentTextureBuilder::default()
+                    .filepath("/nonexistent.hdr")
+                    .build(),
+            )
+            .is_err());
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_node_sockets() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let mut world = World::new(py, "World")?;
+        world.set_use_nodes(py, true)?;
+        let tree = world.node_tree(py)?.unwrap();
+        let mut nodes = tree.nodes(py)?;
+        let mut mapping = nodes.new(py, "ShaderNodeMapping")?;
+
+        // Act
+        mapping.set_location(py, [-200.0, 50.0])?;
+        mapping.set_label(py, "Offset")?;
+        mapping.set_mute(py, true)?;
+        let mut scale = mapping.input(py, "Scale")?;
+        scale.set_default_value(py, [2.0_f32, 2.0, 2.0])?;
+        let background = nodes.get(py, "Background")?;
+        let mut color = background.inputs(py)?.get_index(py, 0)?;
+        color.set_default_value(py, [1.0_f32, 0.0, 0.0, 1.0])?;
+
+        // Assert
+        assert_eq!(mapping.name(py)?, "Mapping");
+        assert_eq!(mapping.bl_idname(py)?, "ShaderNodeMapping");
+        assert_eq!(mapping.label(py)?, "Offset");
+        assert_eq!(mapping.location(py)?, [-200.0, 50.0]);
+        assert!(mapping.mute(py)?);
+        assert!(mapping.parent(py)?.is_none());
+        assert_eq!(mapping.inputs(py)?.len(py)?, 4);
+        assert_eq!(
+            mapping.inputs(py)?.get_identifier(py, "Scale")?.name(py)?,
+            "Scale"
+        );
+        assert_eq!(scale.default_value::<[f32; 3]>(py)?, [2.0, 2.0, 2.0]);
+        assert_eq!(color.name(py)?, "Color");
+        assert_eq!(color.default_value::<[f32; 4]>(py)?, [1.0, 0.0, 0.0, 1.0]);
+        assert_eq!(
+            background.input(py, "Strength")?.default_value::<f32>(py)?,
+            1.0
+        );
+        assert!(matches!(
+            scale.default_value::<f32>(py),
+            Err(BlError::TypeError(_))
+        ));
+        assert!(matches!(
+            scale.set_default_value(py, true),
+            Err(BlError::TypeError(_))
+        ));
+
+        // Act + Assert (typed sockets can be linked)
+        let links = tree.links(py)?;
+        let link = links.new(
+            py,
+            &mapping.output(py, "Vector")?,
+            &background.input(py, "Color")?,
+            true,
+        )?;
+        assert_eq!(link.from_node(py)?.name(py)?, "Mapping");
+        assert!(background.input(py, "Color")?.is_linked(py)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_node_graph() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let tree = bpy::data::node_groups(py)?.new_named(py, "Graph", "ShaderNodeTree")?;
+        let mut graph = NodeGraph::new();
+        let coordinates = graph.add(NodeSpec::new("ShaderNodeTexCoord"));
+        let mapping = graph.add(
+            NodeSpec::new("ShaderNodeMapping")
+                .input("Scale", [2.0_f32, 2.0, 1.0])
+                .property("vector_type", "TEXTURE"),
+        );
+        let texture = graph.add(NodeSpec::new("ShaderNodeTexSky").label("Sky"));
+        let background = graph.add(
+            NodeSpec::new("ShaderNodeBackground")
+                .name("Lighting")
+                .input("Strength", 3.0_f32),
+        );
+        let output = graph.add(NodeSpec::new("ShaderNodeOutputWorld").location([500.0, 0.0]));
+        graph
+            .link(coordinates.output("Generated"), mapping.input("Vector"))
+            .link(mapping.output("Vector"), texture.input("Vector"))
+            .link(texture.output("Color"), background.input("Color"))
+            .link(background.output("Background"), output.input("Surface"));
+
+        // Act
+        let nodes = graph.build(py, &tree)?;
+
+        // Assert
+        assert_eq!(tree.nodes(py)?.len(py)?, 5);
+        assert_eq!(tree.links(py)?.len(py)?, 4);
+        assert_eq!(nodes[background].name(py)?, "Lighting");
+        assert_eq!(nodes[texture].label(py)?, "Sky");
+        assert_eq!(
+            nodes[mapping]
+                .getattr(py, "vector_type")?
+                .extract::<String>(py)?,
+            "TEXTURE"
+        );
+        assert_eq!(
+            nodes[mapping]
+                .input(py, "Scale")?
+                .default_value::<[f32; 3]>(py)?,
+            [2.0, 2.0, 1.0]
+        );
+        assert_eq!(nodes[coordinates].location(py)?, [-1200.0, 0.0]);
+        assert_eq!(nodes[background].location(py)?, [-300.0, 0.0]);
+        assert_eq!(nodes[output].location(py)?, [500.0, 0.0]);
+
+        // Act + Assert (invalid graphs are rejected without modifying the tree)
+        let node_names = tree.nodes(py)?.keys(py)?;
+        let mut graph = NodeGraph::new();
+        graph.clear_existing(true);
+        let mapping = graph.add(NodeSpec::new("ShaderNodeMapping"));
+        let background = graph.add(NodeSpec::new("ShaderNodeBackground"));
+        graph.link(mapping.output("Vectr"), background.input("Color"));
+        let err = graph.build(py, &tree).unwrap_err();
+        assert!(err.to_string().contains("'Vectr'"), "{err}");
+        assert!(err.to_string().contains("'Vector'"), "{err}");
+        assert_eq!(tree.nodes(py)?.keys(py)?, node_names);
+        assert_eq!(tree.links(py)?.len(py)?, 4);
+
+        let mut graph = NodeGraph::new();
+        graph.clear_existing(true);
+        let background = graph.add(NodeSpec::new("ShaderNodeBackground"));
+        graph.set_input(background.input("Strength"), 1.0_f32);
+        graph.set_input(mapping.input("Scale"), [1.0_f32; 3]);
+        let err = graph.build(py, &tree).unwrap_err();
+        assert!(err.to_string().contains("does not belong"), "{err}");
+        assert_eq!(tree.nodes(py)?.keys(py)?, node_names);
+
+        let mut graph = NodeGraph::new();
+        let background = graph.add(NodeSpec::new("ShaderNodeBackground"));
+        let mapping = graph.add(NodeSpec::new("ShaderNodeMapping"));
+        graph.link(background.output("Background"), mapping.input("Vector"));
+        assert!(matches!(graph.build(py, &tree), Err(BlError::TypeError(_))));
+
+        let mut graph = NodeGraph::new();
+        let background = graph.add(NodeSpec::new("ShaderNodeBackground"));
+        let output = graph.add(NodeSpec::new("ShaderNodeOutputWorld"));
+        graph.link(output.input("Surface"), background.output("Background"));
+        let err = graph.build(py, &tree).unwrap_err();
+        assert!(err.to_string().contains("declared as an input"), "{err}");
+        assert_eq!(tree.nodes(py)?.keys(py)?, node_names);
+
+        let mut graph = NodeGraph::new();
+        graph.add(NodeSpec::new("ShaderNodeBackground").input("Strength", true));
+        assert!(matches!(graph.build(py, &tree), Err(BlError::TypeError(_))));
+        assert_eq!(tree.nodes(py)?.keys(py)?, node_names);
+
+        // Act + Assert (existing nodes are replaced by a valid graph)
+        let mut graph = NodeGraph::new();
+        graph.clear_existing(true);
+        let background = graph.add(NodeSpec::new("ShaderNodeBackground"));
+        let output = graph.add(NodeSpec::new("ShaderNodeOutputWorld"));
+        graph
+            .set_input(background.input("Strength"), 0.5_f32)
+            .link(background.output("Background"), output.input("Surface"));
+        let nodes = graph.build(py, &tree)?;
+        assert_eq!(
+            tree.nodes(py)?.keys(py)?,
+            vec!["Background".to_string(), "World Output".to_string()]
+        );
+        assert_eq!(tree.links(py)?.len(py)?, 1);
+        assert_eq!(
+            nodes[background]
+                .input(py, "Strength")?
+                .default_value::<f32>(py)?,
+            0.5
+        );
+        assert!(nodes.get(mapping).is_none());
+        assert!(
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| nodes[mapping].clone()))
+                .is_err()
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_pbr_material() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let textures = tempfile::tempdir()?;
+        let [base_color, normal, occlusion] = ["albedo.png", "normal.png", "ao.png"].map(|name| {
+            let filepath = textures.path().join(name);
+            std::fs::write(&filepath, []).unwrap();
+            filepath
+        });
+        let mut object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+
+        // Act
+        let material = PbrMaterialBuilder::default()
+            .name("Brick")
+            .base_color(PbrInput::texture(&base_color))
+            .roughness(0.3)
+            .normal_map(&normal)
+            .normal_strength(0.5)
+            .ambient_occlusion_map(&occlusion)
+            .uv_map("UVMap")
+            .uv_scale([4.0, 4.0, 1.0])
+            .build()
+            .create(py)?;
+        object.set_active_material(py, &material)?;
+
+        // Assert
+        assert_eq!(material.name(py)?, "Brick");
+        let tree = material.node_tree(py)?;
+        let nodes = tree.nodes(py)?;
+        let bsdf = nodes.get(py, "Principled BSDF")?;
+        assert_eq!(bsdf.input(py, "Roughness")?.default_value::<f32>(py)?, 0.3);
+        assert_eq!(
+            bsdf.input(py, "Base Color")?.links(py)?.len()?,
+            1,
+            "base color is multiplied with ambient occlusion"
+        );
+        assert!(bsdf.input(py, "Normal")?.is_linked(py)?);
+        assert!(!bsdf.input(py, "Metallic")?.is_linked(py)?);
+        let color_spaces: Vec<(String, String)> = bpy::data::images(py)?
+            .as_ref(py)
+            .iter()?
+            .map(|image| {
+                let image = image?;
+                Ok((
+                    image.getattr("name")?.extract()?,
+                    image
+                        .getattr("colorspace_settings")?
+                        .getattr("name")?
+                        .extract()?,
+                ))
+            })
+            .collect::<pyo3::PyResult<_>>()?;
+        assert_eq!(
+            color_spaces,
+            [
+                ("albedo.png".to_string(), "sRGB".to_string()),
+                ("ao.png".to_string(), "Non-Color".to_string()),
+                ("normal.png".to_string(), "Non-Color".to_string()),
+            ]
+        );
+        assert_eq!(
+            nodes
+                .get(py, "UV Map")?
+                .getattr(py, "uv_map")?
+                .extract::<String>(py)?,
+            "UVMap"
+        );
+        assert_eq!(
+            nodes
+                .get(py, "Mapping")?
+                .input(py, "Scale")?
+                .default_value::<[f32; 3]>(py)?,
+            [4.0, 4.0, 1.0]
+        );
+        assert_eq!(
+            nodes
+                .get(py, "Normal Map")?
+                .input(py, "Strength")?
+                .default_value::<f32>(py)?,
+            0.5
+        );
+        assert_eq!(
+            object
+                .getattr(py, "active_material")?
+                .getattr(py, "name")?
+                .extract::<String>(py)?,
+            "Brick"
+        );
+
+        // Act (images are only shared between textures of the same color space)
+        let mortar = PbrMaterialBuilder::default()
+            .name("Mortar")
+            .base_color(PbrInput::texture(&base_color))
+            .roughness(PbrInput::texture(&base_color))
+            .metallic(PbrInput::texture(&base_color))
+            .build()
+            .create(py)?;
+
+        // Assert
+        let images = bpy::data::images(py)?;
+        assert_eq!(images.len(py)?, 4);
+        assert_eq!(images.get(py, "albedo.png")?.colorspace(py)?, "sRGB");
+        assert_eq!(
+            images.get(py, "albedo.png.001")?.colorspace(py)?,
+            "Non-Color"
+        );
+        let mortar_nodes = mortar.node_tree(py)?.nodes(py)?;
+        assert_eq!(
+            mortar_nodes
+                .get(py, "Image Texture.001")?
+                .getattr(py, "image")?
+                .getattr(py, "name")?
+                .extract::<String>(py)?,
+            "albedo.png.001"
+        );
+
+        // Act + Assert (a missing texture leaves neither the material nor its images behind)
+        let n_materials = bpy::data::materials(py)?.len(py)?;
+        let result = PbrMaterialBuilder::default()
+            .name("Broken")
+            .base_color(PbrInput::texture(&normal))
+            .roughness(PbrInput::texture(textures.path().join("missing.png")))
+            .build()
+            .create(py);
+        assert!(result.is_err());
+        assert_eq!(bpy::data::materials(py)?.len(py)?, n_materials);
+        assert_eq!(images.len(py)?, 4);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_image() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let output = tempfile::tempdir()?;
+        let scene = bpy::context::scene(py)?;
+        let mut image = Image::new(
+            py,
+            "Gradient",
+            4,
+            2,
+            &ImageOptionsBuilder::default()
+                .alpha(true)
+                .float_buffer(true)
+                .build(),
+        )?;
+        let pixels: Vec<f32> = (0..4 * 2 * 4).map(|i| i as f32 / 32.0).collect();
+
+        // Act
+        image.set_pixels(py, &pixels)?;
+        image.set_colorspace(py, "Non-Color")?;
+        image.pack(py)?;
+        image.save_as(
+            py,
+            output.path().join("gradient.exr"),
+            ImageFileFormat::OpenExr,
+        )?;
+        image.save_render(
+            py,
+            output.path().join("gradient.png"),
+            &scene,
+            ImageFileFormat::Png,
+            ColorDepth::Bits16,
+        )?;
+
+        // Assert
+        assert_eq!(image.size(py)?, [4, 2]);
+        assert!(image.is_float(py)?);
+        assert_eq!(image.pixels(py)?, pixels);
+        assert_eq!(image.colorspace(py)?, "Non-Color");
+        assert!(image.is_packed(py)?);
+        assert_eq!(image.file_format(py)?, ImageFileFormat::OpenExr);
+        assert!(output.path().join("gradient.exr").is_file());
+        assert!(output.path().join("gradient.png").is_file());
+        let image_settings = scene.render(py)?.getattr("image_settings")?;
+        assert_eq!(
+            image_settings.getattr("color_depth")?.extract::<String>()?,
+            "8",
+            "settings of the scene are restored after saving"
+        );
+        let file_format: String = image_settings.getattr("file_format")?.extract()?;
+        assert!(image
+            .save_render(
+                py,
+                output.path().join("gradient.png").join("gradient.exr"),
+                &scene,
+                ImageFileFormat::OpenExr,
+                ColorDepth::Bits32,
+            )
+            .is_err());
+        assert_eq!(
+            image_settings.getattr("file_format")?.extract::<String>()?,
+            file_format,
+            "settings of the scene are restored after a failed save"
+        );
+        assert_eq!(
+            image_settings.getattr("color_depth")?.extract::<String>()?,
+            "8"
+        );
+        assert!(matches!(
+            image.set_pixels(py, &pixels[..4]),
+            Err(BlError::ValueError(_))
+        ));
+        image.unpack(py, "USE_LOCAL")?;
+        assert!(!image.is_packed(py)?);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_bake() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let output = tempfile::tempdir()?;
+        let mut low_poly = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let high_poly = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let material = PbrMaterialBuilder::default()
+            .name("LowPoly")
+            .build()
+            .create(py)?;
+        low_poly.set_active_material(py, &material)?;
+        let plain = bpy::data::materials(py)?.new(py, "Plain")?;
+        Mesh::from(low_poly.data(py)?)
+            .materials(py)?
+            .append(py, &plain)?;
+        let active_node = material
+            .node_tree(py)?
+            .nodes(py)?
+            .getattr(py, "active")?
+            .extract::<Option<Node>>(py)?;
+        let bake = BakeBuilder::default()
+            .bake_type(BakeType::Normal)
+            .image_name("LowPoly_normal")
+            .width(8)
+            .height(4)
+            .cage_extrusion(0.1)
+            .filepath(output.path().join("normal.png"))
+            .build();
+
+        // Act
+        let image = bake.bake_selected_to_active(py, &low_poly, &[high_poly])?;
+
+        // Assert
+        assert_eq!(image.name(py)?, "LowPoly_normal");
+        assert_eq!(image.size(py)?, [8, 4]);
+        assert_eq!(image.colorspace(py)?, "Non-Color");
+        assert!(image.pixels(py)?.iter().all(|&value| value == 0.5));
+        assert!(output.path().join("normal.png").is_file());
+        let nodes = material.node_tree(py)?.nodes(py)?;
+        assert!(
+            !nodes.as_ref(py).contains("Image Texture")?,
+            "the target image node is removed after baking"
+        );
+        assert_eq!(
+            nodes
+                .getattr(py, "active")?
+                .extract::<Option<Node>>(py)?
+                .map(|node| node.name(py))
+                .transpose()?,
+            active_node.map(|node| node.name(py)).transpose()?,
+            "the active node is restored after baking"
+        );
+        assert!(
+            !plain.use_nodes(py)?,
+            "materials without nodes are restored after baking"
+        );
+        let image_names = bpy::data::images(py)?.keys(py)?;
+        assert!(
+            bake.bake_selected_to_active(py, &low_poly, &[low_poly.clone()])
+                .is_err(),
+            "baking without other selected objects fails"
+        );
+        assert_eq!(
+            bpy::data::images(py)?.keys(py)?,
+            image_names,
+            "the image of a failed bake is removed"
+        );
+        assert!(!nodes.as_ref(py).contains("Image Texture")?);
+        assert!(!plain.use_nodes(py)?);
+        let scene = bpy::context::scene(py)?;
+        assert_eq!(
+            scene.render(py)?.getattr("engine")?.extract::<String>()?,
+            "BLENDER_EEVEE",
+            "the render engine is restored after baking"
+        );
+        assert!(matches!(
+            bake.bake_selected_to_active(py, &low_poly, &[]),
+            Err(BlError::ValueError(_))
+        ));
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_keyframe_animation() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let frames = [1.0, 11.0, 21.0];
+        let locations = [[0.0, 0.0, 0.0], [1.0, 2.0, 3.0], [2.0, 4.0, 6.0]];
+
+        // Act
+        let mut fcurves = object.insert_keyframes(
+            py,
+            "location",
+            &frames,
+            &locations,
+            KeyframeInterpolation::Linear,
+        )?;
+        let noise = fcurves[1].add_noise_modifier(py, 5.0, 0.2)?;
+        fcurves[0].add_cycles_modifier(py)?;
+        fcurves[2].set_interpolation(py, KeyframeInterpolation::Constant)?;
+
+        // Assert
+        let action = object.animation_data(py)?.unwrap().action(py)?.unwrap();
+        assert_eq!(action.name(py)?, format!("{}Action", object.name(py)?));
+        assert_eq!(action.fcurves(py)?.len(py)?, 3);
+        assert_eq!(action.frame_range(py)?, [1.0, 21.0]);
+        assert_eq!(fcurves[1].array_index(py)?, 1);
+        assert_eq!(
+            fcurves[1].keyframes(py)?,
+            [[1.0, 0.0], [11.0, 2.0], [21.0, 4.0]]
+        );
+        assert_eq!(fcurves[0].evaluate(py, 6.0)?, 0.5);
+        assert_eq!(
+            fcurves[0].evaluate(py, 26.0)?,
+            0.5,
+            "cycles modifier repeats"
+        );
+        assert_eq!(fcurves[2].evaluate(py, 6.0)?, 0.0, "constant interpolation");
+        assert_eq!(noise.modifier_type(py)?, FModifierType::Noise);
+        assert_eq!(noise.strength(py)?, 0.2);
+        let keyframe = fcurves[2].keyframe_points(py)?.get_index(py, 1)?;
+        assert_eq!(keyframe.interpolation(py)?, KeyframeInterpolation::Constant);
+
+        // Act + Assert (keyframes are appended to the existing F-curves)
+        let mut fcurves = object.insert_keyframes(
+            py,
+            "location",
+            &[31.0],
+            &[[3.0, 6.0, 9.0]],
+            KeyframeInterpolation::Bezier,
+        )?;
+        assert_eq!(fcurves[2].keyframe_points(py)?.len(py)?, 4);
+        let keyframe_points = fcurves[0].keyframe_points(py)?;
+        assert_eq!(
+            keyframe_points.get_index(py, 0)?.interpolation(py)?,
+            KeyframeInterpolation::Linear,
+            "the interpolation of existing keyframes is kept"
+        );
+        assert_eq!(
+            keyframe_points.get_index(py, 3)?.interpolation(py)?,
+            KeyframeInterpolation::Bezier
+        );
+
+        // Act + Assert (easing modes are written with the integer values of Blender)
+        fcurves[1].set_interpolation(py, KeyframeInterpolation::Sine)?;
+        let fcurves = object.insert_keyframes(
+            py,
+            "location",
+            &[41.0],
+            &[[4.0, 8.0, 12.0]],
+            KeyframeInterpolation::Back,
+        )?;
+        let keyframe_points = fcurves[1].keyframe_points(py)?;
+        assert_eq!(
+            keyframe_points.get_index(py, 0)?.interpolation(py)?,
+            KeyframeInterpolation::Sine
+        );
+        assert_eq!(
+            keyframe_points.get_index(py, 4)?.interpolation(py)?,
+            KeyframeInterpolation::Back
+        );
+        assert!(matches!(
+            object.insert_keyframes(
+                py,
+                "scale",
+                &[1.0, 2.0],
+                &[[1.0, 1.0, 1.0]],
+                KeyframeInterpolation::Linear
+            ),
+            Err(BlError::ValueError(_))
+        ));
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_drivers() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let source = Object::new_mesh(py, [1.0, 2.0, 3.0], [0.0, 0.0, 0.0])?;
+        let other = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let target = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+
+        // Act
+        let driver =
+            target.add_property_driver(py, "location", 2, &source, "location.z", "var * 2")?;
+        let mut scale_driver = target.driver_add(py, "scale", 0)?.driver(py)?.unwrap();
+        scale_driver.set_driver_type(py, DriverType::Max)?;
+        scale_driver.add_transform(
+            py,
+            "x",
+            &source,
+            DriverTransformType::LocX,
+            DriverTransformSpace::WorldSpace,
+        )?;
+        let distance = scale_driver.add_distance(py, "distance", &source, &other)?;
+        let rotation = scale_driver.add_rotation_difference(py, "angle", &source, &other)?;
+
+        // Assert
+        assert_eq!(driver.driver_type(py)?, DriverType::Scripted);
+        assert_eq!(driver.expression(py)?, "var * 2");
+        let variable = driver.variables(py)?.get(py, "var")?;
+        assert_eq!(variable.variable_type(py)?, DriverVariableType::SingleProp);
+        let variable_target = variable.target(py, 0)?;
+        assert_eq!(variable_target.id_type(py)?, "OBJECT");
+        assert_eq!(variable_target.data_path(py)?, "location.z");
+        assert!(matches!(
+            variable.target(py, 1),
+            Err(BlError::ValueError(_))
+        ));
+
+        assert_eq!(scale_driver.variables(py)?.len(py)?, 3);
+        assert_eq!(distance.variable_type(py)?, DriverVariableType::LocDiff);
+        assert_eq!(distance.targets(py)?.len(), 2);
+        assert_eq!(
+            rotation.variable_type(py)?,
+            DriverVariableType::RotationDiff
+        );
+
+        let animation_data = target.animation_data(py)?.unwrap();
+        assert_eq!(animation_data.drivers(py)?.len(), 2);
+        let fcurve = animation_data.find_driver(py, "location", 2)?.unwrap();
+        assert_eq!(fcurve.driver(py)?.unwrap().expression(py)?, "var * 2");
+        assert!(animation_data.find_driver(py, "location", 0)?.is_none());
+
+        // Act + Assert
+        assert!(target.driver_remove(py, "scale", -1)?);
+        assert_eq!(animation_data.drivers(py)?.len(), 1);
+        assert!(matches!(
+            target.driver_add(py, "rotation_euler", -1),
+            Err(BlError::ValueError(_))
+        ));
+        assert_eq!(
+            animation_data.drivers(py)?.len(),
+            1,
+            "no drivers are added for an array without an index"
+        );
+        let fcurves = target.driver_add_all(py, "rotation_euler")?;
+        assert_eq!(fcurves.len(), 3);
+        assert_eq!(fcurves[2].array_index(py)?, 2);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_armature_joint_angles() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let bones = [
+            BoneSpec::new("base", [0.0, 0.0, 0.0], [0.0, 0.0, 1.0]).joint_axis(AxisXYZ::Z),
+            BoneSpec::new("upper_arm", [0.0, 0.0, 1.0], [0.0, 0.0, 2.0])
+                .parent("base")
+                .use_connect(true)
+                .joint_axis(AxisXYZ::X),
+            BoneSpec::new("gripper", [0.0, 0.0, 2.0], [0.0, 0.5, 2.0]).parent("upper_arm"),
+        ];
+        let mesh = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+
+        // Act
+        let armature_object = Object::new_armature(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0], &bones)?;
+        let pose_bones = armature_object.pose_bones(py)?;
+        let base = pose_bones.get(py, "base")?;
+        let mut upper_arm = pose_bones.get(py, "upper_arm")?;
+        upper_arm.set_rotation_mode(py, RotationMode::Xyz)?;
+        armature_object.set_joint_angles(
+            py,
+            &HashMap::from([("base".to_string(), 1.0), ("upper_arm".to_string(), 0.5)]),
+        )?;
+        armature_object.parent_with_automatic_weights(py, std::slice::from_ref(&mesh))?;
+
+        // Assert
+        let armature = armature_object.armature_data()?;
+        assert_eq!(
+            armature.bones(py)?.keys(py)?,
+            ["base", "upper_arm", "gripper"]
+        );
+        let gripper = armature.bones(py)?.get(py, "gripper")?;
+        assert_eq!(gripper.parent(py)?.unwrap().name(py)?, "upper_arm");
+        assert!(armature.bones(py)?.get(py, "upper_arm")?.use_connect(py)?);
+        assert!(!gripper.use_connect(py)?);
+        let [w, x, y, z] = base.rotation_quaternion(py)?;
+        assert_eq!([w, x, y], [0.5_f32.cos(), 0.0, 0.0]);
+        assert!((z - 0.5_f32.sin()).abs() < 1e-6);
+        assert_eq!(upper_arm.rotation_euler(py)?, [0.5, 0.0, 0.0]);
+        assert!(matches!(
+            armature_object.set_joint_angles(py, &HashMap::from([("gripper".to_string(), 1.0)])),
+            Err(BlError::ValueError(_))
+        ));
+        assert!(matches!(
+            armature_object.set_joint_angles(
+                py,
+                &HashMap::from([("base".to_string(), 0.0), ("elbow".to_string(), 1.0)])
+            ),
+            Err(BlError::ValueError(_))
+        ));
+        assert!(
+            (base.rotation_quaternion(py)?[3] - 0.5_f32.sin()).abs() < 1e-6,
+            "no joint angle is set if any bone is invalid"
+        );
+        let objects = bpy::data::objects(py)?.len(py)?;
+        assert!(matches!(
+            Object::new_armature(
+                py,
+                [0.0, 0.0, 0.0],
+                [0.0, 0.0, 0.0],
+                &[BoneSpec::new("orphan", [0.0, 0.0, 0.0], [0.0, 0.0, 1.0]).parent("missing")]
+            ),
+            Err(BlError::ValueError(_))
+        ));
+        assert_eq!(
+            bpy::data::objects(py)?.len(py)?,
+            objects,
+            "the armature object is removed if its bones cannot be added"
+        );
+        assert_eq!(bpy::data::armatures(py)?.len(py)?, 1);
+        assert!(matches!(
+            armature.add_bones(py, &bones),
+            Err(BlError::ValueError(_))
+        ));
+        assert_eq!(mesh.parent(py)?.name(py)?, armature_object.name(py)?);
+        assert_eq!(
+            mesh.modifiers(py)?
+                .get_of_type(py, ModifierType::Armature)?
+                .len(),
+            1
+        );
+
+        // Act + Assert (pose is kept when bones are added in edit mode)
+        {
+            let _edit_mode = EditModeGuard::new(py, &armature_object)?;
+            armature.add_bones(
+                py,
+                &[BoneSpec::new("finger", [0.0, 0.5, 2.0], [0.0, 0.7, 2.0]).parent("gripper")],
+            )?;
+        }
+        let pose_bones = armature_object.pose_bones(py)?;
+        assert_eq!(pose_bones.len(py)?, 4);
+        assert_eq!(
+            pose_bones.get(py, "upper_arm")?.rotation_euler(py)?,
+            [0.5, 0.0, 0.0]
+        );
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_shape_keys() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let mesh: Mesh = object.clone().try_into()?;
+        mesh.as_ref(py).call_method1(
+            "from_pydata",
+            (
+                vec![[0.0_f32, 0.0, 0.0], [1.0, 0.0, 0.0], [1.0, 1.0, 0.0]],
+                Vec::<[usize; 2]>::new(),
+                vec![vec![0_usize, 1, 2]],
+            ),
+        )?;
+
+        // Act
+        let basis = object.shape_key_add(py, "Basis", false)?;
+        let mut smile = object.shape_key_add(py, "Smile", false)?;
+        let raised: Vec<[f32; 3]> = smile
+            .coordinates(py)?
+            .into_iter()
+            .map(|[x, y, z]| [x, y, z + 1.0])
+            .collect();
+        smile.set_coordinates(py, &raised)?;
+        smile.set_slider_max(py, 2.0)?;
+        smile.set_interpolation(py, ShapeKeyInterpolation::Bspline)?;
+        smile.set_value(py, 0.5)?;
+        let mix = object.shape_key_add(py, "Mix", true)?;
+
+        // Assert
+        let key = mesh.shape_keys(py)?.unwrap();
+        assert_eq!(key.key_blocks(py)?.keys(py)?, ["Basis", "Smile", "Mix"]);
+        assert_eq!(key.reference_key(py)?.name(py)?, "Basis");
+        assert_eq!(smile.relative_key(py)?.name(py)?, basis.name(py)?);
+        assert_eq!(smile.slider_max(py)?, 2.0);
+        assert_eq!(smile.interpolation(py)?, ShapeKeyInterpolation::Bspline);
+        assert_eq!(mix.coordinates(py)?[2], [1.0, 1.0, 0.5]);
+        assert_eq!(object.active_shape_key(py)?.unwrap().name(py)?, "Mix");
+        assert!(smile.set_coordinates(py, &raised[..2]).is_err());
+
+        // Act
+        smile.insert_value_keyframe(py, 1.0, 0.0)?;
+        smile.insert_value_keyframe(py, 11.0, 1.0)?;
+        let fcurve = mix.insert_value_keyframes(
+            py,
+            &[1.0, 5.0],
+            &[1.0, 0.0],
+            KeyframeInterpolation::Linear,
+        )?;
+
+        // Assert
+        let action = key.animation_data(py)?.unwrap().action(py)?.unwrap();
+        assert_eq!(action.fcurves(py)?.len(py)?, 2);
+        assert_eq!(fcurve.data_path(py)?, "key_blocks[\"Mix\"].value");
+        assert_eq!(fcurve.evaluate(py, 3.0)?, 0.5);
+
+        // Act
+        object.shape_key_remove(py, &mix)?;
+        assert_eq!(mesh.shape_keys(py)?.unwrap().key_blocks(py)?.len(py)?, 2);
+        object.shape_key_clear(py)?;
+
+        // Assert
+        assert!(mesh.shape_keys(py)?.is_none());
+        assert!(object.active_shape_key(py)?.is_none());
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_vertex_groups() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let mesh: Mesh = object.clone().try_into()?;
+        mesh.as_ref(py).call_method1(
+            "from_pydata",
+            (
+                vec![
+                    [0.0_f32, 0.0, 0.0],
+                    [1.0, 0.0, 0.0],
+                    [1.0, 1.0, 0.0],
+                    [0.0, 1.0, 0.0],
+                ],
+                Vec::<[usize; 2]>::new(),
+                vec![vec![0_usize, 1, 2, 3]],
+            ),
+        )?;
+        let vertex_groups = object.vertex_groups(py)?;
+
+        // Act
+        let spine = vertex_groups.new(py, "spine")?;
+        let arm = vertex_groups.new(py, "arm")?;
+        let hand = vertex_groups.new(py, "hand")?;
+        spine.set_weights(py, &[1.0, 0.5, 0.5, 0.0])?;
+        arm.add(py, &[0, 1, 2, 3], 0.5, VertexGroupAssignMode::Replace)?;
+        arm.add(py, &[3], 0.25, VertexGroupAssignMode::Add)?;
+        hand.add(py, &[0], 0.25, VertexGroupAssignMode::Replace)?;
+
+        // Assert
+        assert_eq!(vertex_groups.keys(py)?, ["spine", "arm", "hand"]);
+        assert_eq!(vertex_groups.active(py)?.unwrap().name(py)?, "hand");
+        assert_eq!(spine.weights(py)?, [(0, 1.0), (1, 0.5), (2, 0.5)]);
+        assert_eq!(arm.weight(py, 3)?, 0.75);
+        assert!(spine.weight(py, 3).is_err());
+        assert!(spine.set_weights(py, &[1.0]).is_err());
+
+        // Act
+        object.limit_vertex_groups_total(py, 2)?;
+        object.normalize_vertex_groups(py, false)?;
+
+        // Assert
+        assert!(hand.weights(py)?.is_empty());
+        assert_eq!(spine.weights(py)?[1], (1, 0.5));
+        assert!((spine.weight(py, 0)? - 2.0 / 3.0).abs() < 1e-6);
+        assert_eq!(arm.weight(py, 3)?, 1.0);
+
+        // Act
+        vertex_groups.remove(py, &spine)?;
+
+        // Assert
+        assert_eq!(arm.index(py)?, 0);
+        assert_eq!(arm.weights(py)?.len(), 4);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_scene_collections() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let object = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        let root = SceneCollection::from_scene(py)?;
+
+        // Act
+        let props = root.new_child(py, "Props")?;
+        let mut chairs = props.new_child(py, "Chairs")?;
+        chairs.set_hide_render(py, true)?;
+        chairs.set_instance_offset(py, [1.0, 2.0, 3.0])?;
+        object.move_to_collection(py, &chairs)?;
+        object.move_to_collection(py, &chairs)?;
+
+        // Assert
+        let collections = bpy::data::collections(py)?;
+        assert_eq!(collections.keys(py)?, ["Props", "Chairs"]);
+        assert_eq!(root.children(py)?.keys(py)?, ["Props"]);
+        assert_eq!(props.children_recursive(py)?.len(), 1);
+        assert!(props.objects(py)?.is_empty(py)?);
+        assert_eq!(props.all_objects(py)?[0].name(py)?, object.name(py)?);
+        assert_eq!(root.all_objects(py)?.len(), 1);
+        assert!(chairs.hide_render(py)?);
+        assert_eq!(chairs.instance_offset(py)?, [1.0, 2.0, 3.0]);
+        let users_collection = object.users_collection(py)?;
+        assert_eq!(users_collection.len(), 1);
+        assert_eq!(users_collection[0].name(py)?, "Chairs");
+
+        // Act
+        chairs.objects(py)?.unlink(py, &object)?;
+        props.children(py)?.unlink(py, &chairs)?;
+        collections.remove(py, &chairs, true, true, true)?;
+
+        // Assert
+        assert!(object.users_collection(py)?.is_empty());
+        assert!(props.children(py)?.is_empty(py)?);
+        assert_eq!(collections.keys(py)?, ["Props"]);
+        Ok(())
+    })
+}
+
+#[test]
+fn mock_bpy_rigid_body_simulation() -> BlResult<()> {
+    with_mock_bpy(|py| {
+        // Arrange
+        let mut ground = Object::new_mesh(py, [0.0, 0.0, 0.0], [0.0, 0.0, 0.0])?;
+        ground.set_name(py, "Ground")?;
+        let mut crate_ = Object::new_mesh(py, [0.0, 0.0, 5.0], [0.0, 0.0, 0.0])?;
+        crate_.set_name(py, "Crate")?;
+        let scene = bpy::context::scene(py)?;
+
+        // Act
+        let mut world = scene.rigid_body_world_ensure(py)?;
+        world.set_substeps_per_frame(py, 20)?;
+        world.set_solver_iterations(py, 30)?;
+        world.set_frame_end(py, 48)?;
+        let mut ground_body = ground.rigid_body_add(py, RigidBodyType::Passive)?;
+        ground_body.set_collision_shape(py, RigidBodyCollisionShape::Mesh)?;
+        let mut crate_body = crate_.rigid_body_add(py, RigidBodyType::Active)?;
+        crate_body.set_mass(py, 2.5)?;
+        crate_body.set_friction(py, 0.8)?;
+        crate_body.set_restitution(py, 0.1)?;
+        let sparse_poses = scene.simulate_rigid_bodies(py, [12, 48])?;
+        let poses = scene.simulate_rigid_bodies(py, 1..=48)?;
+
+        // Assert
+        assert_eq!(world.gravity(py)?, [0.0, 0.0, -9.81]);
+        assert_eq!(world.substeps_per_frame(py)?, 20);
+        assert_eq!(world.frame_start(py)?, 1);
+        assert_eq!(scene.rigid_body_objects(py)?.len(), 2);
+        assert_eq!(crate_body.rigid_body_type(py)?, RigidBodyType::Active);
+        assert_eq!(crate_body.mass(py)?, 2.5);
+        assert_eq!(
+            ground.rigid_body(py)?.unwrap().collision_shape(py)?,
+            RigidBodyCollisionShape::Mesh
+        );
+        assert_eq!(poses["Crate"].len(), 48);
+        assert_eq!(poses["Crate"][0][2][3], 5.0);
+        assert!(poses["Crate"][10][2][3] < 5.0);
+        assert_eq!(poses["Crate"][47][2][3], 0.0);
+        assert_eq!(poses["Ground"][47][2][3], 0.0);
+        assert_eq!(
+            sparse_poses["Crate"],
+            [poses["Crate"][11], poses["Crate"][47]],
+            "the frames in between are simulated as well"
+        );
+        assert_eq!(scene.frame_current(py)?, 1);
+
+        // Act + Assert (constraints)
+        let mut hinge = crate_.rigid_body_constraint_add(py, RigidBodyConstraintType::Hinge)?;
+        hinge.set_object1(py, Some(&ground))?;
+        hinge.set_object2(py, Some(&crate_))?;
+        assert_eq!(hinge.constraint_type(py)?, RigidBodyConstraintType::Hinge);
+        assert_eq!(hinge.object1(py)?.unwrap().name(py)?, "Ground");
+        crate_.rigid_body_constraint_remove(py)?;
+        assert!(crate_.rigid_body_constraint(py)?.is_none());
+
+        // Act
+        scene.frame_set(py, 12, 0.0)?;
+        scene.apply_rigid_body_transforms(py, 48)?;
+
+        // Assert
+        assert!(crate_.rigid_body(py)?.is_none());
+        assert!(scene.rigid_body_objects(py)?.is_empty());
+        assert_eq!(crate_.location(py)?, [0.0, 0.0, 0.0]);
+        assert_eq!(
+            scene.frame_current(py)?,
+            12,
+            "the current frame is restored after applying the transforms"
+        );
+
+        // Act
+        crate_.set_location(py, [0.0, 0.0, 2.0])?;
+        ground.rigid_body_add(py, RigidBodyType::Passive)?;
+        crate_.rigid_body_add(py, RigidBodyType::Active)?;
+        scene.bake_rigid_bodies_to_keyframes(py, 1, 24, 1)?;
+
+        // Assert
+        let action = crate_.animation_data(py)?.unwrap().action(py)?.unwrap();
+        let fcurve = action.fcurves(py)?.find_fcurve(py, "location", 2)?.unwrap();
+        assert_eq!(fcurve.evaluate(py, 1.0)?, 2.0);
+        assert_eq!(fcurve.evaluate(py, 24.0)?, 0.0);
+        assert!(crate_.rigid_body(py)?.is_none());
+        Ok(())
+    })
+}